@@ -9,6 +9,7 @@ FEATS=(
 	gui
 	kernel
 	mf
+	mmdevice
 	ole
 	oleaut
 	shell
@@ -0,0 +1,37 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::decl::*;
+use crate::ole::privs::*;
+use crate::prelude::*;
+use crate::vt::*;
+
+impl comctl_shell_ITaskbarList3 for ITaskbarList3 {}
+impl comctl_shell_ITaskbarList3 for ITaskbarList4 {}
+
+/// This trait is enabled with `comctl` and `shell` features, and provides
+/// methods for [`ITaskbarList3`](crate::ITaskbarList3).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait comctl_shell_ITaskbarList3: shell_ITaskbarList3 {
+	/// [`ITaskbarList3::ThumbBarSetImageList`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-itaskbarlist3-thumbbarsetimagelist)
+	/// method.
+	fn ThumbBarSetImageList(&self,
+		hwnd: &HWND,
+		himagelist: &HIMAGELIST,
+	) -> HrResult<()>
+	{
+		ok_to_hrresult(
+			unsafe {
+				(vt::<ITaskbarList3VT>(self).ThumbBarSetImageList)(
+					self.ptr(),
+					hwnd.ptr(),
+					himagelist.ptr(),
+				)
+			},
+		)
+	}
+}
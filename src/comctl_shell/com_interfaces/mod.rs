@@ -0,0 +1,5 @@
+mod itaskbarlist3;
+
+pub mod traits {
+	pub use super::itaskbarlist3::comctl_shell_ITaskbarList3;
+}
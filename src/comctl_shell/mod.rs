@@ -1,7 +1,9 @@
 #![cfg(all(feature = "comctl", feature = "shell"))]
 
+mod com_interfaces;
 mod handles;
 
 pub mod traits {
+	pub use super::com_interfaces::traits::*;
 	pub use super::handles::traits::*;
 }
@@ -2,8 +2,8 @@
 
 use crate::co;
 use crate::decl::*;
-use crate::dshow::iterators::*;
 use crate::kernel::ffi_types::*;
+use crate::ole::iterators::*;
 use crate::ole::privs::*;
 use crate::prelude::*;
 use crate::vt::*;
@@ -59,7 +59,23 @@ pub trait dshow_IEnumFilters: ole_IUnknown {
 	/// ```
 	#[must_use]
 	fn iter(&self) -> Box<dyn Iterator<Item = HrResult<IBaseFilter>> + '_> {
-		Box::new(IenumfiltersIter::new(self))
+		Box::new(IenumIter::new(move || self.Next()))
+	}
+
+	/// [`IEnumFilters::Next`](crate::prelude::dshow_IEnumFilters::Next)
+	/// method, which retrieves up to `count` elements in a single call.
+	///
+	/// When the end of the enumeration is reached, fewer elements – possibly
+	/// none – are returned.
+	#[must_use]
+	fn next_batch(&self, count: u32) -> HrResult<Vec<IBaseFilter>> {
+		ienum_next_batch(
+			count,
+			|| unsafe { IBaseFilter::null() },
+			|count, elems, fetched| unsafe {
+				(vt::<IEnumFiltersVT>(self).Next)(self.ptr(), count, elems as _, fetched)
+			},
+		)
 	}
 
 	/// [`IEnumFilters::Next`](https://learn.microsoft.com/en-us/windows/win32/api/strmif/nf-strmif-ienumfilters-next)
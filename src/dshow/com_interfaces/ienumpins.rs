@@ -2,8 +2,8 @@
 
 use crate::co;
 use crate::decl::*;
-use crate::dshow::iterators::*;
 use crate::kernel::ffi_types::*;
+use crate::ole::iterators::*;
 use crate::ole::privs::*;
 use crate::prelude::*;
 use crate::vt::*;
@@ -57,7 +57,23 @@ pub trait dshow_IEnumPins: ole_IUnknown {
 	/// ```
 	#[must_use]
 	fn iter(&self) -> Box<dyn Iterator<Item = HrResult<IPin>> + '_> {
-		Box::new(IenumpinsIter::new(self))
+		Box::new(IenumIter::new(move || self.Next()))
+	}
+
+	/// [`IEnumPins::Next`](crate::prelude::dshow_IEnumPins::Next)
+	/// method, which retrieves up to `count` elements in a single call.
+	///
+	/// When the end of the enumeration is reached, fewer elements – possibly
+	/// none – are returned.
+	#[must_use]
+	fn next_batch(&self, count: u32) -> HrResult<Vec<IPin>> {
+		ienum_next_batch(
+			count,
+			|| unsafe { IPin::null() },
+			|count, elems, fetched| unsafe {
+				(vt::<IEnumPinsVT>(self).Next)(self.ptr(), count, elems as _, fetched)
+			},
+		)
 	}
 
 	/// [`IEnumPins::Next`](https://learn.microsoft.com/en-us/windows/win32/api/strmif/nf-strmif-ienumpins-next)
@@ -5,8 +5,10 @@ extern_sys! { "dwmapi";
 	DwmExtendFrameIntoClientArea(HANDLE, PCVOID) -> HRES
 	DwmFlush() -> HRES
 	DwmGetColorizationColor(*mut u32, *mut BOOL) -> HRES
+	DwmGetWindowAttribute(HANDLE, u32, PVOID, u32) -> HRES
 	DwmInvalidateIconicBitmaps(HANDLE) -> HRES
 	DwmIsCompositionEnabled(*mut BOOL) -> HRES
 	DwmSetIconicLivePreviewBitmap(HANDLE, HANDLE, PCVOID, u32) -> HRES
 	DwmSetIconicThumbnail(HANDLE, HANDLE, u32) -> HRES
+	DwmSetWindowAttribute(HANDLE, u32, PCVOID, u32) -> HRES
 }
@@ -17,6 +17,38 @@ impl dwm_Hwnd for HWND {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait dwm_Hwnd: uxtheme_Hwnd {
+	/// Enables or disables the dark title bar of the window, by setting
+	/// [`co::DWMWA::USE_IMMERSIVE_DARK_MODE`](crate::co::DWMWA::USE_IMMERSIVE_DARK_MODE)
+	/// with
+	/// [`HWND::DwmSetWindowAttribute`](crate::prelude::dwm_Hwnd::DwmSetWindowAttribute).
+	///
+	/// Windows 10 builds prior to 20H1 use the undocumented attribute value 19
+	/// instead of 20; if the documented one is rejected, the older one is tried.
+	/// Builds prior to 1809 don't support dark title bars at all, and an error
+	/// is returned.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// hwnd.set_dark_mode(true)?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	fn set_dark_mode(&self, enabled: bool) -> HrResult<()> {
+		let val: i32 = enabled as _; // BOOL
+		unsafe {
+			self.DwmSetWindowAttribute(co::DWMWA::USE_IMMERSIVE_DARK_MODE, &val)
+				.or_else(|_| self.DwmSetWindowAttribute(
+					co::DWMWA::from_raw(co::DWMWA::USE_IMMERSIVE_DARK_MODE.raw() - 1),
+					&val,
+				))
+		}
+	}
+
 	/// [`DwmExtendFrameIntoClientArea`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/nf-dwmapi-dwmextendframeintoclientarea)
 	/// function.
 	fn DwmExtendFrameIntoClientArea(&self,
@@ -33,6 +65,28 @@ pub trait dwm_Hwnd: uxtheme_Hwnd {
 		)
 	}
 
+	/// [`DwmGetWindowAttribute`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/nf-dwmapi-dwmgetwindowattribute)
+	/// function.
+	///
+	/// # Safety
+	///
+	/// The `value` type varies according to `attr`. If you set it wrong,
+	/// you're likely to cause a buffer overrun.
+	unsafe fn DwmGetWindowAttribute<T>(&self,
+		attr: co::DWMWA,
+		value: &mut T,
+	) -> HrResult<()>
+	{
+		ok_to_hrresult(
+			ffi::DwmGetWindowAttribute(
+				self.ptr(),
+				attr.raw(),
+				value as *mut _ as _,
+				std::mem::size_of::<T>() as _,
+			),
+		)
+	}
+
 	/// [`DwmInvalidateIconicBitmaps`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/nf-dwmapi-dwminvalidateiconicbitmaps)
 	/// function.
 	fn DwmInvalidateIconicBitmaps(&self) -> HrResult<()> {
@@ -54,7 +108,7 @@ pub trait dwm_Hwnd: uxtheme_Hwnd {
 				ffi::DwmSetIconicLivePreviewBitmap(
 					self.ptr(),
 					hbmp.ptr(),
-					pt_client.map_or(std::ptr::null(), |pt| &pt as *const _ as _),
+					pt_client.as_ref().map_or(std::ptr::null(), |pt| pt as *const _ as _),
 					sit_flags.unwrap_or_default().raw(),
 				)
 			},
@@ -78,4 +132,44 @@ pub trait dwm_Hwnd: uxtheme_Hwnd {
 			},
 		)
 	}
+
+	/// [`DwmSetWindowAttribute`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/nf-dwmapi-dwmsetwindowattribute)
+	/// function.
+	///
+	/// # Safety
+	///
+	/// The `value` type varies according to `attr`. If you set it wrong,
+	/// you're likely to cause a buffer overrun.
+	///
+	/// # Examples
+	///
+	/// Enabling the dark title bar, available on Windows 10 20H1 and later:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// let dark: i32 = 1; // BOOL
+	/// unsafe {
+	///     hwnd.DwmSetWindowAttribute(
+	///         co::DWMWA::USE_IMMERSIVE_DARK_MODE, &dark)?;
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	unsafe fn DwmSetWindowAttribute<T>(&self,
+		attr: co::DWMWA,
+		value: &T,
+	) -> HrResult<()>
+	{
+		ok_to_hrresult(
+			ffi::DwmSetWindowAttribute(
+				self.ptr(),
+				attr.raw(),
+				value as *const _ as _,
+				std::mem::size_of::<T>() as _,
+			),
+		)
+	}
 }
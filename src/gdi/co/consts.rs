@@ -75,6 +75,43 @@ const_ordinary! { CHARSET: u8;
 	BALTIC 186
 }
 
+const_bitflag! { CHOOSEFONT: u32;
+	/// [`CHOOSEFONT`](crate::CHOOSEFONT) `Flags` (`u32`).
+	///
+	/// Originally has `CF` prefix.
+	=>
+	=>
+	SCREENFONTS 0x0000_0001
+	PRINTERFONTS 0x0000_0002
+	BOTH Self::SCREENFONTS.0 | Self::PRINTERFONTS.0
+	SHOWHELP 0x0000_0004
+	ENABLEHOOK 0x0000_0008
+	ENABLETEMPLATE 0x0000_0010
+	ENABLETEMPLATEHANDLE 0x0000_0020
+	INITTOLOGFONTSTRUCT 0x0000_0040
+	USESTYLE 0x0000_0080
+	EFFECTS 0x0000_0100
+	APPLY 0x0000_0200
+	ANSIONLY 0x0000_0400
+	SCRIPTSONLY Self::ANSIONLY.0
+	NOVECTORFONTS 0x0000_0800
+	NOOEMFONTS Self::NOVECTORFONTS.0
+	NOSIMULATIONS 0x0000_1000
+	LIMITSIZE 0x0000_2000
+	FIXEDPITCHONLY 0x0000_4000
+	WYSIWYG 0x0000_8000
+	FORCEFONTEXIST 0x0001_0000
+	SCALABLEONLY 0x0002_0000
+	TTONLY 0x0004_0000
+	NOFACESEL 0x0008_0000
+	NOSTYLESEL 0x0010_0000
+	NOSIZESEL 0x0020_0000
+	SELECTSCRIPT 0x0040_0000
+	NOSCRIPTSEL 0x0080_0000
+	NOVERTFONTS 0x0100_0000
+	INACTIVEFONTS 0x0200_0000
+}
+
 const_ordinary! { CLIP: u8;
 	/// [`HFONT::CreateFont`](crate::prelude::gdi_Hfont::CreateFont)
 	/// `clip_precision` and [`LOGFONT`](crate::LOGFONT) `lfClipPrecision`
@@ -115,6 +152,20 @@ const_ordinary! { FF: u8;
 	DECORATIVE 5 << 4
 }
 
+const_bitflag! { FONTTYPE: u16;
+	/// [`CHOOSEFONT`](crate::CHOOSEFONT) `nFontType` (`u16`).
+	///
+	/// Originally has `FONTTYPE` suffix.
+	=>
+	=>
+	BOLD 0x0100
+	ITALIC 0x0200
+	REGULAR 0x0400
+	SCREEN 0x2000
+	PRINTER 0x4000
+	SIMULATED 0x8000
+}
+
 const_ordinary! { FW: u32;
 	/// [`HFONT::CreateFont`](crate::prelude::gdi_Hfont::CreateFont) `weight`
 	/// and [`LOGFONT`](crate::LOGFONT) `lfWeight` (`u32`).
@@ -0,0 +1,55 @@
+const_no_debug_display! { GPSTATUS: i32;
+	/// GDI+
+	/// [`Status`](https://learn.microsoft.com/en-us/windows/win32/api/gdiplustypes/ne-gdiplustypes-status)
+	/// enumeration (`i32`).
+	///
+	/// Implements the standard [`Error`](std::error::Error) trait.
+	///
+	/// Does not implement [`FormattedError`](crate::prelude::FormattedError)
+	/// because [`FormatMessage`](crate::FormatMessage) function does not offer
+	/// support for it, so there is no way to obtain a textual description of
+	/// the error codes.
+}
+
+impl std::error::Error for GPSTATUS {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		None
+	}
+}
+
+impl std::fmt::Display for GPSTATUS {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "[{:#04x} {}] GDI+ error.", self.0, self.0)
+	}
+}
+impl std::fmt::Debug for GPSTATUS {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self, f)
+	}
+}
+
+const_values! { GPSTATUS;
+	=>
+	OK 0
+	GENERIC_ERROR 1
+	INVALID_PARAMETER 2
+	OUT_OF_MEMORY 3
+	OBJECT_BUSY 4
+	INSUFFICIENT_BUFFER 5
+	NOT_IMPLEMENTED 6
+	WIN32_ERROR 7
+	WRONG_STATE 8
+	ABORTED 9
+	FILE_NOT_FOUND 10
+	VALUE_OVERFLOW 11
+	ACCESS_DENIED 12
+	UNKNOWN_IMAGE_FORMAT 13
+	FONT_FAMILY_NOT_FOUND 14
+	FONT_STYLE_NOT_FOUND 15
+	NOT_TRUE_TYPE_FONT 16
+	UNSUPPORTED_GDIPLUS_VERSION 17
+	GDIPLUS_NOT_INITIALIZED 18
+	PROPERTY_NOT_FOUND 19
+	PROPERTY_NOT_SUPPORTED 20
+	PROFILE_NOT_FOUND 21
+}
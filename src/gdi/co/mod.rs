@@ -0,0 +1,5 @@
+mod consts;
+mod gpstatus;
+
+pub use consts::*;
+pub use gpstatus::*;
@@ -4,6 +4,7 @@ use crate::kernel::privs::*;
 
 /// Variant parameter for:
 ///
+/// * [`HINSTANCE::LoadBitmap`](crate::prelude::gdi_Hinstance::LoadBitmap);
 /// * [`HINSTANCE::LoadImageBitmap`](crate::prelude::gdi_Hinstance::LoadImageBitmap).
 #[derive(Clone)]
 pub enum IdObmStr {
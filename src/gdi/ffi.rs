@@ -14,6 +14,7 @@ extern_sys! { "gdi32";
 	CreateBrushIndirect(PCVOID) -> HANDLE
 	CreateCompatibleBitmap(HANDLE, i32, i32) -> HANDLE
 	CreateCompatibleDC(HANDLE) -> HANDLE
+	CreateEllipticRgn(i32, i32, i32, i32) -> HANDLE
 	CreateFontIndirectW(PCVOID) -> HANDLE
 	CreateFontW(i32, i32, i32, i32, i32, u32, u32, u32, u32, u32, u32, u32, u32, PCSTR) -> HANDLE
 	CreateHalftonePalette(HANDLE) -> HANDLE
@@ -104,10 +105,29 @@ extern_sys! { "gdi32";
 	WidenPath(HANDLE) -> BOOL
 }
 
+extern_sys! { "gdiplus";
+	GdipCreateBitmapFromFile(PCSTR, *mut HANDLE) -> i32
+	GdipCreateFromHDC(HANDLE, *mut HANDLE) -> i32
+	GdipDeleteGraphics(HANDLE) -> i32
+	GdipDisposeImage(HANDLE) -> i32
+	GdipDrawImageI(HANDLE, HANDLE, i32, i32) -> i32
+	GdipGetImageHeight(HANDLE, *mut u32) -> i32
+	GdipGetImageWidth(HANDLE, *mut u32) -> i32
+	GdipLoadImageFromFile(PCSTR, *mut HANDLE) -> i32
+	GdiplusShutdown(usize)
+	GdiplusStartup(*mut usize, PCVOID, PVOID) -> i32
+}
+
+extern_sys! { "comdlg32";
+	ChooseFontW(PVOID) -> BOOL
+}
+
 extern_sys! { "msimg32";
 	TransparentBlt(HANDLE, i32, i32, i32, i32, HANDLE, i32, i32, i32, i32, u32) -> BOOL
 }
 
 extern_sys! { "user32";
+	LoadBitmapW(HANDLE, PCSTR) -> HANDLE // returns GdiObjectGuard, so needs gdi feature
 	LoadImageW(HANDLE, PCSTR, u32, i32, i32, u32) -> HANDLE // returns GdiObjectGuard, so needs gdi feature
+	SetWindowRgn(HANDLE, HANDLE, BOOL) -> i32 // takes DeleteObjectGuard, so needs gdi feature
 }
@@ -1,9 +1,51 @@
 #![allow(non_snake_case)]
 
+use crate::co;
 use crate::decl::*;
 use crate::gdi::ffi;
+use crate::gdi::privs::*;
+use crate::guard::*;
 use crate::kernel::privs::*;
 
+/// [`ChooseFont`](https://learn.microsoft.com/en-us/previous-versions/windows/desktop/legacy/ms646914(v=vs.85))
+/// function.
+///
+/// Returns `false` if the user cancelled the dialog.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let parent_hwnd: w::HWND; // initialized somewhere
+/// # let parent_hwnd = w::HWND::NULL;
+///
+/// let mut cf = w::CHOOSEFONT::default();
+/// let mut lf = w::LOGFONT::default();
+/// lf.set_lfFaceName("Tahoma");
+///
+/// cf.hwndOwner = parent_hwnd;
+/// cf.Flags = co::CHOOSEFONT::SCREENFONTS | co::CHOOSEFONT::INITTOLOGFONTSTRUCT;
+/// cf.set_lpLogFont(Some(&mut lf));
+///
+/// if w::ChooseFont(&mut cf)? {
+///     println!("The font: {}, {} pt",
+///         cf.lpLogFont().unwrap().lfFaceName(),
+///         cf.iPointSize / 10,
+///     );
+/// }
+/// # Ok::<_, co::CDERR>(())
+/// ```
+pub fn ChooseFont(cf: &mut CHOOSEFONT) -> Result<bool, co::CDERR> {
+	match unsafe { ffi::ChooseFontW(cf as *mut _ as _) } {
+		0 => match CommDlgExtendedError() {
+			co::CDERR::NoValue => Ok(false),
+			err => Err(err),
+		},
+		_ => Ok(true),
+	}
+}
+
 /// [`GdiFlush`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-gdiflush)
 /// function.
 pub fn GdiFlush() -> SysResult<()> {
@@ -25,3 +67,51 @@ pub fn GdiSetBatchLimit(limit: u32) -> SysResult<u32> {
 		n => Ok(n),
 	}
 }
+
+/// [`GdiplusStartup`](https://learn.microsoft.com/en-us/windows/win32/api/gdiplusinit/nf-gdiplusinit-gdiplusstartup)
+/// function.
+///
+/// GDI+ must be initialized before any other GDI+ call, such as
+/// [`GpImage::GdipLoadImageFromFile`](crate::prelude::gdi_Gpimage::GdipLoadImageFromFile).
+/// GDI+ is shut down when the returned guard goes out of scope; the images
+/// loaded with it borrow the guard, so they are disposed before that.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let gdiplus = w::GdiplusStartup()?;
+///
+/// let bitmap = w::GpBitmap::GdipCreateBitmapFromFile(&gdiplus, "C:\\Temp\\foo.png")?;
+/// println!("{} x {}", bitmap.width()?, bitmap.height()?);
+/// # Ok::<_, co::GPSTATUS>(())
+/// ```
+#[must_use]
+pub fn GdiplusStartup() -> Result<GdiplusShutdownGuard, co::GPSTATUS> {
+	#[repr(C)]
+	struct GdiplusStartupInput {
+		GdiplusVersion: u32,
+		DebugEventCallback: *mut std::ffi::c_void,
+		SuppressBackgroundThread: i32,
+		SuppressExternalCodecs: i32,
+	}
+
+	let input = GdiplusStartupInput {
+		GdiplusVersion: 1,
+		DebugEventCallback: std::ptr::null_mut(),
+		SuppressBackgroundThread: 0,
+		SuppressExternalCodecs: 0,
+	};
+	let mut token = usize::default();
+
+	unsafe {
+		gpstatus_to_result(
+			ffi::GdiplusStartup(
+				&mut token,
+				&input as *const _ as _,
+				std::ptr::null_mut(),
+			),
+		).map(|_| GdiplusShutdownGuard::new(token))
+	}
+}
@@ -81,6 +81,116 @@ impl<T> DeleteObjectGuard<T>
 
 //------------------------------------------------------------------------------
 
+/// RAII implementation for a [`GpImage`](crate::GpImage) or a
+/// [`GpBitmap`](crate::GpBitmap) which automatically calls
+/// [`GdipDisposeImage`](https://learn.microsoft.com/en-us/windows/win32/gdiplus/-gdiplus-image-flat)
+/// when the object goes out of scope.
+///
+/// The guard borrows the [`GdiplusShutdownGuard`](crate::guard::GdiplusShutdownGuard),
+/// so the image is always disposed before GDI+ is shut down.
+pub struct GdipDisposeImageGuard<'a, T>
+	where T: gdi_Gpimage,
+{
+	_gdiplus: &'a GdiplusShutdownGuard,
+	handle: T,
+}
+
+impl<'a, T> Drop for GdipDisposeImageGuard<'a, T>
+	where T: gdi_Gpimage,
+{
+	fn drop(&mut self) {
+		if let Some(h) = self.handle.as_opt() {
+			unsafe { ffi::GdipDisposeImage(h.ptr()); } // ignore errors
+		}
+	}
+}
+
+impl<'a, T> Deref for GdipDisposeImageGuard<'a, T>
+	where T: gdi_Gpimage,
+{
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		&self.handle
+	}
+}
+
+impl<'a, T> DerefMut for GdipDisposeImageGuard<'a, T>
+	where T: gdi_Gpimage,
+{
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.handle
+	}
+}
+
+impl<'a, T> GdipDisposeImageGuard<'a, T>
+	where T: gdi_Gpimage,
+{
+	/// Constructs the guard by taking ownership of the handle.
+	///
+	/// # Safety
+	///
+	/// Be sure the handle must be freed with
+	/// [`GdipDisposeImage`](https://learn.microsoft.com/en-us/windows/win32/gdiplus/-gdiplus-image-flat)
+	/// at the end of scope, and that it was created under the given GDI+
+	/// session.
+	#[must_use]
+	pub const unsafe fn new(gdiplus: &'a GdiplusShutdownGuard, handle: T) -> Self {
+		Self { _gdiplus: gdiplus, handle }
+	}
+
+	/// Ejects the underlying handle, leaving a
+	/// [`Handle::INVALID`](crate::prelude::Handle::INVALID) in its place.
+	///
+	/// Since the internal handle will be invalidated, the destructor will not
+	/// run. It's your responsability to run it, otherwise you'll cause a
+	/// resource leak.
+	#[must_use]
+	pub fn leak(&mut self) -> T {
+		std::mem::replace(&mut self.handle, T::INVALID)
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// RAII implementation which automatically calls
+/// [`GdiplusShutdown`](https://learn.microsoft.com/en-us/windows/win32/api/gdiplusinit/nf-gdiplusinit-gdiplusshutdown)
+/// when the object goes out of scope.
+///
+/// GDI+ images borrow this guard, so it cannot be dropped while they are
+/// alive.
+pub struct GdiplusShutdownGuard {
+	token: usize,
+}
+
+impl Drop for GdiplusShutdownGuard {
+	fn drop(&mut self) {
+		unsafe { ffi::GdiplusShutdown(self.token); }
+	}
+}
+
+impl GdiplusShutdownGuard {
+	/// Constructs the guard by taking ownership of the token.
+	///
+	/// # Safety
+	///
+	/// Be sure you need to call
+	/// [`GdiplusShutdown`](https://learn.microsoft.com/en-us/windows/win32/api/gdiplusinit/nf-gdiplusinit-gdiplusshutdown)
+	/// at the end of scope.
+	#[must_use]
+	pub const unsafe fn new(token: usize) -> Self {
+		Self { token }
+	}
+
+	/// Returns the token returned by [`GdiplusStartup`](crate::GdiplusStartup).
+	#[must_use]
+	pub const fn token(&self) -> usize {
+		self.token
+	}
+}
+
+//------------------------------------------------------------------------------
+
 /// RAII implementation for [`LOGPALETTE`](crate::LOGPALETTE) which manages the
 /// allocated memory.
 pub struct LogpaletteGuard {
@@ -0,0 +1,64 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::gdi::{ffi, privs::*};
+use crate::guard::*;
+use crate::prelude::*;
+
+impl_handle! { GpBitmap;
+	/// Pointer to a GDI+
+	/// [`Bitmap`](https://learn.microsoft.com/en-us/windows/win32/api/gdiplusheaders/nl-gdiplusheaders-bitmap)
+	/// object, which is a [`GpImage`](crate::GpImage) made of pixels.
+	///
+	/// GDI+ must be initialized with [`GdiplusStartup`](crate::GdiplusStartup)
+	/// before any bitmap is created.
+}
+
+impl gdi_Gpimage for GpBitmap {}
+impl gdi_Gpbitmap for GpBitmap {}
+
+/// This trait is enabled with the `gdi` feature, and provides methods for
+/// [`GpBitmap`](crate::GpBitmap).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait gdi_Gpbitmap: gdi_Gpimage {
+	/// [`GdipCreateBitmapFromFile`](https://learn.microsoft.com/en-us/windows/win32/gdiplus/-gdiplus-bitmap-flat)
+	/// function.
+	///
+	/// The file remains locked until the bitmap is disposed.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let gdiplus = w::GdiplusStartup()?;
+	///
+	/// let bitmap = w::GpBitmap::GdipCreateBitmapFromFile(&gdiplus, "C:\\Temp\\foo.png")?;
+	/// println!("{} x {}", bitmap.width()?, bitmap.height()?);
+	///
+	/// // GdipDisposeImage() automatically called, then GdiplusShutdown()
+	/// # Ok::<_, co::GPSTATUS>(())
+	/// ```
+	#[must_use]
+	fn GdipCreateBitmapFromFile<'a>(
+		gdiplus: &'a GdiplusShutdownGuard,
+		file_name: &str,
+	) -> Result<GdipDisposeImageGuard<'a, GpBitmap>, co::GPSTATUS>
+	{
+		let mut bitmap = GpBitmap::NULL;
+		unsafe {
+			gpstatus_to_result(
+				ffi::GdipCreateBitmapFromFile(
+					WString::from_str(file_name).as_ptr(),
+					bitmap.as_mut(),
+				),
+			).map(|_| GdipDisposeImageGuard::new(gdiplus, bitmap))
+		}
+	}
+}
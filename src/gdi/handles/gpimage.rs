@@ -0,0 +1,168 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::gdi::{ffi, privs::*};
+use crate::guard::*;
+use crate::prelude::*;
+
+impl_handle! { GpImage;
+	/// Pointer to a GDI+
+	/// [`Image`](https://learn.microsoft.com/en-us/windows/win32/api/gdiplusheaders/nl-gdiplusheaders-image)
+	/// object, which supports formats like BMP, GIF, JPEG, PNG and TIFF.
+	///
+	/// GDI+ must be initialized with [`GdiplusStartup`](crate::GdiplusStartup)
+	/// before any image is loaded. The loaded image borrows the returned
+	/// [`GdiplusShutdownGuard`](crate::guard::GdiplusShutdownGuard), so GDI+
+	/// is shut down only after the image is disposed.
+}
+
+impl gdi_Gpimage for GpImage {}
+
+/// This trait is enabled with the `gdi` feature, and provides methods for
+/// [`GpImage`](crate::GpImage).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait gdi_Gpimage: Handle {
+	/// Draws the image at the given coordinates of the device context, in its
+	/// original size, by calling
+	/// [`GdipCreateFromHDC`](https://learn.microsoft.com/en-us/windows/win32/gdiplus/-gdiplus-graphics-flat),
+	/// [`GdipDrawImageI`](https://learn.microsoft.com/en-us/windows/win32/gdiplus/-gdiplus-graphics-flat)
+	/// and
+	/// [`GdipDeleteGraphics`](https://learn.microsoft.com/en-us/windows/win32/gdiplus/-gdiplus-graphics-flat).
+	///
+	/// # Examples
+	///
+	/// Drawing an image within a `WM_PAINT` handler:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	/// let image: w::GpImage; // initialized somewhere
+	/// # let image = w::GpImage::NULL;
+	///
+	/// let hdc = hwnd.BeginPaint()?;
+	/// image.draw_image(&hdc, 10, 10)?;
+	/// # Ok::<_, Box<dyn std::error::Error>>(())
+	/// ```
+	fn draw_image(&self, hdc: &HDC, x: i32, y: i32) -> Result<(), co::GPSTATUS> {
+		let mut graphics = std::ptr::null_mut();
+		unsafe {
+			gpstatus_to_result(ffi::GdipCreateFromHDC(hdc.ptr(), &mut graphics))?;
+			let res = gpstatus_to_result(
+				ffi::GdipDrawImageI(graphics, self.ptr(), x, y),
+			);
+			ffi::GdipDeleteGraphics(graphics);
+			res
+		}
+	}
+
+	/// Returns the height of the image, in pixels, by calling
+	/// [`GdipGetImageHeight`](crate::prelude::gdi_Gpimage::GdipGetImageHeight).
+	#[must_use]
+	fn height(&self) -> Result<u32, co::GPSTATUS> {
+		self.GdipGetImageHeight()
+	}
+
+	/// Returns the width of the image, in pixels, by calling
+	/// [`GdipGetImageWidth`](crate::prelude::gdi_Gpimage::GdipGetImageWidth).
+	#[must_use]
+	fn width(&self) -> Result<u32, co::GPSTATUS> {
+		self.GdipGetImageWidth()
+	}
+
+	/// [`GdipGetImageHeight`](https://learn.microsoft.com/en-us/windows/win32/gdiplus/-gdiplus-image-flat)
+	/// function.
+	#[must_use]
+	fn GdipGetImageHeight(&self) -> Result<u32, co::GPSTATUS> {
+		let mut height = u32::default();
+		gpstatus_to_result(
+			unsafe { ffi::GdipGetImageHeight(self.ptr(), &mut height) },
+		).map(|_| height)
+	}
+
+	/// [`GdipGetImageWidth`](https://learn.microsoft.com/en-us/windows/win32/gdiplus/-gdiplus-image-flat)
+	/// function.
+	#[must_use]
+	fn GdipGetImageWidth(&self) -> Result<u32, co::GPSTATUS> {
+		let mut width = u32::default();
+		gpstatus_to_result(
+			unsafe { ffi::GdipGetImageWidth(self.ptr(), &mut width) },
+		).map(|_| width)
+	}
+
+	/// [`GdipLoadImageFromFile`](https://learn.microsoft.com/en-us/windows/win32/gdiplus/-gdiplus-image-flat)
+	/// function.
+	///
+	/// The file remains locked until the image is disposed.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let gdiplus = w::GdiplusStartup()?;
+	///
+	/// let image = w::GpImage::GdipLoadImageFromFile(&gdiplus, "C:\\Temp\\foo.png")?;
+	/// println!("{} x {}", image.width()?, image.height()?);
+	///
+	/// // GdipDisposeImage() automatically called, then GdiplusShutdown()
+	/// # Ok::<_, co::GPSTATUS>(())
+	/// ```
+	#[must_use]
+	fn GdipLoadImageFromFile<'a>(
+		gdiplus: &'a GdiplusShutdownGuard,
+		file_name: &str,
+	) -> Result<GdipDisposeImageGuard<'a, GpImage>, co::GPSTATUS>
+	{
+		let mut image = GpImage::NULL;
+		unsafe {
+			gpstatus_to_result(
+				ffi::GdipLoadImageFromFile(
+					WString::from_str(file_name).as_ptr(),
+					image.as_mut(),
+				),
+			).map(|_| GdipDisposeImageGuard::new(gdiplus, image))
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::decl::*;
+	use crate::prelude::*;
+
+	/// A 1x1 transparent PNG.
+	const PNG_1X1: [u8; 68] = [
+		0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d,
+		0x49, 0x48, 0x44, 0x52, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01,
+		0x08, 0x06, 0x00, 0x00, 0x00, 0x1f, 0x15, 0xc4, 0x89, 0x00, 0x00, 0x00,
+		0x0b, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9c, 0x63, 0x60, 0x00, 0x02, 0x00,
+		0x00, 0x05, 0x00, 0x01, 0x7a, 0x5e, 0xab, 0x3f, 0x00, 0x00, 0x00, 0x00,
+		0x49, 0x45, 0x4e, 0x44, 0xae, 0x42, 0x60, 0x82,
+	];
+
+	#[test]
+	fn load_png_from_file() {
+		let path = std::env::temp_dir().join("winsafe_gpimage_test.png");
+		std::fs::write(&path, PNG_1X1).unwrap();
+		let path_str = path.to_str().unwrap();
+
+		let gdiplus = GdiplusStartup().unwrap();
+		{
+			let image = GpImage::GdipLoadImageFromFile(&gdiplus, path_str).unwrap();
+			assert_eq!((image.width().unwrap(), image.height().unwrap()), (1, 1));
+
+			let bitmap = GpBitmap::GdipCreateBitmapFromFile(&gdiplus, path_str).unwrap();
+			assert_eq!((bitmap.width().unwrap(), bitmap.height().unwrap()), (1, 1));
+		} // release the file before deleting it
+
+		let _ = std::fs::remove_file(&path);
+	}
+}
@@ -20,6 +20,53 @@ impl gdi_Hdc for HDC {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait gdi_Hdc: user_Hdc {
+	/// Returns the number of adjacent color bits for each pixel, by multiplying
+	/// [`co::GDC::BITSPIXEL`](crate::co::GDC::BITSPIXEL) and
+	/// [`co::GDC::PLANES`](crate::co::GDC::PLANES) with
+	/// [`HDC::GetDeviceCaps`](crate::prelude::gdi_Hdc::GetDeviceCaps).
+	#[must_use]
+	fn color_depth(&self) -> i32 {
+		self.GetDeviceCaps(co::GDC::BITSPIXEL)
+			* self.GetDeviceCaps(co::GDC::PLANES)
+	}
+
+	/// Returns the horizontal and vertical number of pixels per logical inch,
+	/// retrieved with
+	/// [`co::GDC::LOGPIXELSX`](crate::co::GDC::LOGPIXELSX) and
+	/// [`co::GDC::LOGPIXELSY`](crate::co::GDC::LOGPIXELSY) with
+	/// [`HDC::GetDeviceCaps`](crate::prelude::gdi_Hdc::GetDeviceCaps).
+	///
+	/// # Examples
+	///
+	/// Retrieving the DPI of the screen:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let screen_dc = w::HWND::NULL.GetDC()?;
+	/// let (dpi_x, dpi_y) = screen_dc.logical_dpi();
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn logical_dpi(&self) -> (i32, i32) {
+		(
+			self.GetDeviceCaps(co::GDC::LOGPIXELSX),
+			self.GetDeviceCaps(co::GDC::LOGPIXELSY),
+		)
+	}
+
+	/// Returns the width and height of the physical screen, in millimeters,
+	/// retrieved with [`co::GDC::HORZSIZE`](crate::co::GDC::HORZSIZE) and
+	/// [`co::GDC::VERTSIZE`](crate::co::GDC::VERTSIZE) with
+	/// [`HDC::GetDeviceCaps`](crate::prelude::gdi_Hdc::GetDeviceCaps).
+	#[must_use]
+	fn physical_size_mm(&self) -> (i32, i32) {
+		(
+			self.GetDeviceCaps(co::GDC::HORZSIZE),
+			self.GetDeviceCaps(co::GDC::VERTSIZE),
+		)
+	}
+
 	/// [`AborthPath`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-abortpath)
 	/// function.
 	fn AbortPath(&self) -> SysResult<()> {
@@ -18,6 +18,20 @@ impl gdi_Hinstance for HINSTANCE {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait gdi_Hinstance: user_Hinstance {
+	/// [`LoadBitmap`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-loadbitmapw)
+	/// function.
+	///
+	/// This function has been superseded by
+	/// [`HINSTANCE::LoadImageBitmap`](crate::prelude::gdi_Hinstance::LoadImageBitmap),
+	/// which offers more options.
+	#[must_use]
+	fn LoadBitmap(&self, name: IdObmStr) -> SysResult<DeleteObjectGuard<HBITMAP>> {
+		unsafe {
+			ptr_to_sysresult_handle(ffi::LoadBitmapW(self.ptr(), name.as_ptr()))
+				.map(|h| DeleteObjectGuard::new(h))
+		}
+	}
+
 	/// [`LoadImage`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-loadimagew)
 	/// method for [`HBITMAP`](crate::HBITMAP).
 	#[must_use]
@@ -20,6 +20,18 @@ impl gdi_Hrgn for HRGN {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait gdi_Hrgn: Handle {
+	/// [`CreateEllipticRgn`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-createellipticrgn)
+	/// function.
+	#[must_use]
+	fn CreateEllipticRgn(bounds: RECT) -> SysResult<DeleteObjectGuard<HRGN>> {
+		unsafe {
+			ptr_to_sysresult_handle(
+				ffi::CreateEllipticRgn(
+					bounds.left, bounds.top, bounds.right, bounds.bottom),
+			).map(|h| DeleteObjectGuard::new(h))
+		}
+	}
+
 	/// [`CreateRectRgn`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-createrectrgn)
 	/// function.
 	#[must_use]
@@ -54,7 +66,7 @@ pub trait gdi_Hrgn: Handle {
 		unsafe {
 			ptr_to_sysresult_handle(
 				ffi::CreateRoundRectRgn(
-					bounds.left, bounds.top, bounds.right, bounds.top,
+					bounds.left, bounds.top, bounds.right, bounds.bottom,
 					size.cx, size.cy,
 				),
 			).map(|h| DeleteObjectGuard::new(h))
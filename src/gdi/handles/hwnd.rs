@@ -0,0 +1,63 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::decl::*;
+use crate::gdi::ffi;
+use crate::guard::*;
+use crate::kernel::privs::*;
+use crate::prelude::*;
+
+impl gdi_Hwnd for HWND {}
+
+/// This trait is enabled with the `gdi` feature, and provides methods for
+/// [`HWND`](crate::HWND).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait gdi_Hwnd: user_Hwnd {
+	/// [`SetWindowRgn`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setwindowrgn)
+	/// function.
+	///
+	/// After a successful call, the system owns the region, so the guard is
+	/// leaked; if the call fails, the region is deleted. Pass `None` to remove
+	/// the window region.
+	///
+	/// # Examples
+	///
+	/// Applying an elliptical region:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// let hrgn = w::HRGN::CreateEllipticRgn(w::RECT {
+	///     left: 0, top: 0, right: 200, bottom: 100,
+	/// })?;
+	///
+	/// hwnd.SetWindowRgn(Some(hrgn), true)?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	fn SetWindowRgn(&self,
+		hrgn: Option<DeleteObjectGuard<HRGN>>,
+		redraw: bool,
+	) -> SysResult<()>
+	{
+		bool_to_sysresult(
+			unsafe {
+				ffi::SetWindowRgn(
+					self.ptr(),
+					hrgn.as_ref().map_or(std::ptr::null_mut(), |h| h.ptr()),
+					redraw as _,
+				)
+			},
+		).map(|_| {
+			if let Some(mut hrgn) = hrgn {
+				let _ = hrgn.leak(); // now owned by the system
+			}
+		})
+	}
+}
@@ -1,4 +1,6 @@
 mod gdi_traits;
+mod gpbitmap;
+mod gpimage;
 mod hbitmap;
 mod hbrush;
 mod hdc;
@@ -7,8 +9,11 @@ mod hinstance;
 mod hpalette;
 mod hpen;
 mod hrgn;
+mod hwnd;
 
 pub mod decl {
+	pub use super::gpbitmap::GpBitmap;
+	pub use super::gpimage::GpImage;
 	pub use super::hfont::HFONT;
 	pub use super::hpalette::HPALETTE;
 	pub use super::hpen::HPEN;
@@ -16,6 +21,8 @@ pub mod decl {
 
 pub mod traits {
 	pub use super::gdi_traits::*;
+	pub use super::gpbitmap::gdi_Gpbitmap;
+	pub use super::gpimage::gdi_Gpimage;
 	pub use super::hbitmap::gdi_Hbitmap;
 	pub use super::hbrush::gdi_Hbrush;
 	pub use super::hdc::gdi_Hdc;
@@ -24,4 +31,5 @@ pub mod traits {
 	pub use super::hpalette::gdi_Hpalette;
 	pub use super::hpen::gdi_Hpen;
 	pub use super::hrgn::gdi_Hrgn;
+	pub use super::hwnd::gdi_Hwnd;
 }
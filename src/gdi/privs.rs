@@ -1,4 +1,14 @@
+use crate::co;
+
 pub(crate) const CLR_INVALID: u32 = 0xffff_ffff;
 pub(crate) const GDI_ERROR: u32 = 0xffff_ffff;
 pub(crate) const HIMETRIC_PER_INCH: i32 = 2540;
 pub(crate) const LF_FACESIZE: usize = 32;
+
+/// If value is `Ok`, yields `Ok(())`, otherwise `Err(status)`.
+pub(crate) const fn gpstatus_to_result(status: i32) -> Result<(), co::GPSTATUS> {
+	match unsafe { co::GPSTATUS::from_raw(status) } {
+		co::GPSTATUS::OK => Ok(()),
+		status => Err(status),
+	}
+}
@@ -1,5 +1,7 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
+use std::marker::PhantomData;
+
 use crate::co;
 use crate::decl::*;
 use crate::gdi::privs::*;
@@ -86,6 +88,37 @@ impl BITMAPINFOHEADER {
 	pub_fn_serialize!();
 }
 
+/// [`CHOOSEFONT`](https://learn.microsoft.com/en-us/windows/win32/api/commdlg/ns-commdlg-choosefontw)
+/// struct.
+#[repr(C)]
+pub struct CHOOSEFONT<'a> {
+	lStructSize: u32,
+	pub hwndOwner: HWND,
+	pub hDC: HDC,
+	lpLogFont: *mut LOGFONT,
+	pub iPointSize: i32,
+	pub Flags: co::CHOOSEFONT,
+	pub rgbColors: COLORREF,
+	pub lCustData: isize,
+	pub lpfnHook: Option<CFHOOKPROC>,
+	lpTemplateName: *mut u16, // u16 resource ID
+	pub hInstance: HINSTANCE,
+	lpszStyle: *mut u16,
+	pub nFontType: co::FONTTYPE,
+	pub nSizeMin: i32,
+	pub nSizeMax: i32,
+
+	_lpLogFont: PhantomData<&'a mut LOGFONT>,
+}
+
+impl_default_with_size!(CHOOSEFONT, lStructSize, 'a);
+
+impl<'a> CHOOSEFONT<'a> {
+	pub_fn_ptr_get_set!('a, lpLogFont, set_lpLogFont, LOGFONT);
+	pub_fn_resource_id_get_set!(lpTemplateName, set_lpTemplateName);
+	pub_fn_string_ptr_get_set!('a, lpszStyle, set_lpszStyle);
+}
+
 /// [`LOGBRUSH`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-logbrush)
 /// struct.
 #[repr(C)]
@@ -0,0 +1,6 @@
+use crate::kernel::ffi_types::*;
+
+extern_sys! { "gdiplus";
+	GdipCreateBitmapFromStream(COMPTR, *mut HANDLE) -> i32
+	GdipLoadImageFromStream(COMPTR, *mut HANDLE) -> i32
+}
@@ -0,0 +1,38 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::gdi::privs::*;
+use crate::gdi_ole::ffi;
+use crate::guard::*;
+use crate::prelude::*;
+
+impl gdi_ole_Gpbitmap for GpBitmap {}
+
+/// This trait is enabled with `gdi` and `ole` features, and provides methods
+/// for [`GpBitmap`](crate::GpBitmap).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait gdi_ole_Gpbitmap: gdi_Gpbitmap {
+	/// [`GdipCreateBitmapFromStream`](https://learn.microsoft.com/en-us/windows/win32/gdiplus/-gdiplus-bitmap-flat)
+	/// function.
+	///
+	/// The stream must remain valid while the bitmap is in use.
+	#[must_use]
+	fn GdipCreateBitmapFromStream<'a>(
+		gdiplus: &'a GdiplusShutdownGuard,
+		stream: &impl ole_IStream,
+	) -> Result<GdipDisposeImageGuard<'a, GpBitmap>, co::GPSTATUS>
+	{
+		let mut bitmap = GpBitmap::NULL;
+		unsafe {
+			gpstatus_to_result(
+				ffi::GdipCreateBitmapFromStream(stream.ptr(), bitmap.as_mut()),
+			).map(|_| GdipDisposeImageGuard::new(gdiplus, bitmap))
+		}
+	}
+}
@@ -0,0 +1,38 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::gdi::privs::*;
+use crate::gdi_ole::ffi;
+use crate::guard::*;
+use crate::prelude::*;
+
+impl gdi_ole_Gpimage for GpImage {}
+
+/// This trait is enabled with `gdi` and `ole` features, and provides methods
+/// for [`GpImage`](crate::GpImage).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait gdi_ole_Gpimage: gdi_Gpimage {
+	/// [`GdipLoadImageFromStream`](https://learn.microsoft.com/en-us/windows/win32/gdiplus/-gdiplus-image-flat)
+	/// function.
+	///
+	/// The stream must remain valid while the image is in use.
+	#[must_use]
+	fn GdipLoadImageFromStream<'a>(
+		gdiplus: &'a GdiplusShutdownGuard,
+		stream: &impl ole_IStream,
+	) -> Result<GdipDisposeImageGuard<'a, GpImage>, co::GPSTATUS>
+	{
+		let mut image = GpImage::NULL;
+		unsafe {
+			gpstatus_to_result(
+				ffi::GdipLoadImageFromStream(stream.ptr(), image.as_mut()),
+			).map(|_| GdipDisposeImageGuard::new(gdiplus, image))
+		}
+	}
+}
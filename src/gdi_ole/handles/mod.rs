@@ -0,0 +1,7 @@
+mod gpbitmap;
+mod gpimage;
+
+pub mod traits {
+	pub use super::gpbitmap::gdi_ole_Gpbitmap;
+	pub use super::gpimage::gdi_ole_Gpimage;
+}
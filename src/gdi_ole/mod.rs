@@ -0,0 +1,8 @@
+#![cfg(all(feature = "gdi", feature = "ole"))]
+
+mod ffi;
+mod handles;
+
+pub mod traits {
+	pub use super::handles::traits::*;
+}
@@ -0,0 +1,58 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::ole::privs::*;
+use crate::prelude::*;
+use crate::vt::*;
+
+impl gdi_shell_IShellItemImageFactory for IShellItemImageFactory {}
+
+/// This trait is enabled with `gdi` and `shell` features, and provides methods
+/// for [`IShellItemImageFactory`](crate::IShellItemImageFactory).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait gdi_shell_IShellItemImageFactory: shell_IShellItemImageFactory {
+	/// [`IShellItemImageFactory::GetImage`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellitemimagefactory-getimage)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// Retrieving a 96 x 96 thumbnail:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let factory: w::IShellItemImageFactory; // initialized somewhere
+	/// # let factory = unsafe { w::IShellItemImageFactory::null() };
+	///
+	/// let hbmp = factory.GetImage(
+	///     w::SIZE::new(96, 96),
+	///     co::SIIGBF::THUMBNAILONLY,
+	/// )?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn GetImage(&self,
+		size: SIZE,
+		flags: co::SIIGBF,
+	) -> HrResult<DeleteObjectGuard<HBITMAP>>
+	{
+		let mut hbmp = HBITMAP::NULL;
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IShellItemImageFactoryVT>(self).GetImage)(
+					self.ptr(),
+					MAKEQWORD(size.cx as _, size.cy as _),
+					flags.raw(),
+					hbmp.as_mut(),
+				)
+			},
+		).map(|_| unsafe { DeleteObjectGuard::new(hbmp) })
+	}
+}
@@ -0,0 +1,5 @@
+mod ishellitemimagefactory;
+
+pub mod traits {
+	pub use super::ishellitemimagefactory::gdi_shell_IShellItemImageFactory;
+}
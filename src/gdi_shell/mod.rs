@@ -0,0 +1,7 @@
+#![cfg(all(feature = "gdi", feature = "shell"))]
+
+mod com_interfaces;
+
+pub mod traits {
+	pub use super::com_interfaces::traits::*;
+}
@@ -387,6 +387,11 @@ pub trait GuiEvents {
 		/// message.
 	}
 
+	fn_wm_withparm_noret! { wm_hot_key, co::WM::HOTKEY, wm::HotKey;
+		/// [`WM_HOTKEY`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-hotkey)
+		/// message.
+	}
+
 	fn_wm_withparm_noret! { wm_h_scroll, co::WM::HSCROLL, wm::HScroll;
 		/// [`WM_HSCROLL`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-hscroll)
 		/// message.
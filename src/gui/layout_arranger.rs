@@ -40,6 +40,53 @@ pub enum Vert {
 	Resize,
 }
 
+/// Computes the new position and size of a control, given its original
+/// coordinates relative to the parent, how much the parent client area has
+/// changed since then, and the control's resize behavior.
+///
+/// This is the same math used internally by the windows of the `gui` module,
+/// and it can be used with [`HDWP`](crate::HDWP) to arrange controls manually.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, gui};
+///
+/// let rc_orig = w::RECT { left: 10, top: 10, right: 110, bottom: 30 };
+/// let parent_delta = w::SIZE::new(50, 20); // parent grew 50 x 20 pixels
+///
+/// let rc = gui::anchored_rect(
+///     rc_orig, parent_delta, (gui::Horz::Repos, gui::Vert::Repos));
+/// assert!(rc == w::RECT { left: 60, top: 30, right: 160, bottom: 50 });
+/// ```
+#[must_use]
+pub fn anchored_rect(
+	rc_orig: RECT,
+	parent_delta: SIZE,
+	resize_behavior: (Horz, Vert),
+) -> RECT
+{
+	let (horz, vert) = resize_behavior;
+	RECT {
+		left: match horz {
+			Horz::Repos => rc_orig.left + parent_delta.cx,
+			_ => rc_orig.left, // keep original x pos
+		},
+		top: match vert {
+			Vert::Repos => rc_orig.top + parent_delta.cy,
+			_ => rc_orig.top, // keep original y pos
+		},
+		right: match horz {
+			Horz::None => rc_orig.right,
+			_ => rc_orig.right + parent_delta.cx,
+		},
+		bottom: match vert {
+			Vert::None => rc_orig.bottom,
+			_ => rc_orig.bottom + parent_delta.cy,
+		},
+	}
+}
+
 struct ChildInfo {
 	hchild: HWND,
 	horz: Horz,
@@ -138,29 +185,20 @@ impl LayoutArranger {
 				},
 			};
 
+			let rc = anchored_rect(
+				rc_orig,
+				SIZE::new(
+					p.client_area.cx - sz_parent_orig.cx,
+					p.client_area.cy - sz_parent_orig.cy,
+				),
+				(ctrl.horz, ctrl.vert),
+			);
+
 			hdwp.DeferWindowPos(
 				&ctrl.hchild,
 				HwndPlace::None,
-				POINT::new(
-					match ctrl.horz {
-						Horz::Repos => p.client_area.cx - sz_parent_orig.cx + rc_orig.left,
-						_ => rc_orig.left // keep original x pos
-					},
-					match ctrl.vert {
-						Vert::Repos => p.client_area.cy - sz_parent_orig.cy + rc_orig.top,
-						_ => rc_orig.top // keep original y pos
-					},
-				),
-				SIZE::new(
-					match ctrl.horz {
-						Horz::Resize => p.client_area.cx - sz_parent_orig.cx + rc_orig.right - rc_orig.left,
-						_ => rc_orig.right - rc_orig.left // keep original width
-					},
-					match ctrl.vert {
-						Vert::Resize => p.client_area.cy - sz_parent_orig.cy + rc_orig.bottom - rc_orig.top,
-						_ => rc_orig.bottom - rc_orig.top // keep original height
-					},
-				),
+				POINT::new(rc.left, rc.top),
+				SIZE::new(rc.right - rc.left, rc.bottom - rc.top),
 				uflags,
 			)?;
 		}
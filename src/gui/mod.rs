@@ -49,7 +49,7 @@ pub(in crate::gui) mod privs {
 
 pub mod events;
 
-pub use layout_arranger::{Horz, Vert};
+pub use layout_arranger::{anchored_rect, Horz, Vert};
 pub use msg_error::MsgError;
 pub use native_controls::*;
 pub use raw_base::{Brush, Cursor, Icon};
@@ -83,8 +83,7 @@ pub(in crate::gui) fn multiply_dpi(
 	unsafe {
 		if (pt.is_some() || sz.is_some()) && DPI.x == 0 { // DPI not cached yet?
 			let screen_dc = HWND::NULL.GetDC()?;
-			DPI.x = screen_dc.GetDeviceCaps(co::GDC::LOGPIXELSX); // cache
-			DPI.y = screen_dc.GetDeviceCaps(co::GDC::LOGPIXELSY);
+			(DPI.x, DPI.y) = screen_dc.logical_dpi(); // cache
 		}
 
 		if let Some(pt) = pt {
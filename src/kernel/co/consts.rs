@@ -217,6 +217,27 @@ const_ordinary! { EVENTLOG: u16;
 	WARNING_TYPE 0x0002
 }
 
+const_bitflag! { DUPLICATE: u32;
+	/// [`DuplicateHandle`](crate::prelude::HandleDuplicate::DuplicateHandle)
+	/// `options` (`u32`).
+	=>
+	=>
+	CLOSE_SOURCE 0x0000_0001
+	SAME_ACCESS 0x0000_0002
+}
+
+const_ordinary! { FILE_ACTION: u32;
+	/// [`HFILE::ReadDirectoryChanges`](crate::prelude::kernel_Hfile::ReadDirectoryChanges)
+	/// returned action (`u32`).
+	=>
+	=>
+	ADDED 0x0000_0001
+	REMOVED 0x0000_0002
+	MODIFIED 0x0000_0003
+	RENAMED_OLD_NAME 0x0000_0004
+	RENAMED_NEW_NAME 0x0000_0005
+}
+
 const_bitflag! { FILE_ATTRIBUTE: u32;
 	/// File
 	/// [attributes](https://learn.microsoft.com/en-us/windows/win32/fileio/file-attribute-constants)
@@ -291,6 +312,37 @@ const_bitflag! { FILE_MAP: u32;
 	TARGETS_INVALID 0x4000_0000
 }
 
+const_bitflag! { FILE_NAME: u32;
+	/// [`HFILE::GetFinalPathNameByHandle`](crate::prelude::kernel_Hfile::GetFinalPathNameByHandle)
+	/// `flags` (`u32`).
+	///
+	/// Originally has `FILE_NAME` and `VOLUME_NAME` prefixes.
+	=>
+	=>
+	NORMALIZED 0x0
+	OPENED 0x8
+
+	VOLUME_NAME_DOS 0x0
+	VOLUME_NAME_GUID 0x1
+	VOLUME_NAME_NT 0x2
+	VOLUME_NAME_NONE 0x4
+}
+
+const_bitflag! { FILE_NOTIFY_CHANGE: u32;
+	/// [`HFILE::ReadDirectoryChanges`](crate::prelude::kernel_Hfile::ReadDirectoryChanges)
+	/// `notify_filter` (`u32`).
+	=>
+	=>
+	FILE_NAME 0x0000_0001
+	DIR_NAME 0x0000_0002
+	ATTRIBUTES 0x0000_0004
+	SIZE 0x0000_0008
+	LAST_WRITE 0x0000_0010
+	LAST_ACCESS 0x0000_0020
+	CREATION 0x0000_0040
+	SECURITY 0x0000_0100
+}
+
 const_bitflag! { FILE_SHARE: u32;
 	/// [`HFILE::CreateFile`](crate::prelude::kernel_Hfile::CreateFile)
 	/// `share_mode` (`u32`).
@@ -421,6 +473,16 @@ const_bitflag! { GENERIC: u32;
 	ALL 0x1000_0000
 }
 
+const_bitflag! { GET_MODULE_HANDLE_EX_FLAG: u32;
+	/// [`HINSTANCE::GetModuleHandleEx`](crate::prelude::kernel_Hinstance::GetModuleHandleEx)
+	/// `flags` (`u32`).
+	=>
+	=>
+	PIN 0x0000_0001
+	UNCHANGED_REFCOUNT 0x0000_0002
+	FROM_ADDRESS 0x0000_0004
+}
+
 const_bitflag! { GMEM: u32;
 	/// [`HGLOBAL::GlobalAlloc`](crate::prelude::kernel_Hglobal::GlobalAlloc)
 	/// and
@@ -721,11 +783,37 @@ const_bitflag! { MBC: u32;
 	USEGLYPHCHARS 0x0000_0004
 }
 
-const_ordinary! { PAGE: u32;
+const_bitflag! { MEM: u32;
+	/// [`VirtualAlloc`](crate::VirtualAlloc) `alloc_type` and
+	/// [`VirtualFree`](crate::VirtualFree) `free_type` (`u32`).
+	=>
+	=>
+	COALESCE_PLACEHOLDERS 0x0000_0001
+	PRESERVE_PLACEHOLDER 0x0000_0002
+	COMMIT 0x0000_1000
+	RESERVE 0x0000_2000
+	REPLACE_PLACEHOLDER 0x0000_4000
+	DECOMMIT 0x0000_4000
+	RELEASE 0x0000_8000
+	RESERVE_PLACEHOLDER 0x0004_0000
+	RESET 0x0008_0000
+	TOP_DOWN 0x0010_0000
+	WRITE_WATCH 0x0020_0000
+	PHYSICAL 0x0040_0000
+	RESET_UNDO 0x0100_0000
+	LARGE_PAGES 0x2000_0000
+}
+
+const_bitflag! { PAGE: u32;
 	/// [`HFILE::CreateFileMapping`](crate::prelude::kernel_Hfile::CreateFileMapping)
-	/// `protect` (`u32`).
+	/// `protect`, [`VirtualAlloc`](crate::VirtualAlloc) `protect` and
+	/// [`VirtualProtect`](crate::VirtualProtect) `new_protect` (`u32`).
 	=>
 	=>
+	/// Disables all access to the committed region of pages.
+	NOACCESS 0x01
+	/// Enables execute access to the committed region of pages.
+	EXECUTE 0x10
 	/// Allows views to be mapped for read-only copy-on-write or execute
 	/// access.
 	///
@@ -767,6 +855,13 @@ const_ordinary! { PAGE: u32;
 	/// The file handle must be created with the
 	/// [`GENERIC::READ`](crate::co::GENERIC::READ) access right.
 	WRITECOPY 0x08
+	/// Pages in the region become guard pages. Must be combined with another
+	/// protection value.
+	GUARD 0x100
+	/// Sets all pages to be non-cachable.
+	NOCACHE 0x200
+	/// Sets all pages to be write-combined.
+	WRITECOMBINE 0x400
 
 	SEC_COMMIT 0x800_0000
 	SEC_IMAGE 0x100_0000
@@ -777,6 +872,37 @@ const_ordinary! { PAGE: u32;
 	SEC_WRITECOMBINE 0x4000_0000
 }
 
+const_bitflag! { PIPE: u32;
+	/// [`HPIPE::CreateNamedPipe`](crate::prelude::kernel_Hpipe::CreateNamedPipe)
+	/// `pipe_mode` (`u32`).
+	=>
+	=>
+	TYPE_BYTE 0x0000_0000
+	TYPE_MESSAGE 0x0000_0004
+	READMODE_BYTE 0x0000_0000
+	READMODE_MESSAGE 0x0000_0002
+	WAIT 0x0000_0000
+	NOWAIT 0x0000_0001
+	ACCEPT_REMOTE_CLIENTS 0x0000_0000
+	REJECT_REMOTE_CLIENTS 0x0000_0008
+}
+
+const_bitflag! { PIPE_ACCESS: u32;
+	/// [`HPIPE::CreateNamedPipe`](crate::prelude::kernel_Hpipe::CreateNamedPipe)
+	/// `open_mode` (`u32`).
+	///
+	/// Also includes the `FILE_FLAG` values which can be used with named pipes.
+	=>
+	=>
+	INBOUND 0x0000_0001
+	OUTBOUND 0x0000_0002
+	DUPLEX 0x0000_0003
+
+	FIRST_PIPE_INSTANCE 0x0008_0000
+	OVERLAPPED 0x4000_0000
+	WRITE_THROUGH 0x8000_0000
+}
+
 const_bitflag! { PRIORITY_CLASS: u32;
 	/// [`GetPriorityClass`](crate::prelude::kernel_Hprocess::GetPriorityClass)
 	/// and
@@ -70,6 +70,23 @@ impl IdStr {
 	}
 }
 
+/// A module identifier.
+///
+/// Variable parameter for:
+///
+/// * [`HINSTANCE::GetModuleHandleEx`](crate::prelude::kernel_Hinstance::GetModuleHandleEx).
+#[derive(Clone)]
+pub enum ModuleNameAddr {
+	/// The executable file of the current process.
+	Exe,
+	/// The name of a module loaded by the current process.
+	Name(String),
+	/// An address within the module, like the address of a function.
+	/// [`co::GET_MODULE_HANDLE_EX_FLAG::FROM_ADDRESS`](crate::co::GET_MODULE_HANDLE_EX_FLAG::FROM_ADDRESS)
+	/// will be automatically added to the flags.
+	Addr(*const std::ffi::c_void),
+}
+
 /// Registry value types.
 ///
 /// This is a high-level abstraction over the [`co::REG`](crate::co::REG)
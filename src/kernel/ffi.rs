@@ -79,17 +79,27 @@ extern_sys! { "advapi32";
 
 extern_sys! { "kernel32";
 	BeginUpdateResourceW(PCSTR, BOOL) -> HANDLE
+	CancelIoEx(HANDLE, PVOID) -> BOOL
 	CheckRemoteDebuggerPresent(HANDLE, *mut BOOL) -> BOOL
 	CloseHandle(HANDLE) -> BOOL
+	CloseThreadpoolWork(HANDLE)
+	ConnectNamedPipe(HANDLE, PVOID) -> BOOL
 	CopyFileW(PCSTR, PCSTR, BOOL) -> BOOL
 	CreateDirectoryW(PCSTR, PVOID) -> BOOL
+	CreateEventW(PVOID, BOOL, BOOL, PCSTR) -> HANDLE
 	CreateFileMappingFromApp(HANDLE, PVOID, u32, u64, PCSTR) -> HANDLE
 	CreateFileW(PCSTR, u32, u32, PVOID, u32, u32, HANDLE) -> HANDLE
+	CreateNamedPipeW(PCSTR, u32, u32, u32, u32, u32, u32, PVOID) -> HANDLE
 	CreatePipe(*mut HANDLE, *mut HANDLE, PVOID, u32) -> BOOL
 	CreateProcessW(PCSTR, PSTR, PVOID, PVOID, BOOL, u32, PVOID, PCSTR, PVOID, PVOID) -> BOOL
 	CreateThread(PVOID, usize, PVOID, PVOID, u32, *mut u32) -> HANDLE
+	CreateThreadpoolWork(PFUNC, PVOID, PVOID) -> HANDLE
 	CreateToolhelp32Snapshot(u32, u32) -> HANDLE
+	DebugBreak()
 	DeleteFileW(PCSTR) -> BOOL
+	DeviceIoControl(HANDLE, u32, PCVOID, u32, PVOID, u32, *mut u32, PVOID) -> BOOL
+	DisconnectNamedPipe(HANDLE) -> BOOL
+	DuplicateHandle(HANDLE, HANDLE, HANDLE, *mut HANDLE, u32, BOOL, u32) -> BOOL
 	EndUpdateResourceW(HANDLE, BOOL) -> BOOL
 	EnumResourceLanguagesW(HANDLE, PCSTR, PCSTR, PFUNC, isize) -> BOOL
 	EnumResourceNamesW(HANDLE, PCSTR, PFUNC, isize) -> BOOL
@@ -129,16 +139,21 @@ extern_sys! { "kernel32";
 	GetFileSizeEx(HANDLE, *mut i64) -> BOOL
 	GetFileTime(HANDLE, PVOID, PVOID, PVOID) -> BOOL
 	GetFileType(HANDLE) -> u32
+	GetFinalPathNameByHandleW(HANDLE, PSTR, u32, u32) -> u32
 	GetFirmwareType(*mut u32) -> BOOL
+	GetFullPathNameW(PCSTR, u32, PSTR, *mut PSTR) -> u32
 	GetGuiResources(HANDLE, u32) -> u32
 	GetLargePageMinimum() -> usize
 	GetLastError() -> u32
 	GetLocalTime(PVOID)
 	GetLogicalDrives() -> u32
 	GetLogicalDriveStringsW(u32, PSTR) -> u32
+	GetLongPathNameW(PCSTR, PSTR, u32) -> u32
 	GetModuleFileNameW(HANDLE, PSTR, u32) -> u32
+	GetModuleHandleExW(u32, PCSTR, *mut HANDLE) -> BOOL
 	GetModuleHandleW(PCSTR) -> HANDLE
 	GetNativeSystemInfo(PVOID)
+	GetOverlappedResult(HANDLE, PVOID, *mut u32, BOOL) -> BOOL
 	GetPriorityClass(HANDLE) -> u32
 	GetProcAddress(HANDLE, *const u8) -> PCVOID
 	GetProcessHandleCount(HANDLE, &mut u32) -> BOOL
@@ -147,6 +162,7 @@ extern_sys! { "kernel32";
 	GetProcessId(HANDLE) -> u32
 	GetProcessIdOfThread(HANDLE) -> u32
 	GetProcessTimes(HANDLE, PVOID, PVOID, PVOID, PVOID) -> BOOL
+	GetShortPathNameW(PCSTR, PSTR, u32) -> u32
 	GetStartupInfoW(PVOID)
 	GetStdHandle(u32) -> HANDLE
 	GetSystemDirectoryW(PSTR, u32) -> u32
@@ -158,14 +174,19 @@ extern_sys! { "kernel32";
 	GetSystemTimes(PVOID, PVOID, PVOID) -> BOOL
 	GetTempFileNameW(PCSTR, PCSTR, u32, PSTR) -> u32
 	GetTempPathW(u32, PSTR) -> u32
+	GetThreadDescription(HANDLE, *mut PSTR) -> HRES
 	GetThreadId(HANDLE) -> u32
 	GetThreadTimes(HANDLE, PVOID, PVOID, PVOID, PVOID) -> BOOL
 	GetTickCount64() -> u64
 	GetVolumeInformationW(PCSTR, PSTR, u32, *mut u32, *mut u32, *mut u32, PSTR, u32) -> BOOL
 	GetVolumePathNameW(PCSTR, PSTR, u32) -> BOOL
+	GlobalAddAtomW(PCSTR) -> u16
 	GlobalAlloc(u32, usize) -> HANDLE
+	GlobalDeleteAtom(u16) -> u16
+	GlobalFindAtomW(PCSTR) -> u16
 	GlobalFlags(HANDLE) -> u32
 	GlobalFree(HANDLE) -> HANDLE
+	GlobalGetAtomNameW(u16, PSTR, i32) -> u32
 	GlobalLock(HANDLE) -> PVOID
 	GlobalMemoryStatusEx(PVOID) -> BOOL
 	GlobalReAlloc(HANDLE, usize, u32) -> HANDLE
@@ -211,17 +232,21 @@ extern_sys! { "kernel32";
 	OutputDebugStringW(PCSTR)
 	Process32FirstW(HANDLE, PVOID) -> BOOL
 	Process32NextW(HANDLE, PVOID) -> BOOL
+	ProcessIdToSessionId(u32, *mut u32) -> BOOL
 	QueryFullProcessImageNameW(HANDLE, u32, PSTR, *mut u32) -> BOOL
 	QueryPerformanceCounter(*mut i64) -> BOOL
 	QueryPerformanceFrequency(*mut i64) -> BOOL
 	QueryProcessAffinityUpdateMode(HANDLE, *mut u32) -> BOOL
 	ReadConsoleW(HANDLE, PVOID, u32, *mut u32, PVOID) -> BOOL
+	ReadDirectoryChangesW(HANDLE, PVOID, u32, BOOL, u32, *mut u32, PVOID, PFUNC) -> BOOL
 	ReadFile(HANDLE, PVOID, u32, *mut u32, PVOID) -> BOOL
 	ReplaceFileW(PCSTR, PCSTR, PCSTR, u32, PVOID, PVOID) -> BOOL
+	ResetEvent(HANDLE) -> BOOL
 	ResumeThread(HANDLE) -> u32
 	SetConsoleMode(HANDLE, u32) -> BOOL
 	SetCurrentDirectoryW(PCSTR) -> BOOL
 	SetEndOfFile(HANDLE) -> BOOL
+	SetEvent(HANDLE) -> BOOL
 	SetFileAttributesW(PCSTR, u32) -> BOOL
 	SetFilePointerEx(HANDLE, i64, *mut i64, u32) -> BOOL
 	SetFileTime(HANDLE, PCVOID, PCVOID, PCVOID) -> BOOL
@@ -229,12 +254,14 @@ extern_sys! { "kernel32";
 	SetPriorityClass(HANDLE, u32) -> BOOL
 	SetProcessAffinityUpdateMode(HANDLE, u32) -> BOOL
 	SetProcessPriorityBoost(HANDLE, BOOL) -> BOOL
+	SetThreadDescription(HANDLE, PCSTR) -> HRES
 	SetThreadIdealProcessor(HANDLE, u32) -> u32
 	SetThreadIdealProcessorEx(HANDLE, PCVOID, PVOID) -> BOOL
 	SetThreadPriorityBoost(HANDLE, BOOL) -> BOOL
 	SetThreadStackGuarantee(*mut u32) -> BOOL
 	SizeofResource(HANDLE, HANDLE) -> u32
 	Sleep(u32)
+	SubmitThreadpoolWork(HANDLE)
 	SuspendThread(HANDLE) -> u32
 	SwitchToThread() -> BOOL
 	SystemTimeToFileTime(PCVOID, PVOID) -> BOOL
@@ -248,10 +275,16 @@ extern_sys! { "kernel32";
 	UpdateResourceW(HANDLE, PCSTR, PCSTR, u16, PVOID, u32) -> BOOL
 	VerifyVersionInfoW(PVOID, u32, u64) -> BOOL
 	VerSetConditionMask(u64, u32, u8) -> u64
+	VirtualAlloc(PVOID, usize, u32, u32) -> PVOID
+	VirtualFree(PVOID, usize, u32) -> BOOL
+	VirtualProtect(PVOID, usize, u32, *mut u32) -> BOOL
 	WaitForSingleObject(HANDLE, u32) -> u32
+	WaitForThreadpoolWorkCallbacks(HANDLE, BOOL)
+	WaitNamedPipeW(PCSTR, u32) -> BOOL
 	WideCharToMultiByte(u32, u32, PCSTR, i32, PSTR, i32, *const u8, *mut BOOL) -> i32
 	WriteConsoleW(HANDLE, PCVOID, u32, *mut u32, PVOID) -> BOOL
 	WriteFile(HANDLE, PCVOID, u32, *mut u32, PVOID) -> BOOL
+	WTSGetActiveConsoleSessionId() -> u32
 }
 
 extern_sys! { "ktmw32";
@@ -262,6 +295,18 @@ extern_sys! { "ktmw32";
 	RollbackTransaction(HANDLE) -> BOOL
 }
 
+extern_sys! { "ntdll";
+	RtlGetVersion(PVOID) -> i32
+	RtlNtStatusToDosError(i32) -> u32
+}
+
+extern_sys! { "psapi";
+	EnumProcessModules(HANDLE, *mut HANDLE, u32, *mut u32) -> BOOL
+	EnumProcesses(*mut u32, u32, *mut u32) -> BOOL
+	GetModuleBaseNameW(HANDLE, HANDLE, PSTR, u32) -> u32
+	GetProcessMemoryInfo(HANDLE, PVOID, u32) -> BOOL
+}
+
 // This block should really be kernel.
 extern_sys! { "user32";
 	CharLowerW(PSTR) -> PSTR
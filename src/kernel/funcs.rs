@@ -215,6 +215,25 @@ pub fn CreateWellKnownSid(
 	}
 }
 
+/// [`DebugBreak`](https://learn.microsoft.com/en-us/windows/win32/api/debugapi/nf-debugapi-debugbreak)
+/// function.
+///
+/// If the process is not being debugged, this will usually terminate it. You
+/// may want to check [`IsDebuggerPresent`](crate::IsDebuggerPresent) first.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// if w::IsDebuggerPresent() {
+///     w::DebugBreak();
+/// }
+/// ```
+pub fn DebugBreak() {
+	unsafe { ffi::DebugBreak() }
+}
+
 /// [`DeleteFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-deletefilew)
 /// function.
 pub fn DeleteFile(file_name: &str) -> SysResult<()> {
@@ -252,6 +271,53 @@ pub fn EncryptionDisable(dir_path: &str, disable: bool) -> SysResult<()> {
 	)
 }
 
+/// [`EnumProcesses`](https://learn.microsoft.com/en-us/windows/win32/api/psapi/nf-psapi-enumprocesses)
+/// function.
+///
+/// Returns the identifiers of all running processes.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// for pid in w::EnumProcesses()? {
+///     if let Ok(hprocess) = w::HPROCESS::OpenProcess(
+///         co::PROCESS::QUERY_INFORMATION | co::PROCESS::VM_READ,
+///         false,
+///         pid,
+///     ) {
+///         if let Ok(name) = hprocess.GetModuleBaseName(None) {
+///             println!("{} {}", pid, name);
+///         } // protected processes are skipped
+///     }
+/// }
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[must_use]
+pub fn EnumProcesses() -> SysResult<Vec<u32>> {
+	let mut pids = vec![0u32; 1024];
+	loop {
+		let mut bytes_returned = u32::default();
+		bool_to_sysresult(
+			unsafe {
+				ffi::EnumProcesses(
+					pids.as_mut_ptr(),
+					(pids.len() * std::mem::size_of::<u32>()) as _,
+					&mut bytes_returned,
+				)
+			},
+		)?;
+
+		let count = bytes_returned as usize / std::mem::size_of::<u32>();
+		if count < pids.len() {
+			pids.truncate(count);
+			return Ok(pids);
+		}
+		pids.resize(pids.len() * 2, 0); // buffer may be too small, try again
+	}
+}
+
 /// [`EqualDomainSid`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-equaldomainsid)
 /// function.
 #[must_use]
@@ -377,6 +443,43 @@ pub fn FlushProcessWriteBuffers() {
 /// You don't need to call this function: all error types implement the
 /// [`FormattedError`](crate::prelude::FormattedError) trait which will
 /// automatically call `FormatMessage`.
+///
+/// The message can also come from a module message table, with
+/// [`FORMAT_MESSAGE::FROM_HMODULE`](crate::co::FORMAT_MESSAGE::FROM_HMODULE),
+/// or from a string, with
+/// [`FORMAT_MESSAGE::FROM_STRING`](crate::co::FORMAT_MESSAGE::FROM_STRING).
+/// The buffer allocated by the system is automatically freed.
+///
+/// # Safety
+///
+/// The `source` pointer must match the `flags`, and `args` must contain
+/// valid pointers for all the insertions in the message, if
+/// [`FORMAT_MESSAGE::ARGUMENT_ARRAY`](crate::co::FORMAT_MESSAGE::ARGUMENT_ARRAY)
+/// is passed.
+///
+/// # Examples
+///
+/// Formatting a string with an insertion argument:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let fmt = w::WString::from_str("Hello, %1!");
+/// let name = w::WString::from_str("world");
+///
+/// let text = unsafe {
+///     w::FormatMessage(
+///         co::FORMAT_MESSAGE::ALLOCATE_BUFFER
+///             | co::FORMAT_MESSAGE::FROM_STRING
+///             | co::FORMAT_MESSAGE::ARGUMENT_ARRAY,
+///         Some(fmt.as_ptr() as _),
+///         0,
+///         w::LANGID::USER_DEFAULT,
+///         Some(&[name.as_ptr() as _]),
+///     )?
+/// };
+/// # Ok::<_, co::ERROR>(())
+/// ```
 #[must_use]
 pub unsafe fn FormatMessage(
 	flags: co::FORMAT_MESSAGE,
@@ -434,6 +537,17 @@ pub fn GetCommandLine() -> String {
 
 /// [`GetComputerName`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-getcomputernamew)
 /// function.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let computer = w::GetComputerName()?;
+/// let user = w::GetUserName()?;
+/// println!("Running as {} on {}", user, computer);
+/// # Ok::<_, co::ERROR>(())
+/// ```
 #[must_use]
 pub fn GetComputerName() -> SysResult<String> {
 	let mut buf = WString::new_alloc_buf(MAX_COMPUTERNAME_LENGTH + 1);
@@ -572,6 +686,31 @@ pub fn GetFirmwareType() -> SysResult<co::FIRMWARE_TYPE> {
 		.map(|_| unsafe { co::FIRMWARE_TYPE::from_raw(ft) })
 }
 
+/// [`GetFullPathName`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getfullpathnamew)
+/// function.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let full = w::GetFullPathName("..\\foo.txt")?;
+/// println!("{}", full);
+/// # Ok::<_, w::co::ERROR>(())
+/// ```
+#[must_use]
+pub fn GetFullPathName(file_name: &str) -> SysResult<String> {
+	let wfile_name = WString::from_str(file_name);
+	grow_path_buf(|buf, buf_len| unsafe {
+		ffi::GetFullPathNameW(
+			wfile_name.as_ptr(),
+			buf_len,
+			buf,
+			std::ptr::null_mut(),
+		)
+	})
+}
+
 /// [`GetLargePageMinimum`](https://learn.microsoft.com/en-us/windows/win32/api/memoryapi/nf-memoryapi-getlargepageminimum)
 /// function.
 #[must_use]
@@ -606,6 +745,22 @@ pub fn GetLogicalDrives() -> u32 {
 
 /// [`GetLogicalDriveStrings`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getlogicaldrivestringsw)
 /// function.
+///
+/// # Examples
+///
+/// Listing all drives along with their file system names:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// for drive in w::GetLogicalDriveStrings()?.iter() {
+///     let mut sys_name = String::default();
+///     w::GetVolumeInformation(
+///         Some(drive), None, None, None, None, Some(&mut sys_name))?;
+///     println!("{} {}", drive, sys_name);
+/// }
+/// # Ok::<_, co::ERROR>(())
+/// ```
 #[must_use]
 pub fn GetLogicalDriveStrings() -> SysResult<Vec<String>> {
 	let len = match unsafe {
@@ -622,6 +777,16 @@ pub fn GetLogicalDriveStrings() -> SysResult<Vec<String>> {
 	).map(|_| parse_multi_z_str(buf.as_ptr()))
 }
 
+/// [`GetLongPathName`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getlongpathnamew)
+/// function.
+#[must_use]
+pub fn GetLongPathName(short_path: &str) -> SysResult<String> {
+	let wshort_path = WString::from_str(short_path);
+	grow_path_buf(|buf, buf_len| unsafe {
+		ffi::GetLongPathNameW(wshort_path.as_ptr(), buf, buf_len)
+	})
+}
+
 /// [`GetFileAttributes`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getfileattributesw)
 /// function.
 ///
@@ -684,6 +849,29 @@ pub fn GetNativeSystemInfo(si: &mut SYSTEM_INFO) {
 	unsafe { ffi::GetNativeSystemInfo(si as *mut _ as _) }
 }
 
+/// [`GetShortPathName`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getshortpathnamew)
+/// function.
+///
+/// # Examples
+///
+/// Converting a path to its short form, and back:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let short = w::GetShortPathName("C:\\Program Files")?;
+/// let long = w::GetLongPathName(&short)?;
+/// println!("{} -> {}", short, long);
+/// # Ok::<_, w::co::ERROR>(())
+/// ```
+#[must_use]
+pub fn GetShortPathName(long_path: &str) -> SysResult<String> {
+	let wlong_path = WString::from_str(long_path);
+	grow_path_buf(|buf, buf_len| unsafe {
+		ffi::GetShortPathNameW(wlong_path.as_ptr(), buf, buf_len)
+	})
+}
+
 /// [`GetSidLengthRequired`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-getsidlengthrequired)
 /// function.
 #[must_use]
@@ -832,6 +1020,20 @@ pub fn GetTempPath() -> SysResult<String> {
 
 /// [`GetTickCount64`](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-gettickcount64)
 /// function.
+///
+/// Returns the number of milliseconds since the system was started. Unlike
+/// `GetTickCount`, this value does not wrap around after 49.7 days.
+///
+/// # Examples
+///
+/// Retrieving the system uptime:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let uptime = std::time::Duration::from_millis(w::GetTickCount64());
+/// println!("Up for {} hours", uptime.as_secs() / 3600);
+/// ```
 #[must_use]
 pub fn GetTickCount64() -> u64 {
 	unsafe { ffi::GetTickCount64() }
@@ -839,6 +1041,19 @@ pub fn GetTickCount64() -> u64 {
 
 /// [`GetUserName`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-getusernamew)
 /// function.
+///
+/// The buffer is sized by a first call to the function, so names of any
+/// length are retrieved.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let user = w::GetUserName()?;
+/// println!("Current user: {}", user);
+/// # Ok::<_, co::ERROR>(())
+/// ```
 #[must_use]
 pub fn GetUserName() -> SysResult<String> {
 	let mut name_sz = u32::default();
@@ -980,6 +1195,74 @@ pub fn GetWindowsAccountDomainSid(sid: &SID) -> SysResult<SidGuard> {
 	}
 }
 
+/// [`GlobalAddAtom`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-globaladdatomw)
+/// function.
+///
+/// Each successful call increments the reference count of the atom, which
+/// must be balanced by a call to [`GlobalDeleteAtom`](crate::GlobalDeleteAtom),
+/// otherwise the atom will remain in the global atom table until the system
+/// restarts.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let atom = w::GlobalAddAtom("my_app_atom")?;
+///
+/// let found = w::GlobalFindAtom("my_app_atom")?;
+/// assert_eq!(atom, found);
+/// println!("{}", w::GlobalGetAtomName(atom)?);
+///
+/// w::GlobalDeleteAtom(atom)?;
+/// # Ok::<_, w::co::ERROR>(())
+/// ```
+pub fn GlobalAddAtom(name: &str) -> SysResult<ATOM> {
+	match unsafe { ffi::GlobalAddAtomW(WString::from_str(name).as_ptr()) } {
+		0 => Err(GetLastError()),
+		atom => Ok(unsafe { ATOM::from_raw(atom) }),
+	}
+}
+
+/// [`GlobalDeleteAtom`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-globaldeleteatom)
+/// function.
+///
+/// Decrements the reference count of the atom; the atom is removed from the
+/// global atom table when the count reaches zero.
+pub fn GlobalDeleteAtom(atom: ATOM) -> SysResult<()> {
+	SetLastError(co::ERROR::SUCCESS);
+	match unsafe { ffi::GlobalDeleteAtom(atom.raw()) } {
+		0 => Ok(()),
+		_ => Err(GetLastError()),
+	}
+}
+
+/// [`GlobalFindAtom`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-globalfindatomw)
+/// function.
+///
+/// Fails with [`ERROR::FILE_NOT_FOUND`](crate::co::ERROR::FILE_NOT_FOUND) if
+/// the atom doesn't exist.
+#[must_use]
+pub fn GlobalFindAtom(name: &str) -> SysResult<ATOM> {
+	match unsafe { ffi::GlobalFindAtomW(WString::from_str(name).as_ptr()) } {
+		0 => Err(GetLastError()),
+		atom => Ok(unsafe { ATOM::from_raw(atom) }),
+	}
+}
+
+/// [`GlobalGetAtomName`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-globalgetatomnamew)
+/// function.
+#[must_use]
+pub fn GlobalGetAtomName(atom: ATOM) -> SysResult<String> {
+	let mut buf = WString::new_alloc_buf(256); // max atom name length is 255 chars
+	match unsafe {
+		ffi::GlobalGetAtomNameW(atom.raw(), buf.as_mut_ptr(), buf.buf_len() as _)
+	} {
+		0 => Err(GetLastError()),
+		_ => Ok(buf.to_string()),
+	}
+}
+
 /// [`GlobalMemoryStatusEx`](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-globalmemorystatusex)
 /// function.
 pub fn GlobalMemoryStatusEx(msx: &mut MEMORYSTATUSEX) -> SysResult<()> {
@@ -1220,6 +1503,29 @@ pub fn IsWindowsVistaOrGreater() -> SysResult<bool> {
 	)
 }
 
+/// Returns whether the running system is Windows 10 or later.
+///
+/// Unlike [`IsWindows10OrGreater`](crate::IsWindows10OrGreater), this function
+/// relies on [`RtlGetVersion`](crate::RtlGetVersion), so the result doesn't
+/// depend on the application manifest.
+#[must_use]
+pub fn is_windows_10_or_greater() -> SysResult<bool> {
+	RtlGetVersion().map(|osvi| osvi.dwMajorVersion >= 10)
+}
+
+/// Returns whether the running system is Windows 11 or later, that is, Windows
+/// 10.0 with build number 22000 or greater.
+///
+/// This function relies on [`RtlGetVersion`](crate::RtlGetVersion), so the
+/// result doesn't depend on the application manifest.
+#[must_use]
+pub fn is_windows_11_or_greater() -> SysResult<bool> {
+	RtlGetVersion().map(|osvi| {
+		osvi.dwMajorVersion > 10
+			|| (osvi.dwMajorVersion == 10 && osvi.dwBuildNumber >= 22000)
+	})
+}
+
 /// [`LOBYTE`](https://learn.microsoft.com/en-us/previous-versions/windows/desktop/legacy/ms632658(v=vs.85))
 /// macro.
 #[must_use]
@@ -1500,10 +1806,44 @@ pub fn MultiByteToWideChar(
 
 /// [`OutputDebugString`](https://learn.microsoft.com/en-us/windows/win32/api/debugapi/nf-debugapi-outputdebugstringw)
 /// function.
+///
+/// To format the text before sending it, you can use the
+/// [`odbg!`](crate::odbg) macro.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// w::OutputDebugString("First line.\nSecond line – ação, 日本語.\n");
+/// ```
 pub fn OutputDebugString(output_string: &str) {
 	unsafe { ffi::OutputDebugStringW(WString::from_str(output_string).as_ptr()) }
 }
 
+/// [`ProcessIdToSessionId`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-processidtosessionid)
+/// function.
+///
+/// # Examples
+///
+/// Checking whether the current process runs in the interactive console
+/// session:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let session_id = w::ProcessIdToSessionId(w::GetCurrentProcessId())?;
+/// let is_console = session_id == w::WTSGetActiveConsoleSessionId();
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[must_use]
+pub fn ProcessIdToSessionId(process_id: u32) -> SysResult<u32> {
+	let mut session_id = u32::default();
+	bool_to_sysresult(
+		unsafe { ffi::ProcessIdToSessionId(process_id, &mut session_id) },
+	).map(|_| session_id)
+}
+
 /// [`QueryPerformanceCounter`](https://learn.microsoft.com/en-us/windows/win32/api/profileapi/nf-profileapi-queryperformancecounter)
 /// function.
 ///
@@ -1565,6 +1905,36 @@ pub fn ReplaceFile(
 	)
 }
 
+/// [`RtlGetVersion`](https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/wdm/nf-wdm-rtlgetversion)
+/// function.
+///
+/// Unlike `GetVersionEx`, this function always returns the actual version of
+/// the running system, regardless of the compatibility information in the
+/// application manifest.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let osvi = w::RtlGetVersion()?;
+/// println!("Windows {}.{} build {}",
+///     osvi.dwMajorVersion, osvi.dwMinorVersion, osvi.dwBuildNumber);
+///
+/// assert!(osvi.dwMajorVersion >= 6);
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[must_use]
+pub fn RtlGetVersion() -> SysResult<OSVERSIONINFOEX> {
+	let mut osvi = OSVERSIONINFOEX::default();
+	match unsafe { ffi::RtlGetVersion(&mut osvi as *mut _ as _) } {
+		0 => Ok(osvi), // STATUS_SUCCESS
+		status => Err(unsafe {
+			co::ERROR::from_raw(ffi::RtlNtStatusToDosError(status))
+		}),
+	}
+}
+
 /// [`SetCurrentDirectory`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-setcurrentdirectory)
 /// function.
 pub fn SetCurrentDirectory(path_name: &str) -> SysResult<()> {
@@ -1691,6 +2061,141 @@ pub fn VerSetConditionMask(
 	}
 }
 
+/// [`VirtualAlloc`](https://learn.microsoft.com/en-us/windows/win32/api/memoryapi/nf-memoryapi-virtualalloc)
+/// function.
+///
+/// The system always chooses the address of the new region. To commit pages
+/// of a region which has only been reserved, use
+/// [`VirtualFreeGuard::commit`](crate::guard::VirtualFreeGuard::commit).
+///
+/// The returned guard calls [`VirtualFree`](crate::VirtualFree) with
+/// [`MEM::RELEASE`](crate::co::MEM::RELEASE) when it goes out of scope, so the
+/// whole region is released.
+///
+/// # Examples
+///
+/// Reserving two pages, committing the first one, then making it read-only:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let mut block = w::VirtualAlloc(8192, co::MEM::RESERVE, co::PAGE::NOACCESS)?;
+/// block.commit(0, 4096, co::PAGE::READWRITE)?;
+///
+/// let page = unsafe { &mut block.as_mut_slice()[..4096] }; // committed part only
+/// page[0] = 0xff;
+///
+/// let old_prot = unsafe {
+///     w::VirtualProtect(block.as_mut_ptr(), 4096, co::PAGE::READONLY)?
+/// };
+///
+/// // VirtualFree() automatically called
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[must_use]
+pub fn VirtualAlloc(
+	size: usize,
+	alloc_type: co::MEM,
+	protect: co::PAGE,
+) -> SysResult<VirtualFreeGuard>
+{
+	match unsafe {
+		ffi::VirtualAlloc(
+			std::ptr::null_mut(),
+			size,
+			alloc_type.raw(),
+			protect.raw(),
+		)
+	} {
+		p if p.is_null() => Err(GetLastError()),
+		p => Ok(unsafe { VirtualFreeGuard::new(p, size) }),
+	}
+}
+
+/// [`VirtualFree`](https://learn.microsoft.com/en-us/windows/win32/api/memoryapi/nf-memoryapi-virtualfree)
+/// function.
+///
+/// **Note:** Memory allocated with [`VirtualAlloc`](crate::VirtualAlloc) is
+/// automatically released by its guard; use this function only for partial
+/// decommits or with a [leaked](crate::guard::VirtualFreeGuard::leak) pointer.
+///
+/// # Safety
+///
+/// The address must belong to a region allocated with `VirtualAlloc`, and it
+/// must not be used after being freed.
+pub unsafe fn VirtualFree(
+	address: *mut std::ffi::c_void,
+	size: usize,
+	free_type: co::MEM,
+) -> SysResult<()>
+{
+	bool_to_sysresult(ffi::VirtualFree(address, size, free_type.raw()))
+}
+
+/// [`VirtualProtect`](https://learn.microsoft.com/en-us/windows/win32/api/memoryapi/nf-memoryapi-virtualprotect)
+/// function.
+///
+/// Returns the previous access protection of the first page in the region.
+///
+/// # Safety
+///
+/// Changing the protection of memory which is referenced elsewhere may cause
+/// access violations.
+pub unsafe fn VirtualProtect(
+	address: *mut std::ffi::c_void,
+	size: usize,
+	new_protect: co::PAGE,
+) -> SysResult<co::PAGE>
+{
+	let mut old_protect = co::PAGE::default();
+	bool_to_sysresult(
+		ffi::VirtualProtect(address, size, new_protect.raw(), old_protect.as_mut()),
+	).map(|_| old_protect)
+}
+
+/// [`WaitNamedPipe`](https://learn.microsoft.com/en-us/windows/win32/api/namedpipeapi/nf-namedpipeapi-waitnamedpipew)
+/// function.
+///
+/// If `milliseconds` is `None`, waits indefinitely until an instance of the
+/// named pipe is available.
+///
+/// # Examples
+///
+/// Connecting to a named pipe as a client:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let pipe_name = "\\\\.\\pipe\\my_pipe";
+///
+/// w::WaitNamedPipe(pipe_name, Some(5000))?;
+///
+/// let (hpipe, _) = w::HFILE::CreateFile(
+///     pipe_name,
+///     co::GENERIC::READ | co::GENERIC::WRITE,
+///     None,
+///     None,
+///     co::DISPOSITION::OPEN_EXISTING,
+///     co::FILE_ATTRIBUTE::NORMAL,
+///     None,
+///     None,
+///     None,
+/// )?;
+///
+/// hpipe.WriteFile("hello".as_bytes(), None)?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub fn WaitNamedPipe(name: &str, milliseconds: Option<u32>) -> SysResult<()> {
+	bool_to_sysresult(
+		unsafe {
+			ffi::WaitNamedPipeW(
+				WString::from_str(name).as_ptr(),
+				milliseconds.unwrap_or(INFINITE),
+			)
+		},
+	)
+}
+
 /// [`WideCharToMultiByte`](https://learn.microsoft.com/en-us/windows/win32/api/stringapiset/nf-stringapiset-widechartomultibyte)
 /// function.
 ///
@@ -1745,3 +2250,42 @@ pub fn WideCharToMultiByte(
 		u8_buf
 	})
 }
+
+/// [`WTSGetActiveConsoleSessionId`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-wtsgetactiveconsolesessionid)
+/// function.
+///
+/// Returns `0xffff_ffff` if there is no session attached to the physical
+/// console, which may happen while sessions are being switched.
+#[must_use]
+pub fn WTSGetActiveConsoleSessionId() -> u32 {
+	unsafe { ffi::WTSGetActiveConsoleSessionId() }
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::decl::*;
+
+	#[test]
+	fn computer_and_user_names() {
+		assert!(!GetComputerName().unwrap().is_empty());
+		assert!(!GetUserName().unwrap().is_empty());
+	}
+
+	#[test]
+	fn enum_processes_includes_current() {
+		assert!(EnumProcesses().unwrap().contains(&GetCurrentProcessId()));
+	}
+
+	#[test]
+	fn logical_drives_include_system_drive() {
+		let system_drive = format!("{}\\",
+			std::env::var("SystemDrive").unwrap_or_else(|_| "C:".to_owned()));
+		let drives = GetLogicalDriveStrings().unwrap();
+		assert!(drives.iter().any(|d| d.eq_ignore_ascii_case(&system_drive)));
+
+		let mut sys_name = String::default();
+		GetVolumeInformation(
+			Some(&system_drive), None, None, None, None, Some(&mut sys_name)).unwrap();
+		assert!(!sys_name.is_empty());
+	}
+}
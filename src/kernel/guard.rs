@@ -1,6 +1,7 @@
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
 
+use crate::co;
 use crate::decl::*;
 use crate::kernel::ffi;
 use crate::prelude::*;
@@ -133,6 +134,59 @@ impl CloseHandlePiGuard {
 
 //------------------------------------------------------------------------------
 
+/// RAII implementation for [`HTHREADPOOLWORK`](crate::HTHREADPOOLWORK) which
+/// automatically calls
+/// [`WaitForThreadpoolWorkCallbacks`](https://learn.microsoft.com/en-us/windows/win32/api/threadpoolapiset/nf-threadpoolapiset-waitforthreadpoolworkcallbacks)
+/// and
+/// [`CloseThreadpoolWork`](https://learn.microsoft.com/en-us/windows/win32/api/threadpoolapiset/nf-threadpoolapiset-closethreadpoolwork)
+/// when the object goes out of scope. The callback closure is freed only
+/// afterwards.
+pub struct CloseThreadpoolWorkGuard {
+	hwork: HTHREADPOOLWORK,
+	_func: Box<dyn Fn() + Send + Sync>, // must outlive the work object
+}
+
+impl Drop for CloseThreadpoolWorkGuard {
+	fn drop(&mut self) {
+		if let Some(h) = self.hwork.as_opt() {
+			unsafe {
+				ffi::WaitForThreadpoolWorkCallbacks(h.ptr(), false as _);
+				ffi::CloseThreadpoolWork(h.ptr());
+			}
+		}
+	}
+}
+
+impl Deref for CloseThreadpoolWorkGuard {
+	type Target = HTHREADPOOLWORK;
+
+	fn deref(&self) -> &Self::Target {
+		&self.hwork
+	}
+}
+
+impl CloseThreadpoolWorkGuard {
+	/// Constructs the guard by taking ownership of the handle and the closure
+	/// passed as the callback context.
+	///
+	/// # Safety
+	///
+	/// Be sure the handle must be freed with
+	/// [`CloseThreadpoolWork`](https://learn.microsoft.com/en-us/windows/win32/api/threadpoolapiset/nf-threadpoolapiset-closethreadpoolwork)
+	/// at the end of scope, and that the closure is the one the callback
+	/// refers to.
+	#[must_use]
+	pub unsafe fn new(
+		hwork: HTHREADPOOLWORK,
+		func: Box<dyn Fn() + Send + Sync>,
+	) -> Self
+	{
+		Self { hwork, _func: func }
+	}
+}
+
+//------------------------------------------------------------------------------
+
 handle_guard! { DeregisterEventSourceGuard: HEVENTLOG;
 	ffi::DeregisterEventSource;
 	/// RAII implementation for [`HEVENTLOG`](crate::HEVENTLOG) which
@@ -771,3 +825,131 @@ handle_guard! { UnmapViewOfFileGuard: HFILEMAPVIEW;
 	/// [`UnmapViewOfFile`](https://learn.microsoft.com/en-us/windows/win32/api/memoryapi/nf-memoryapi-unmapviewoffile)
 	/// when the object goes out of scope.
 }
+
+//------------------------------------------------------------------------------
+
+/// RAII implementation for the memory allocated by
+/// [`VirtualAlloc`](crate::VirtualAlloc) which automatically calls
+/// [`VirtualFree`](https://learn.microsoft.com/en-us/windows/win32/api/memoryapi/nf-memoryapi-virtualfree)
+/// with `MEM_RELEASE` when the object goes out of scope.
+pub struct VirtualFreeGuard {
+	pmem: *mut std::ffi::c_void,
+	sz: usize,
+}
+
+impl Drop for VirtualFreeGuard {
+	fn drop(&mut self) {
+		if !self.pmem.is_null() {
+			unsafe { ffi::VirtualFree(self.pmem, 0, co::MEM::RELEASE.raw()); } // ignore errors
+		}
+	}
+}
+
+impl VirtualFreeGuard {
+	/// Constructs the guard by taking ownership of the memory block.
+	///
+	/// # Safety
+	///
+	/// Be sure the memory must be freed with
+	/// [`VirtualFree`](https://learn.microsoft.com/en-us/windows/win32/api/memoryapi/nf-memoryapi-virtualfree)
+	/// at the end of scope, the pointer is valid, and the size is correct.
+	#[must_use]
+	pub const unsafe fn new(pmem: *mut std::ffi::c_void, sz: usize) -> Self {
+		Self { pmem, sz }
+	}
+
+	/// Ejects the underlying memory pointer and size, leaving null and zero in
+	/// their places.
+	///
+	/// Since the internal memory pointer will be invalidated, the destructor
+	/// will not run. It's your responsibility to run it, otherwise you'll cause
+	/// a memory leak.
+	#[must_use]
+	pub fn leak(&mut self) -> (*mut std::ffi::c_void, usize) {
+		(
+			std::mem::replace(&mut self.pmem, std::ptr::null_mut()),
+			std::mem::replace(&mut self.sz, 0),
+		)
+	}
+
+	/// Commits pages within the region by calling
+	/// [`VirtualAlloc`](https://learn.microsoft.com/en-us/windows/win32/api/memoryapi/nf-memoryapi-virtualalloc)
+	/// with [`MEM::COMMIT`](crate::co::MEM::COMMIT). The range is rounded to
+	/// page boundaries by the system.
+	///
+	/// Fails with [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if the range is not inside the region.
+	pub fn commit(&self,
+		offset: usize,
+		size: usize,
+		protect: co::PAGE,
+	) -> SysResult<()>
+	{
+		if offset.checked_add(size).is_none_or(|end| end > self.sz) {
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
+		match unsafe {
+			ffi::VirtualAlloc(
+				(self.pmem as *mut u8).add(offset) as _,
+				size,
+				co::MEM::COMMIT.raw(),
+				protect.raw(),
+			)
+		} {
+			p if p.is_null() => Err(GetLastError()),
+			_ => Ok(()),
+		}
+	}
+
+	/// Returns a pointer to the allocated memory block.
+	#[must_use]
+	pub const fn as_ptr(&self) -> *const std::ffi::c_void {
+		self.pmem
+	}
+
+	/// Returns a mutable pointer to the allocated memory block.
+	#[must_use]
+	pub fn as_mut_ptr(&mut self) -> *mut std::ffi::c_void {
+		self.pmem
+	}
+
+	/// Returns a slice over the allocated memory block.
+	///
+	/// # Safety
+	///
+	/// All pages of the region must be committed and readable; reading
+	/// reserved, [`PAGE::NOACCESS`](crate::co::PAGE::NOACCESS) or
+	/// [`PAGE::GUARD`](crate::co::PAGE::GUARD) pages causes an access
+	/// violation.
+	#[must_use]
+	pub const unsafe fn as_slice(&self) -> &[u8] {
+		std::slice::from_raw_parts(self.pmem as _, self.sz)
+	}
+
+	/// Returns a mutable slice over the allocated memory block.
+	///
+	/// # Safety
+	///
+	/// All pages of the region must be committed, readable and writable;
+	/// touching reserved, read-only,
+	/// [`PAGE::NOACCESS`](crate::co::PAGE::NOACCESS) or
+	/// [`PAGE::GUARD`](crate::co::PAGE::GUARD) pages causes an access
+	/// violation.
+	#[must_use]
+	pub unsafe fn as_mut_slice(&mut self) -> &mut [u8] {
+		std::slice::from_raw_parts_mut(self.pmem as _, self.sz)
+	}
+
+	/// Returns the size of the allocated memory block.
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.sz
+	}
+
+	/// Returns `true` if the memory block has zero size, which happens after
+	/// [`leak`](crate::guard::VirtualFreeGuard::leak).
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.sz == 0
+	}
+}
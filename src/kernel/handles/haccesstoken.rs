@@ -13,6 +13,7 @@ impl_handle! { HACCESSTOKEN;
 }
 
 impl kernel_Haccesstoken for HACCESSTOKEN {}
+impl HandleDuplicate for HACCESSTOKEN {}
 
 /// This trait is enabled with the `kernel` feature, and provides methods for
 /// [`HACCESSTOKEN`](crate::HACCESSTOKEN).
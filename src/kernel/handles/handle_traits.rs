@@ -2,6 +2,12 @@
 
 use std::{fmt, hash};
 
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::{ffi, privs::*};
+use crate::prelude::*;
+
 /// A native
 /// [handle](https://learn.microsoft.com/en-us/windows/win32/sysinfo/handles-and-objects),
 /// implemented by all handle types.
@@ -103,3 +109,86 @@ pub trait Handle: Sized
 		}
 	}
 }
+
+/// This trait is enabled with the `kernel` feature, and implements methods for
+/// any handle which can be duplicated with
+/// [`DuplicateHandle`](https://learn.microsoft.com/en-us/windows/win32/api/handleapi/nf-handleapi-duplicatehandle).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait HandleDuplicate: Handle {
+	/// Calls
+	/// [`DuplicateHandle`](https://learn.microsoft.com/en-us/windows/win32/api/handleapi/nf-handleapi-duplicatehandle)
+	/// to duplicate the handle into another process.
+	///
+	/// The returned handle value is only meaningful within `target_process`,
+	/// therefore it's not wrapped in a guard: it must be passed to the target
+	/// process, which is responsible for closing it. Don't use it in the
+	/// current process.
+	///
+	/// If `desired_access` is `None`, the duplicate will have the same access
+	/// rights of the source handle.
+	#[must_use]
+	fn duplicate_to_process(&self,
+		target_process: &HPROCESS,
+		desired_access: Option<u32>,
+		inherit_handle: bool,
+	) -> SysResult<Self>
+	{
+		let mut handle = Self::NULL;
+		unsafe {
+			bool_to_sysresult(
+				ffi::DuplicateHandle(
+					ffi::GetCurrentProcess(),
+					self.ptr(),
+					target_process.ptr(),
+					handle.as_mut(),
+					desired_access.unwrap_or_default(),
+					inherit_handle as _,
+					match desired_access {
+						Some(_) => 0,
+						None => co::DUPLICATE::SAME_ACCESS.raw(),
+					},
+				),
+			).map(|_| handle)
+		}
+	}
+
+	/// [`DuplicateHandle`](https://learn.microsoft.com/en-us/windows/win32/api/handleapi/nf-handleapi-duplicatehandle)
+	/// function.
+	///
+	/// Both source and target processes are the current process. If
+	/// `desired_access` is `None`, the duplicate will have the same access
+	/// rights of the source handle, by passing
+	/// [`DUPLICATE::SAME_ACCESS`](crate::co::DUPLICATE::SAME_ACCESS).
+	///
+	/// To duplicate the handle into another process, use
+	/// [`duplicate_to_process`](crate::prelude::HandleDuplicate::duplicate_to_process).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hfile: w::HFILE; // initialized somewhere
+	/// # let hfile = w::HFILE::NULL;
+	///
+	/// let hfile_dup = hfile.DuplicateHandle(None, false)?;
+	/// # Ok::<_, w::co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn DuplicateHandle(&self,
+		desired_access: Option<u32>,
+		inherit_handle: bool,
+	) -> SysResult<CloseHandleGuard<Self>>
+	{
+		self.duplicate_to_process(
+			&HPROCESS::GetCurrentProcess(),
+			desired_access,
+			inherit_handle,
+		).map(|handle| unsafe { CloseHandleGuard::new(handle) })
+	}
+}
@@ -0,0 +1,79 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::{ffi, privs::*};
+use crate::prelude::*;
+
+impl_handle! { HEVENT;
+	/// Handle to an
+	/// [event](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-createeventw).
+	/// Originally just a `HANDLE`.
+}
+
+impl kernel_Hevent for HEVENT {}
+impl HandleDuplicate for HEVENT {}
+
+/// This trait is enabled with the `kernel` feature, and provides methods for
+/// [`HEVENT`](crate::HEVENT).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait kernel_Hevent: Handle {
+	/// [`CreateEvent`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-createeventw)
+	/// function.
+	#[must_use]
+	fn CreateEvent(
+		security_attributes: Option<&mut SECURITY_ATTRIBUTES>,
+		manual_reset: bool,
+		initial_state: bool,
+		name: Option<&str>,
+	) -> SysResult<CloseHandleGuard<HEVENT>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				ffi::CreateEventW(
+					security_attributes.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _),
+					manual_reset as _,
+					initial_state as _,
+					WString::from_opt_str(name).as_ptr(),
+				),
+			).map(|h| CloseHandleGuard::new(h))
+		}
+	}
+
+	/// [`ResetEvent`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-resetevent)
+	/// function.
+	fn ResetEvent(&self) -> SysResult<()> {
+		bool_to_sysresult(unsafe { ffi::ResetEvent(self.ptr()) })
+	}
+
+	/// [`SetEvent`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-setevent)
+	/// function.
+	fn SetEvent(&self) -> SysResult<()> {
+		bool_to_sysresult(unsafe { ffi::SetEvent(self.ptr()) })
+	}
+
+	/// [`WaitForSingleObject`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-waitforsingleobject)
+	/// function.
+	fn WaitForSingleObject(&self,
+		milliseconds: Option<u32>,
+	) -> SysResult<co::WAIT>
+	{
+		match unsafe {
+			co::WAIT::from_raw(
+				ffi::WaitForSingleObject(
+					self.ptr(),
+					milliseconds.unwrap_or(INFINITE),
+				),
+			)
+		} {
+			co::WAIT::FAILED => Err(GetLastError()),
+			wait => Ok(wait),
+		}
+	}
+}
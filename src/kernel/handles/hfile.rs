@@ -16,6 +16,7 @@ impl_handle! { HFILE;
 }
 
 impl kernel_Hfile for HFILE {}
+impl HandleDuplicate for HFILE {}
 
 /// This trait is enabled with the `kernel` feature, and provides methods for
 /// [`HFILE`](crate::HFILE).
@@ -26,6 +27,81 @@ impl kernel_Hfile for HFILE {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait kernel_Hfile: Handle {
+	/// Starts an overlapped read of up to `num_bytes`, at the given file
+	/// offset, returning an [`Overlapped`](crate::Overlapped) object which
+	/// owns the buffer until the operation completes.
+	///
+	/// The file must have been opened with
+	/// [`FILE_FLAG::OVERLAPPED`](crate::co::FILE_FLAG::OVERLAPPED).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hfile: w::HFILE; // opened with FILE_FLAG::OVERLAPPED
+	/// # let hfile = w::HFILE::NULL;
+	///
+	/// let op = hfile.read_overlapped(4096, 0)?;
+	///
+	/// // do other work...
+	///
+	/// let data = op.into_buffer()?; // waits for completion
+	/// println!("{} bytes read", data.len());
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn read_overlapped(&self,
+		num_bytes: usize,
+		offset: u64,
+	) -> SysResult<Overlapped<'_, Self>>
+	{
+		Overlapped::ReadFile(self, num_bytes, offset)
+	}
+
+	/// Starts an overlapped write of a copy of `data`, at the given file
+	/// offset, returning an [`Overlapped`](crate::Overlapped) object which
+	/// owns the buffer until the operation completes.
+	///
+	/// The file must have been opened with
+	/// [`FILE_FLAG::OVERLAPPED`](crate::co::FILE_FLAG::OVERLAPPED).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let (hfile, _) = w::HFILE::CreateFile(
+	///     "C:\\Temp\\test.bin",
+	///     co::GENERIC::WRITE,
+	///     None,
+	///     None,
+	///     co::DISPOSITION::CREATE_ALWAYS,
+	///     co::FILE_ATTRIBUTE::NORMAL,
+	///     Some(co::FILE_FLAG::OVERLAPPED),
+	///     None,
+	///     None,
+	/// )?;
+	///
+	/// let mut op = hfile.write_overlapped(&[0xff; 1024], 4096)?;
+	///
+	/// if op.GetOverlappedResult(false)?.is_none() {
+	///     println!("Still pending...");
+	/// }
+	///
+	/// let written = op.GetOverlappedResult(true)?.unwrap();
+	/// println!("{} bytes written", written);
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn write_overlapped(&self,
+		data: &[u8],
+		offset: u64,
+	) -> SysResult<Overlapped<'_, Self>>
+	{
+		Overlapped::WriteFile(self, data, offset)
+	}
+
 	/// [`CreateFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-createfilew)
 	/// function.
 	///
@@ -133,8 +209,78 @@ pub trait kernel_Hfile: Handle {
 		}
 	}
 
+	/// [`DeviceIoControl`](https://learn.microsoft.com/en-us/windows/win32/api/ioapiset/nf-ioapiset-deviceiocontrol)
+	/// function.
+	///
+	/// Returns the number of bytes written to `out_buf`.
+	///
+	/// # Examples
+	///
+	/// Retrieving the compression state of a file with
+	/// [`FSCTL_GET_COMPRESSION`](https://learn.microsoft.com/en-us/windows/win32/api/winioctl/ni-winioctl-fsctl_get_compression):
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hfile: w::HFILE; // initialized somewhere
+	/// # let hfile = w::HFILE::NULL;
+	///
+	/// const FSCTL_GET_COMPRESSION: u32 = 0x0009_003c;
+	///
+	/// let mut out = [0u8; 2];
+	/// hfile.DeviceIoControl(FSCTL_GET_COMPRESSION, None, Some(&mut out))?;
+	/// let compression_format = u16::from_le_bytes(out);
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	fn DeviceIoControl(&self,
+		control_code: u32,
+		in_buf: Option<&[u8]>,
+		out_buf: Option<&mut [u8]>,
+	) -> SysResult<u32>
+	{
+		let mut bytes_returned = u32::default();
+		let (in_ptr, in_len) = in_buf.map_or(
+			(std::ptr::null(), 0), |b| (b.as_ptr(), b.len()));
+		let (out_ptr, out_len) = out_buf.map_or(
+			(std::ptr::null_mut(), 0), |b| (b.as_mut_ptr(), b.len()));
+
+		bool_to_sysresult(
+			unsafe {
+				ffi::DeviceIoControl(
+					self.ptr(),
+					control_code,
+					in_ptr as _,
+					in_len as _,
+					out_ptr as _,
+					out_len as _,
+					&mut bytes_returned,
+					std::ptr::null_mut(),
+				)
+			},
+		).map(|_| bytes_returned)
+	}
+
 	/// [`GetFileInformationByHandle`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getfileinformationbyhandle)
 	/// function.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hfile: w::HFILE; // initialized somewhere
+	/// # let hfile = w::HFILE::NULL;
+	///
+	/// let mut fi = w::BY_HANDLE_FILE_INFORMATION::default();
+	/// hfile.GetFileInformationByHandle(&mut fi)?;
+	///
+	/// println!("Size: {}, volume: {:#010x}, index: {:#018x}",
+	///     fi.nFileSize(), fi.dwVolumeSerialNumber, fi.nFileIndex());
+	///
+	/// let mut st = w::SYSTEMTIME::default();
+	/// w::FileTimeToSystemTime(&fi.ftLastWriteTime, &mut st)?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
 	fn GetFileInformationByHandle(&self,
 		fi: &mut BY_HANDLE_FILE_INFORMATION,
 	) -> SysResult<()>
@@ -188,6 +334,68 @@ pub trait kernel_Hfile: Handle {
 		}
 	}
 
+	/// [`GetFinalPathNameByHandle`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getfinalpathnamebyhandlew)
+	/// function.
+	///
+	/// Symbolic links and junctions are resolved. When
+	/// [`co::FILE_NAME::VOLUME_NAME_DOS`](crate::co::FILE_NAME::VOLUME_NAME_DOS)
+	/// is used, the returned path is prefixed with `\\?\` (or `\\?\UNC\`
+	/// for network shares), which you may want to strip before displaying it.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hfile: w::HFILE; // initialized somewhere
+	/// # let hfile = w::HFILE::NULL;
+	///
+	/// let final_path = hfile.GetFinalPathNameByHandle(
+	///     co::FILE_NAME::NORMALIZED | co::FILE_NAME::VOLUME_NAME_DOS)?;
+	///
+	/// let display_path = final_path.strip_prefix("\\\\?\\")
+	///     .unwrap_or(&final_path);
+	/// println!("{}", display_path);
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn GetFinalPathNameByHandle(&self, flags: co::FILE_NAME) -> SysResult<String> {
+		grow_path_buf(|buf, buf_len| unsafe {
+			ffi::GetFinalPathNameByHandleW(self.ptr(), buf, buf_len, flags.raw())
+		})
+	}
+
+	/// [`GetOverlappedResult`](https://learn.microsoft.com/en-us/windows/win32/api/ioapiset/nf-ioapiset-getoverlappedresult)
+	/// function.
+	///
+	/// Returns the number of bytes transferred by the overlapped operation, or
+	/// `None` if `wait` is `false` and the operation is still pending – that
+	/// is, [`ERROR::IO_INCOMPLETE`](crate::co::ERROR::IO_INCOMPLETE).
+	///
+	/// Prefer the [`Overlapped`](crate::Overlapped) wrapper, which owns the
+	/// `OVERLAPPED` struct and the buffer.
+	fn GetOverlappedResult(&self,
+		overlapped: &mut OVERLAPPED,
+		wait: bool,
+	) -> SysResult<Option<u32>>
+	{
+		let mut bytes_transferred = u32::default();
+		match bool_to_sysresult(
+			unsafe {
+				ffi::GetOverlappedResult(
+					self.ptr(),
+					overlapped as *mut _ as _,
+					&mut bytes_transferred,
+					wait as _,
+				)
+			},
+		) {
+			Ok(_) => Ok(Some(bytes_transferred)),
+			Err(co::ERROR::IO_INCOMPLETE) => Ok(None), // not an error, still pending
+			Err(e) => Err(e),
+		}
+	}
+
 	/// [`LockFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-lockfile)
 	/// function.
 	///
@@ -237,27 +445,145 @@ pub trait kernel_Hfile: Handle {
 		}
 	}
 
+	/// [`ReadDirectoryChanges`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-readdirectorychangesw)
+	/// function.
+	///
+	/// Blocks until a change happens in the directory, then returns the
+	/// changes, with file names relative to the directory. The handle must be
+	/// a directory opened with
+	/// [`FILE_FLAG::BACKUP_SEMANTICS`](crate::co::FILE_FLAG::BACKUP_SEMANTICS).
+	///
+	/// The records are written into `buffer`; since they must be
+	/// `DWORD`-aligned, a few leading bytes of `buffer` may be left unused. If
+	/// more changes happened than `buffer` can hold, an empty `Vec` is
+	/// returned, and you should enumerate the directory again.
+	///
+	/// Fails with [`ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if a
+	/// record doesn't fit in the returned data.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let (hdir, _) = w::HFILE::CreateFile(
+	///     "C:\\Temp",
+	///     co::GENERIC::READ,
+	///     Some(co::FILE_SHARE::READ | co::FILE_SHARE::WRITE | co::FILE_SHARE::DELETE),
+	///     None,
+	///     co::DISPOSITION::OPEN_EXISTING,
+	///     co::FILE_ATTRIBUTE::NORMAL,
+	///     Some(co::FILE_FLAG::BACKUP_SEMANTICS),
+	///     None,
+	///     None,
+	/// )?;
+	///
+	/// let mut buf = vec![0u8; 64 * 1024];
+	///
+	/// loop {
+	///     for change in hdir.ReadDirectoryChanges(
+	///         false,
+	///         co::FILE_NOTIFY_CHANGE::FILE_NAME | co::FILE_NOTIFY_CHANGE::LAST_WRITE,
+	///         &mut buf,
+	///     )? {
+	///         if change.action == co::FILE_ACTION::ADDED {
+	///             println!("Created: {}", change.file_name);
+	///         }
+	///     }
+	/// }
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn ReadDirectoryChanges(&self,
+		watch_subtree: bool,
+		notify_filter: co::FILE_NOTIFY_CHANGE,
+		buffer: &mut [u8],
+	) -> SysResult<Vec<DirChange>>
+	{
+		const HEADER_LEN: usize = 12; // NextEntryOffset, Action, FileNameLength
+
+		let skip = buffer.as_ptr().align_offset(std::mem::align_of::<u32>()).min(buffer.len());
+		let buf = &mut buffer[skip..]; // FILE_NOTIFY_INFORMATION must be DWORD-aligned
+		let mut bytes_returned = u32::default();
+
+		bool_to_sysresult(
+			unsafe {
+				ffi::ReadDirectoryChangesW(
+					self.ptr(),
+					buf.as_mut_ptr() as _,
+					buf.len().min(u32::MAX as _) as _,
+					watch_subtree as _,
+					notify_filter.raw(),
+					&mut bytes_returned,
+					std::ptr::null_mut(),
+					std::ptr::null(),
+				)
+			},
+		)?;
+
+		let data = &buf[..(bytes_returned as usize).min(buf.len())];
+		let read_u32 = |off: usize| u32::from_ne_bytes(data[off..off + 4].try_into().unwrap());
+		let mut changes = Vec::<DirChange>::default();
+		let mut off = 0;
+
+		while !data.is_empty() { // if empty, buffer overflow, changes were lost
+			if data.len() - off < HEADER_LEN {
+				return Err(co::ERROR::INVALID_DATA);
+			}
+			let next_entry_offset = read_u32(off) as usize;
+			let action = read_u32(off + 4);
+			let name_len = read_u32(off + 8) as usize; // in bytes
+
+			let name_end = (off + HEADER_LEN).checked_add(name_len)
+				.ok_or(co::ERROR::INVALID_DATA)?;
+			let name_bytes = data.get(off + HEADER_LEN..name_end)
+				.ok_or(co::ERROR::INVALID_DATA)?;
+			let name_wchars = name_bytes.chunks_exact(2)
+				.map(|pair| u16::from_ne_bytes([pair[0], pair[1]]))
+				.collect::<Vec<_>>();
+			changes.push(DirChange {
+				action: unsafe { co::FILE_ACTION::from_raw(action) },
+				file_name: WString::from_wchars_slice(&name_wchars).to_string(),
+			});
+
+			if next_entry_offset == 0 {
+				break;
+			} else if next_entry_offset >= data.len() - off {
+				return Err(co::ERROR::INVALID_DATA);
+			}
+			off += next_entry_offset;
+		}
+		Ok(changes)
+	}
+
 	/// [`ReadFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-readfile)
 	/// function.
 	///
 	/// Returns the number of bytes read.
+	///
+	/// If `overlapped` is passed and the operation completes asynchronously,
+	/// this method waits for it, since `buffer` is only borrowed. For actual
+	/// asynchronous reads, use
+	/// [`HFILE::read_overlapped`](crate::prelude::kernel_Hfile::read_overlapped).
 	fn ReadFile(&self,
 		buffer: &mut [u8],
 		overlapped: Option<&mut OVERLAPPED>,
 	) -> SysResult<u32>
 	{
 		let mut bytes_read = u32::default();
-		bool_to_sysresult(
+		let poverlapped = overlapped.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _);
+		let res = bool_to_sysresult(
 			unsafe {
 				ffi::ReadFile(
 					self.ptr(),
 					buffer.as_mut_ptr() as _,
 					buffer.len() as _,
 					&mut bytes_read,
-					overlapped.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _),
+					poverlapped,
 				)
 			},
-		).map(|_| bytes_read)
+		).map(|_| bytes_read);
+		wait_if_pending(self.ptr(), poverlapped, res)
 	}
 
 	/// [`SetEndOfFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-setendoffile)
@@ -311,23 +637,64 @@ pub trait kernel_Hfile: Handle {
 	/// function.
 	///
 	/// Returns the number of bytes written.
+	///
+	/// If `overlapped` is passed and the operation completes asynchronously,
+	/// this method waits for it, since `data` is only borrowed. For actual
+	/// asynchronous writes, use
+	/// [`HFILE::write_overlapped`](crate::prelude::kernel_Hfile::write_overlapped).
 	fn WriteFile(&self,
 		data: &[u8],
 		overlapped: Option<&mut OVERLAPPED>,
 	) -> SysResult<u32>
 	{
 		let mut bytes_written = u32::default();
-
-		bool_to_sysresult(
+		let poverlapped = overlapped.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _);
+		let res = bool_to_sysresult(
 			unsafe {
 				ffi::WriteFile(
 					self.ptr(),
 					data.as_ptr() as _,
 					data.len() as _,
 					&mut bytes_written,
-					overlapped.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _),
+					poverlapped,
 				)
 			},
-		).map(|_| bytes_written)
+		).map(|_| bytes_written);
+		wait_if_pending(self.ptr(), poverlapped, res)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::co;
+	use crate::decl::*;
+	use crate::prelude::*;
+
+	#[test]
+	fn overlapped_write_then_read() {
+		let path = std::env::temp_dir().join("winsafe_hfile_overlapped_test.bin");
+		let data: Vec<u8> = (0..=255).cycle().take(64 * 1024).collect();
+		{
+			let (hfile, _) = HFILE::CreateFile(
+				path.to_str().unwrap(),
+				co::GENERIC::READ | co::GENERIC::WRITE,
+				None,
+				None,
+				co::DISPOSITION::CREATE_ALWAYS,
+				co::FILE_ATTRIBUTE::NORMAL,
+				Some(co::FILE_FLAG::OVERLAPPED),
+				None,
+				None,
+			).unwrap();
+
+			let mut op = hfile.write_overlapped(&data, 0).unwrap();
+			assert_eq!(op.GetOverlappedResult(true).unwrap(), Some(data.len() as u32));
+			drop(op);
+
+			let op = hfile.read_overlapped(data.len(), 0).unwrap();
+			assert_eq!(op.into_buffer().unwrap(), data);
+		} // close the file before deleting it
+
+		let _ = std::fs::remove_file(&path);
 	}
 }
@@ -16,6 +16,7 @@ impl_handle! { HFILEMAP;
 }
 
 impl kernel_Hfilemap for HFILEMAP {}
+impl HandleDuplicate for HFILEMAP {}
 
 /// This trait is enabled with the `kernel` feature, and provides methods for
 /// [`HFILEMAP`](crate::HFILEMAP).
@@ -28,6 +29,10 @@ impl kernel_Hfilemap for HFILEMAP {}
 pub trait kernel_Hfilemap: Handle {
 	/// [`MapViewOfFile`](https://learn.microsoft.com/en-us/windows/win32/api/memoryapi/nf-memoryapi-mapviewoffile)
 	/// function.
+	///
+	/// The `offset` must be a multiple of the allocation granularity, which can
+	/// be retrieved with [`GetSystemInfo`](crate::GetSystemInfo), in the
+	/// [`SYSTEM_INFO::dwAllocationGranularity`](crate::SYSTEM_INFO) field.
 	#[must_use]
 	fn MapViewOfFile(&self,
 		desired_access: co::FILE_MAP,
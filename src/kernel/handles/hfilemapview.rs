@@ -28,6 +28,9 @@ pub trait kernel_Hfilemapview: Handle {
 	/// **Note**: If the file is resized to a smaller size, the slice will still
 	/// map the bytes beyond the file. This may cause serious errors. So, if the
 	/// file is resized, re-generate the slice by calling `as_slice` again.
+	///
+	/// The `len` is not validated against the mapping size. If you need
+	/// bounds checking, use [`FileMapped`](crate::FileMapped).
 	#[must_use]
 	fn as_mut_slice(&self, len: usize) -> &mut [u8] {
 		unsafe { std::slice::from_raw_parts_mut(self.ptr() as _, len) }
@@ -39,6 +42,9 @@ pub trait kernel_Hfilemapview: Handle {
 	/// map the bytes beyond the file. This may cause serious errors. So, if the
 	/// file is resized, re-generate the slice by calling `as_slice` again.
 	///
+	/// The `len` is not validated against the mapping size. If you need
+	/// bounds checking, use [`FileMapped`](crate::FileMapped).
+	///
 	/// # Examples
 	///
 	/// Reading the contents of a file into a string:
@@ -3,7 +3,7 @@
 use crate::co;
 use crate::decl::*;
 use crate::guard::*;
-use crate::kernel::ffi;
+use crate::kernel::{ffi, iterators::*};
 use crate::prelude::*;
 
 impl_handle! { HFINDFILE;
@@ -23,6 +23,35 @@ impl kernel_Hfindfile for HFINDFILE {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait kernel_Hfindfile: Handle {
+	/// Returns an iterator over the found files, which calls
+	/// [`HFINDFILE::FindFirstFile`](crate::prelude::kernel_Hfindfile::FindFirstFile)
+	/// and
+	/// [`HFINDFILE::FindNextFile`](crate::prelude::kernel_Hfindfile::FindNextFile)
+	/// internally. The search handle is closed when the iterator goes out of
+	/// scope.
+	///
+	/// Unlike [`path::dir_list`](crate::path::dir_list), the
+	/// [`WIN32_FIND_DATA`](crate::WIN32_FIND_DATA) of each entry is returned,
+	/// including the `.` and `..` directories, if matched.
+	///
+	/// # Examples
+	///
+	/// Listing the EXE files in a directory, along with their sizes:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// for wfd in w::HFINDFILE::iter("C:\\Windows\\*.exe") {
+	///     let wfd = wfd?;
+	///     println!("{} {}", wfd.cFileName(), wfd.nFileSize());
+	/// }
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn iter(file_name: &str) -> Box<dyn Iterator<Item = SysResult<WIN32_FIND_DATA>>> {
+		Box::new(HfindfileIter::new(file_name))
+	}
+
 	/// [`FindFirstFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-findfirstfilew)
 	/// function.
 	///
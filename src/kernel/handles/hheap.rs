@@ -59,6 +59,25 @@ pub trait kernel_Hheap: Handle {
 
 	/// [`HeapCreate`](https://learn.microsoft.com/en-us/windows/win32/api/heapapi/nf-heapapi-heapcreate)
 	/// function.
+	///
+	/// # Examples
+	///
+	/// Creating a private growable heap, and allocating a block on it. The
+	/// block is freed before the heap is destroyed, because the guards are
+	/// dropped in reverse order:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let heap = w::HHEAP::HeapCreate(None, 0, 0)?;
+	///
+	/// let mut block = heap.HeapAlloc(Some(co::HEAP_ALLOC::ZERO_MEMORY), 100)?;
+	/// block.as_mut_slice()[0] = 0xff;
+	///
+	/// // HeapFree() called here
+	/// // HeapDestroy() called here
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
 	#[must_use]
 	fn HeapCreate(
 		options: Option<co::HEAP_CREATE>,
@@ -221,6 +221,59 @@ pub trait kernel_Hinstance: Handle {
 		)
 	}
 
+	/// [`GetModuleHandleEx`](https://learn.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-getmodulehandleexw)
+	/// function.
+	///
+	/// Unless you pass
+	/// [`UNCHANGED_REFCOUNT`](crate::co::GET_MODULE_HANDLE_EX_FLAG::UNCHANGED_REFCOUNT)
+	/// or [`PIN`](crate::co::GET_MODULE_HANDLE_EX_FLAG::PIN), the module
+	/// reference count is incremented, and the handle must be released with
+	/// [`FreeLibrary`](https://learn.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-freelibrary),
+	/// which can be done by passing it to
+	/// [`FreeLibraryGuard::new`](crate::guard::FreeLibraryGuard::new).
+	///
+	/// # Examples
+	///
+	/// Retrieving the module which contains a given function:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// extern "system" fn some_callback() {}
+	///
+	/// let hinstance = w::HINSTANCE::GetModuleHandleEx(
+	///     co::GET_MODULE_HANDLE_EX_FLAG::UNCHANGED_REFCOUNT,
+	///     w::ModuleNameAddr::Addr(some_callback as _),
+	/// )?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn GetModuleHandleEx(
+		flags: co::GET_MODULE_HANDLE_EX_FLAG,
+		module: ModuleNameAddr,
+	) -> SysResult<HINSTANCE>
+	{
+		let mut hmod = HINSTANCE::NULL;
+		let wname = match &module {
+			ModuleNameAddr::Name(name) => WString::from_str(name),
+			_ => WString::default(),
+		};
+		let (flags, pname) = match module {
+			ModuleNameAddr::Exe
+			| ModuleNameAddr::Name(_) => (flags, wname.as_ptr()),
+			ModuleNameAddr::Addr(addr) => (
+				flags | co::GET_MODULE_HANDLE_EX_FLAG::FROM_ADDRESS,
+				addr as *const u16,
+			),
+		};
+
+		bool_to_sysresult(
+			unsafe {
+				ffi::GetModuleHandleExW(flags.raw(), pname, hmod.as_mut())
+			},
+		).map(|_| hmod)
+	}
+
 	/// [`GetProcAddress`](https://learn.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-getprocaddress)
 	/// function.
 	#[must_use]
@@ -1,5 +1,6 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
+use crate::co;
 use crate::decl::*;
 use crate::guard::*;
 use crate::kernel::{ffi, privs::*};
@@ -7,11 +8,14 @@ use crate::prelude::*;
 
 impl_handle! { HPIPE;
 	/// Handle to an
-	/// [anonymous pipe](https://learn.microsoft.com/en-us/windows/win32/ipc/anonymous-pipes).
+	/// [anonymous pipe](https://learn.microsoft.com/en-us/windows/win32/ipc/anonymous-pipes)
+	/// or a
+	/// [named pipe](https://learn.microsoft.com/en-us/windows/win32/ipc/named-pipes).
 	/// Originally just a `HANDLE`.
 }
 
 impl kernel_Hpipe for HPIPE {}
+impl HandleDuplicate for HPIPE {}
 
 /// This trait is enabled with the `kernel` feature, and provides methods for
 /// [`HPIPE`](crate::HPIPE).
@@ -22,6 +26,82 @@ impl kernel_Hpipe for HPIPE {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait kernel_Hpipe: Handle {
+	/// [`ConnectNamedPipe`](https://learn.microsoft.com/en-us/windows/win32/api/namedpipeapi/nf-namedpipeapi-connectnamedpipe)
+	/// function.
+	///
+	/// If a client connected between the calls to
+	/// [`HPIPE::CreateNamedPipe`](crate::prelude::kernel_Hpipe::CreateNamedPipe)
+	/// and this method, the
+	/// [`ERROR::PIPE_CONNECTED`](crate::co::ERROR::PIPE_CONNECTED) error is not
+	/// reported, since the connection is already established.
+	///
+	/// Blocks until a client connects, therefore the pipe must not have been
+	/// created with
+	/// [`PIPE_ACCESS::OVERLAPPED`](crate::co::PIPE_ACCESS::OVERLAPPED).
+	fn ConnectNamedPipe(&self) -> SysResult<()> {
+		match bool_to_sysresult(
+			unsafe { ffi::ConnectNamedPipe(self.ptr(), std::ptr::null_mut()) },
+		) {
+			Err(co::ERROR::PIPE_CONNECTED) => Ok(()), // client connected before the call
+			res => res,
+		}
+	}
+
+	/// [`CreateNamedPipe`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-createnamedpipew)
+	/// function.
+	///
+	/// # Examples
+	///
+	/// Serving a single client:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hpipe = w::HPIPE::CreateNamedPipe(
+	///     "\\\\.\\pipe\\my_pipe",
+	///     co::PIPE_ACCESS::DUPLEX,
+	///     co::PIPE::TYPE_BYTE | co::PIPE::READMODE_BYTE | co::PIPE::WAIT,
+	///     &w::NamedPipeOpts { max_instances: 1, ..Default::default() },
+	///     None,
+	/// )?;
+	///
+	/// hpipe.ConnectNamedPipe()?;
+	///
+	/// let mut buf = [0u8; 4096];
+	/// let num_read = hpipe.ReadFile(&mut buf, None)?;
+	/// println!("Received {} bytes.", num_read);
+	///
+	/// hpipe.DisconnectNamedPipe()?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn CreateNamedPipe(
+		name: &str,
+		open_mode: co::PIPE_ACCESS,
+		pipe_mode: co::PIPE,
+		opts: &NamedPipeOpts,
+		attrs: Option<&mut SECURITY_ATTRIBUTES>,
+	) -> SysResult<CloseHandleGuard<HPIPE>>
+	{
+		unsafe {
+			match HPIPE::from_ptr(
+				ffi::CreateNamedPipeW(
+					WString::from_str(name).as_ptr(),
+					open_mode.raw(),
+					pipe_mode.raw(),
+					opts.max_instances,
+					opts.out_buffer_size,
+					opts.in_buffer_size,
+					opts.default_timeout,
+					attrs.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _),
+				),
+			) {
+				HPIPE::NULL | HPIPE::INVALID => Err(GetLastError()),
+				handle => Ok(CloseHandleGuard::new(handle)),
+			}
+		}
+	}
+
 	/// [`CreatePipe`](https://learn.microsoft.com/en-us/windows/win32/api/namedpipeapi/nf-namedpipeapi-createpipe)
 	/// function.
 	///
@@ -45,6 +125,12 @@ pub trait kernel_Hpipe: Handle {
 		}
 	}
 
+	/// [`DisconnectNamedPipe`](https://learn.microsoft.com/en-us/windows/win32/api/namedpipeapi/nf-namedpipeapi-disconnectnamedpipe)
+	/// function.
+	fn DisconnectNamedPipe(&self) -> SysResult<()> {
+		bool_to_sysresult(unsafe { ffi::DisconnectNamedPipe(self.ptr()) })
+	}
+
 	/// [`ReadFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-readfile)
 	/// function.
 	///
@@ -69,3 +155,49 @@ pub trait kernel_Hpipe: Handle {
 			.WriteFile(data, overlapped)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::co;
+	use crate::decl::*;
+	use crate::prelude::*;
+
+	#[test]
+	fn named_pipe_round_trip() {
+		let name = format!("\\\\.\\pipe\\winsafe_test_{}_{}",
+			GetCurrentProcessId(), GetCurrentThreadId());
+		let hpipe = HPIPE::CreateNamedPipe(
+			&name,
+			co::PIPE_ACCESS::DUPLEX,
+			co::PIPE::TYPE_BYTE | co::PIPE::READMODE_BYTE | co::PIPE::WAIT,
+			&NamedPipeOpts { max_instances: 1, ..Default::default() },
+			None,
+		).unwrap();
+
+		let client = std::thread::spawn({
+			let name = name.clone();
+			move || {
+				WaitNamedPipe(&name, Some(5000)).unwrap();
+				let (hfile, _) = HFILE::CreateFile(
+					&name,
+					co::GENERIC::WRITE,
+					None,
+					None,
+					co::DISPOSITION::OPEN_EXISTING,
+					co::FILE_ATTRIBUTE::NORMAL,
+					None,
+					None,
+					None,
+				).unwrap();
+				hfile.WriteFile(b"ping", None).unwrap()
+			}
+		});
+
+		hpipe.ConnectNamedPipe().unwrap();
+		let mut buf = [0u8; 16];
+		let num_read = hpipe.ReadFile(&mut buf, None).unwrap();
+		assert_eq!(client.join().unwrap(), 4);
+		assert_eq!(&buf[..num_read as usize], b"ping");
+		hpipe.DisconnectNamedPipe().unwrap();
+	}
+}
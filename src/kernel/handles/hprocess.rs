@@ -13,6 +13,7 @@ impl_handle! { HPROCESS;
 }
 
 impl kernel_Hprocess for HPROCESS {}
+impl HandleDuplicate for HPROCESS {}
 
 /// This trait is enabled with the `kernel` feature, and provides methods for
 /// [`HPROCESS`](crate::HPROCESS).
@@ -23,6 +24,31 @@ impl kernel_Hprocess for HPROCESS {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait kernel_Hprocess: Handle {
+	/// Returns the total CPU time consumed by the process, that is, the sum of
+	/// kernel and user times, by calling
+	/// [`HPROCESS::GetProcessTimes`](crate::prelude::kernel_Hprocess::GetProcessTimes).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let cpu = w::HPROCESS::GetCurrentProcess().cpu_time()?;
+	/// println!("CPU time: {} ms", cpu.as_millis());
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn cpu_time(&self) -> SysResult<std::time::Duration> {
+		let (mut creation, mut exit, mut kernel, mut user) = (
+			FILETIME::default(),
+			FILETIME::default(),
+			FILETIME::default(),
+			FILETIME::default(),
+		);
+		self.GetProcessTimes(&mut creation, &mut exit, &mut kernel, &mut user)?;
+		Ok(filetime_to_duration(&kernel) + filetime_to_duration(&user))
+	}
+
 	/// [`CheckRemoteDebuggerPresent`](https://learn.microsoft.com/en-us/windows/win32/api/debugapi/nf-debugapi-checkremotedebuggerpresent)
 	/// function.
 	#[must_use]
@@ -75,6 +101,49 @@ pub trait kernel_Hprocess: Handle {
 		}
 	}
 
+	/// [`EnumProcessModules`](https://learn.microsoft.com/en-us/windows/win32/api/psapi/nf-psapi-enumprocessmodules)
+	/// function.
+	///
+	/// Returns the modules loaded by the process. The process must have been
+	/// opened with [`PROCESS::QUERY_INFORMATION`](crate::co::PROCESS::QUERY_INFORMATION)
+	/// and [`PROCESS::VM_READ`](crate::co::PROCESS::VM_READ) access rights.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hprocess = w::HPROCESS::GetCurrentProcess();
+	/// for hmodule in hprocess.EnumProcessModules()? {
+	///     println!("{}", hprocess.GetModuleBaseName(Some(&hmodule))?);
+	/// }
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn EnumProcessModules(&self) -> SysResult<Vec<HINSTANCE>> {
+		let mut hmodules = Vec::<HINSTANCE>::new();
+		loop {
+			let mut bytes_needed = u32::default();
+			bool_to_sysresult(
+				unsafe {
+					ffi::EnumProcessModules(
+						self.ptr(),
+						hmodules.as_mut_ptr() as _,
+						(hmodules.len() * std::mem::size_of::<HINSTANCE>()) as _,
+						&mut bytes_needed,
+					)
+				},
+			)?;
+
+			let count = bytes_needed as usize / std::mem::size_of::<HINSTANCE>();
+			if count <= hmodules.len() {
+				hmodules.truncate(count);
+				return Ok(hmodules);
+			}
+			hmodules.resize_with(count, || HINSTANCE::NULL); // modules may be loaded meanwhile, try again
+		}
+	}
+
 	/// [`FlushInstructionCache`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-flushinstructioncache)
 	/// function.
 	fn FlushInstructionCache(&self,
@@ -114,6 +183,35 @@ pub trait kernel_Hprocess: Handle {
 		}
 	}
 
+	/// [`GetModuleBaseName`](https://learn.microsoft.com/en-us/windows/win32/api/psapi/nf-psapi-getmodulebasenamew)
+	/// function.
+	///
+	/// If `hmodule` is `None`, returns the name of the process executable.
+	///
+	/// For an example, see
+	/// [`HPROCESS::EnumProcessModules`](crate::prelude::kernel_Hprocess::EnumProcessModules).
+	#[must_use]
+	fn GetModuleBaseName(&self,
+		hmodule: Option<&HINSTANCE>,
+	) -> SysResult<String>
+	{
+		let mut buf = WString::new_alloc_buf(MAX_PATH + 1);
+		loop {
+			match unsafe {
+				ffi::GetModuleBaseNameW(
+					self.ptr(),
+					hmodule.map_or(std::ptr::null_mut(), |h| h.ptr()),
+					buf.as_mut_ptr(),
+					buf.buf_len() as _,
+				)
+			} {
+				0 => return Err(GetLastError()),
+				len if (len as usize) < buf.buf_len() - 1 => return Ok(buf.to_string()),
+				_ => buf = WString::new_alloc_buf(buf.buf_len() * 2), // name may be truncated, try again
+			}
+		}
+	}
+
 	/// [`GetPriorityClass`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getpriorityclass)
 	/// function.
 	#[must_use]
@@ -144,6 +242,32 @@ pub trait kernel_Hprocess: Handle {
 		}
 	}
 
+	/// [`GetProcessMemoryInfo`](https://learn.microsoft.com/en-us/windows/win32/api/psapi/nf-psapi-getprocessmemoryinfo)
+	/// function.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let pmc = w::HPROCESS::GetCurrentProcess().GetProcessMemoryInfo()?;
+	/// println!("Working set: {} KB", pmc.WorkingSetSize / 1024);
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn GetProcessMemoryInfo(&self) -> SysResult<PROCESS_MEMORY_COUNTERS> {
+		let mut pmc = PROCESS_MEMORY_COUNTERS::default();
+		bool_to_sysresult(
+			unsafe {
+				ffi::GetProcessMemoryInfo(
+					self.ptr(),
+					&mut pmc as *mut _ as _,
+					std::mem::size_of::<PROCESS_MEMORY_COUNTERS>() as _,
+				)
+			},
+		).map(|_| pmc)
+	}
+
 	/// [`GetProcessTimes`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getprocesstimes)
 	/// function.
 	fn GetProcessTimes(&self,
@@ -340,3 +464,28 @@ pub trait kernel_Hprocess: Handle {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::decl::*;
+	use crate::prelude::*;
+
+	#[test]
+	fn current_process_cpu_time() {
+		let mut acc = 0u64;
+		for i in 0..50_000_000u64 { // burn some CPU time
+			acc = acc.wrapping_add(std::hint::black_box(i));
+		}
+		std::hint::black_box(acc);
+
+		let cpu = HPROCESS::GetCurrentProcess().cpu_time().unwrap();
+		assert!(!cpu.is_zero());
+	}
+
+	#[test]
+	fn current_process_memory_info() {
+		let pmc = HPROCESS::GetCurrentProcess().GetProcessMemoryInfo().unwrap();
+		assert!(pmc.WorkingSetSize > 0);
+		assert!(pmc.PeakWorkingSetSize >= pmc.WorkingSetSize);
+	}
+}
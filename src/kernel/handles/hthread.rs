@@ -13,6 +13,7 @@ impl_handle! { HTHREAD;
 }
 
 impl kernel_Hthread for HTHREAD {}
+impl HandleDuplicate for HTHREAD {}
 
 /// This trait is enabled with the `kernel` feature, and provides methods for
 /// [`HTHREAD`](crate::HTHREAD).
@@ -23,6 +24,31 @@ impl kernel_Hthread for HTHREAD {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait kernel_Hthread: Handle {
+	/// Returns the total CPU time consumed by the thread, that is, the sum of
+	/// kernel and user times, by calling
+	/// [`HTHREAD::GetThreadTimes`](crate::prelude::kernel_Hthread::GetThreadTimes).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let cpu = w::HTHREAD::GetCurrentThread().cpu_time()?;
+	/// println!("CPU time: {} ms", cpu.as_millis());
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn cpu_time(&self) -> SysResult<std::time::Duration> {
+		let (mut creation, mut exit, mut kernel, mut user) = (
+			FILETIME::default(),
+			FILETIME::default(),
+			FILETIME::default(),
+			FILETIME::default(),
+		);
+		self.GetThreadTimes(&mut creation, &mut exit, &mut kernel, &mut user)?;
+		Ok(filetime_to_duration(&kernel) + filetime_to_duration(&user))
+	}
+
 	/// [`CreateThread`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-createthread)
 	/// function.
 	///
@@ -78,6 +104,22 @@ pub trait kernel_Hthread: Handle {
 		}
 	}
 
+	/// [`GetThreadDescription`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getthreaddescription)
+	/// function.
+	///
+	/// Available since Windows 10 version 1607.
+	#[must_use]
+	fn GetThreadDescription(&self) -> SysResult<String> {
+		let mut pstr = std::ptr::null_mut::<u16>();
+		hresult_to_sysresult(
+			unsafe { ffi::GetThreadDescription(self.ptr(), &mut pstr) },
+		).map(|_| {
+			let description = WString::from_wchars_nullt(pstr).to_string();
+			let _ = unsafe { LocalFreeGuard::new(HLOCAL::from_ptr(pstr as _)) };
+			description
+		})
+	}
+
 	/// [`GetThreadId`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getthreadid)
 	/// function.
 	#[must_use]
@@ -137,6 +179,35 @@ pub trait kernel_Hthread: Handle {
 		minus1_as_error(unsafe { ffi::ResumeThread(self.ptr()) })
 	}
 
+	/// [`SetThreadDescription`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-setthreaddescription)
+	/// function.
+	///
+	/// Available since Windows 10 version 1607.
+	///
+	/// # Examples
+	///
+	/// Naming the current thread, so it's shown in debuggers and profilers:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hthread = w::HTHREAD::GetCurrentThread();
+	/// hthread.SetThreadDescription("worker")?;
+	///
+	/// let name = hthread.GetThreadDescription()?;
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	fn SetThreadDescription(&self, description: &str) -> SysResult<()> {
+		hresult_to_sysresult(
+			unsafe {
+				ffi::SetThreadDescription(
+					self.ptr(),
+					WString::from_str(description).as_ptr(),
+				)
+			},
+		)
+	}
+
 	/// [`SetThreadIdealProcessor`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-setthreadidealprocessor)
 	/// function.
 	///
@@ -197,3 +268,18 @@ pub trait kernel_Hthread: Handle {
 		)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::decl::*;
+	use crate::prelude::*;
+
+	#[test]
+	fn thread_description_round_trip() {
+		std::thread::spawn(|| {
+			let hthread = HTHREAD::GetCurrentThread();
+			hthread.SetThreadDescription("winsafe worker").unwrap();
+			assert_eq!(hthread.GetThreadDescription().unwrap(), "winsafe worker");
+		}).join().unwrap();
+	}
+}
@@ -0,0 +1,96 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::{ffi, ffi_types::*, privs::*};
+use crate::prelude::*;
+
+impl_handle! { HTHREADPOOLWORK;
+	/// Handle to a
+	/// [thread pool work](https://learn.microsoft.com/en-us/windows/win32/api/threadpoolapiset/nf-threadpoolapiset-createthreadpoolwork)
+	/// object. Originally `PTP_WORK`.
+}
+
+impl kernel_Hthreadpoolwork for HTHREADPOOLWORK {}
+
+/// This trait is enabled with the `kernel` feature, and provides methods for
+/// [`HTHREADPOOLWORK`](crate::HTHREADPOOLWORK).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait kernel_Hthreadpoolwork: Handle {
+	/// [`CreateThreadpoolWork`](https://learn.microsoft.com/en-us/windows/win32/api/threadpoolapiset/nf-threadpoolapiset-createthreadpoolwork)
+	/// function.
+	///
+	/// The closure is owned by the returned guard, which, when dropped, waits
+	/// for all the outstanding callbacks to finish before closing the work
+	/// object and freeing the closure.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use std::sync::{Arc, atomic::{AtomicU32, Ordering}};
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let counter = Arc::new(AtomicU32::new(0));
+	///
+	/// let hwork = w::HTHREADPOOLWORK::CreateThreadpoolWork({
+	///     let counter = counter.clone();
+	///     move || {
+	///         counter.fetch_add(1, Ordering::SeqCst);
+	///     }
+	/// })?;
+	///
+	/// hwork.SubmitThreadpoolWork();
+	/// hwork.SubmitThreadpoolWork();
+	/// hwork.WaitForThreadpoolWorkCallbacks(false);
+	///
+	/// assert_eq!(counter.load(Ordering::SeqCst), 2);
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn CreateThreadpoolWork<F>(func: F) -> SysResult<CloseThreadpoolWorkGuard>
+		where F: Fn() + Send + Sync + 'static,
+	{
+		let func = Box::new(func);
+		unsafe {
+			ptr_to_sysresult_handle(
+				ffi::CreateThreadpoolWork(
+					threadpool_work_proc::<F> as _,
+					&*func as *const F as _,
+					std::ptr::null_mut(),
+				),
+			).map(|h| CloseThreadpoolWorkGuard::new(h, func))
+		}
+	}
+
+	/// [`SubmitThreadpoolWork`](https://learn.microsoft.com/en-us/windows/win32/api/threadpoolapiset/nf-threadpoolapiset-submitthreadpoolwork)
+	/// function.
+	fn SubmitThreadpoolWork(&self) {
+		unsafe { ffi::SubmitThreadpoolWork(self.ptr()); }
+	}
+
+	/// [`WaitForThreadpoolWorkCallbacks`](https://learn.microsoft.com/en-us/windows/win32/api/threadpoolapiset/nf-threadpoolapiset-waitforthreadpoolworkcallbacks)
+	/// function.
+	fn WaitForThreadpoolWorkCallbacks(&self, cancel_pending_callbacks: bool) {
+		unsafe {
+			ffi::WaitForThreadpoolWorkCallbacks(
+				self.ptr(),
+				cancel_pending_callbacks as _,
+			);
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+extern "system" fn threadpool_work_proc<F>(
+	_: PVOID, context: PVOID, _: HANDLE)
+	where F: Fn() + Send + Sync + 'static,
+{
+	let func = unsafe { &*(context as *const F) };
+	func();
+}
@@ -1,5 +1,6 @@
 mod haccesstoken;
 mod handle_traits;
+mod hevent;
 mod heventlog;
 mod hfile;
 mod hfilemap;
@@ -15,11 +16,13 @@ mod hprocess;
 mod hprocesslist;
 mod hstd;
 mod hthread;
+mod hthreadpoolwork;
 mod htransaction;
 mod hupdatesrc;
 
 pub mod decl {
 	pub use super::haccesstoken::HACCESSTOKEN;
+	pub use super::hevent::HEVENT;
 	pub use super::heventlog::HEVENTLOG;
 	pub use super::hfile::HFILE;
 	pub use super::hfilemap::HFILEMAP;
@@ -35,15 +38,10 @@ pub mod decl {
 	pub use super::hprocesslist::HPROCESSLIST;
 	pub use super::hstd::HSTD;
 	pub use super::hthread::HTHREAD;
+	pub use super::hthreadpoolwork::HTHREADPOOLWORK;
 	pub use super::htransaction::HTRANSACTION;
 	pub use super::hupdatesrc::HUPDATERSRC;
 
-	impl_handle! { HEVENT;
-		/// Handle to an
-		/// [event](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-createeventw).
-		/// Originally just a `HANDLE`.
-	}
-
 	impl_handle! { HRSRC;
 		/// Handle to a
 		/// [resource](https://learn.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-findresourcew).
@@ -66,6 +64,7 @@ pub mod decl {
 pub mod traits {
 	pub use super::haccesstoken::kernel_Haccesstoken;
 	pub use super::handle_traits::*;
+	pub use super::hevent::kernel_Hevent;
 	pub use super::heventlog::kernel_Heventlog;
 	pub use super::hfile::kernel_Hfile;
 	pub use super::hfilemap::kernel_Hfilemap;
@@ -81,6 +80,7 @@ pub mod traits {
 	pub use super::hprocesslist::kernel_Hprocesslist;
 	pub use super::hstd::kernel_Hstd;
 	pub use super::hthread::kernel_Hthread;
+	pub use super::hthreadpoolwork::kernel_Hthreadpoolwork;
 	pub use super::htransaction::kernel_Htransaction;
 	pub use super::hupdatesrc::kernel_Hupdatersrc;
 }
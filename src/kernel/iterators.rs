@@ -1,8 +1,65 @@
 use crate::co;
 use crate::decl::*;
+use crate::guard::*;
 use crate::kernel::ffi;
 use crate::prelude::*;
 
+pub(in crate::kernel) struct HfindfileIter {
+	file_name: String,
+	hfind: Option<FindCloseGuard>,
+	wfd: WIN32_FIND_DATA,
+	has_more: bool,
+}
+
+impl Iterator for HfindfileIter {
+	type Item = SysResult<WIN32_FIND_DATA>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if !self.has_more {
+			return None;
+		}
+
+		let has_more_res = match &self.hfind {
+			None => { // first pass
+				HFINDFILE::FindFirstFile(&self.file_name, &mut self.wfd)
+					.map(|(hfind, found)| {
+						self.hfind = Some(hfind); // FindClose will be called on drop
+						found
+					})
+			},
+			Some(hfind) => hfind.FindNextFile(&mut self.wfd),
+		};
+
+		match has_more_res {
+			Err(e) => {
+				self.has_more = false; // no further iterations
+				Some(Err(e))
+			},
+			Ok(has_more) => {
+				self.has_more = has_more;
+				if has_more {
+					Some(Ok(self.wfd.clone()))
+				} else {
+					None // no file found
+				}
+			},
+		}
+	}
+}
+
+impl HfindfileIter {
+	pub(in crate::kernel) fn new(file_name: &str) -> Self {
+		Self {
+			file_name: file_name.to_owned(),
+			hfind: None,
+			wfd: WIN32_FIND_DATA::default(),
+			has_more: true,
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
 pub(in crate::kernel) struct HheapHeapwalkIter<'a, H>
 	where H: kernel_Hheap,
 {
@@ -2,7 +2,7 @@
 
 use crate::co;
 use crate::decl::*;
-use crate::kernel::ffi_types::*;
+use crate::kernel::{ffi, ffi_types::*};
 use crate::prelude::*;
 
 pub(crate) const GMEM_INVALID_HANDLE: u32 = 0x8000;
@@ -70,6 +70,21 @@ pub(crate) const fn error_to_sysresult(lstatus: i32) -> SysResult<()> {
 	}
 }
 
+/// If the `HRESULT` is a failure, yields `Err(ERROR)` with its code, otherwise
+/// `Ok()`. Used by the few kernel functions which return `HRESULT`.
+///
+/// Only `FACILITY_WIN32` codes carry a Win32 error; any other failure yields
+/// `ERROR::SXS_UNTRANSLATABLE_HRESULT`.
+pub(crate) const fn hresult_to_sysresult(hr: HRES) -> SysResult<()> {
+	const FACILITY_WIN32: u32 = 7;
+	let facility = (hr >> 16) & 0x1fff;
+	match hr as i32 {
+		0.. => Ok(()),
+		_ if facility == FACILITY_WIN32 => Err(unsafe { co::ERROR::from_raw(hr & 0xffff) }),
+		_ => Err(co::ERROR::SXS_UNTRANSLATABLE_HRESULT),
+	}
+}
+
 /// If value is -1, yields `Err(GetLastError())`, otherwise `Ok(dword)`.
 pub(crate) fn minus1_as_error(dword: u32) -> SysResult<u32> {
 	const MINUS_ONE: u32 = -1i32 as u32;
@@ -79,6 +94,54 @@ pub(crate) fn minus1_as_error(dword: u32) -> SysResult<u32> {
 	}
 }
 
+/// Calls a function which writes a path into the given buffer, returning its
+/// length, or the required buffer size if the buffer is too small. The buffer
+/// grows until the path fits; a zero length yields `Err(GetLastError)`.
+pub(crate) fn grow_path_buf(
+	mut get: impl FnMut(*mut u16, u32) -> u32,
+) -> SysResult<String>
+{
+	let mut buf = WString::new_alloc_buf(MAX_PATH + 1);
+	loop {
+		match get(unsafe { buf.as_mut_ptr() }, buf.buf_len() as _) {
+			0 => return Err(GetLastError()),
+			len if len as usize >= buf.buf_len() => { // buffer too small, len is the required size
+				buf = WString::new_alloc_buf(len as _);
+			},
+			_ => return Ok(buf.to_string()),
+		}
+	}
+}
+
+/// If a synchronous call to an overlapped function failed with
+/// `ERROR::IO_PENDING`, waits until the operation completes, so the borrowed
+/// buffer and `OVERLAPPED` outlive it. Returns the bytes transferred.
+pub(crate) fn wait_if_pending(
+	hfile: HANDLE,
+	poverlapped: PVOID,
+	res: SysResult<u32>,
+) -> SysResult<u32>
+{
+	match res {
+		Err(co::ERROR::IO_PENDING) if !poverlapped.is_null() => {
+			let mut bytes_transferred = u32::default();
+			bool_to_sysresult(
+				unsafe {
+					ffi::GetOverlappedResult(hfile, poverlapped, &mut bytes_transferred, 1)
+				},
+			).map(|_| bytes_transferred)
+		},
+		res => res,
+	}
+}
+
+/// Converts a [`FILETIME`](crate::FILETIME) holding a time interval, in
+/// 100-nanosecond units, to a `Duration`.
+pub(crate) const fn filetime_to_duration(ft: &FILETIME) -> std::time::Duration {
+	let intervals = ((ft.dwHighDateTime as u64) << 32) | ft.dwLowDateTime as u64;
+	std::time::Duration::from_nanos(intervals * 100)
+}
+
 /// Converts a string to an ISO-8859-1 null-terminated byte array.
 pub(crate) fn str_to_iso88591(s: &str) -> Vec<u8> {
 	s.chars().map(|ch| ch as u8)
@@ -107,3 +170,18 @@ pub(crate) fn parse_multi_z_str(src: *const u16) -> Vec<String> {
 	}
 	strings
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::co;
+	use super::hresult_to_sysresult;
+
+	#[test]
+	fn hresult_facilities() {
+		assert_eq!(hresult_to_sysresult(0), Ok(())); // S_OK
+		assert_eq!(hresult_to_sysresult(1), Ok(())); // S_FALSE
+		assert_eq!(hresult_to_sysresult(0x8007_0005), Err(co::ERROR::ACCESS_DENIED));
+		assert_eq!(hresult_to_sysresult(0x8000_4005), // E_FAIL
+			Err(co::ERROR::SXS_UNTRANSLATABLE_HRESULT));
+	}
+}
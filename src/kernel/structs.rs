@@ -20,6 +20,25 @@ pub struct ACL {
 	pub Sbz2: u16,
 }
 
+/// [`ATOM`](https://learn.microsoft.com/en-us/windows/win32/winprog/windows-data-types#atom)
+/// returned by [`GlobalAddAtom`](crate::GlobalAddAtom) and `RegisterClassEx`.
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ATOM(u16);
+
+impl_intunderlying!(ATOM, u16);
+
+impl std::fmt::Display for ATOM {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(&self.0, f)
+	}
+}
+impl std::fmt::Debug for ATOM {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "ATOM [{:#06x} {}]", self.0, self.0)
+	}
+}
+
 /// [`BY_HANDLE_FILE_INFORMATION`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/ns-fileapi-by_handle_file_information)
 /// struct.
 #[repr(C)]
@@ -37,6 +56,23 @@ pub struct BY_HANDLE_FILE_INFORMATION {
 	pub nFileIndexLow: u32,
 }
 
+impl BY_HANDLE_FILE_INFORMATION {
+	/// Returns the nFileSizeHigh and nFileSizeLow fields.
+	#[must_use]
+	pub const fn nFileSize(&self) -> u64 {
+		MAKEQWORD(self.nFileSizeLow, self.nFileSizeHigh)
+	}
+
+	/// Returns the nFileIndexHigh and nFileIndexLow fields.
+	///
+	/// Along with `dwVolumeSerialNumber`, it uniquely identifies a file, so it
+	/// can be used to detect hard links.
+	#[must_use]
+	pub const fn nFileIndex(&self) -> u64 {
+		MAKEQWORD(self.nFileIndexLow, self.nFileIndexHigh)
+	}
+}
+
 /// [`CONSOLE_READCONSOLE_CONTROL`](https://learn.microsoft.com/en-us/windows/console/console-readconsole-control)
 /// struct.
 #[repr(C)]
@@ -111,7 +147,11 @@ impl std::fmt::Debug for GUID {
 
 impl GUID {
 	/// Creates a new `GUID` from a representative hex string, which can be
-	/// copied straight from standard `GUID` declarations.
+	/// copied straight from standard `GUID` declarations. The string may be
+	/// enclosed in curly braces.
+	///
+	/// When used in a const context, an invalid string fails the build. See
+	/// also the [`guid!`](crate::guid) macro.
 	///
 	/// # Panics
 	///
@@ -126,18 +166,29 @@ impl GUID {
 	/// ```
 	#[must_use]
 	pub const fn new(guid_str: &str) -> Self {
-		if guid_str.len() != 36 {
-			panic!("Bad number of GUID chars.");
+		let chs = guid_str.as_bytes();
+		let o = match chs.len() { // offset to skip the opening brace
+			36 => 0,
+			38 if chs[0] == b'{' && chs[37] == b'}' => 1,
+			_ => panic!("Bad number of GUID chars."),
+		};
+		if chs[o + 8] != b'-' || chs[o + 13] != b'-'
+			|| chs[o + 18] != b'-' || chs[o + 23] != b'-'
+		{
+			panic!("Bad GUID separator.");
 		}
 
-		let chs = guid_str.as_bytes();
-		let p1 = Self::parse_block([chs[0], chs[1], chs[2], chs[3], chs[4],
-			chs[5], chs[6], chs[7]]);
-		let p2 = Self::parse_block([chs[9], chs[10], chs[11], chs[12]]);
-		let p3 = Self::parse_block([chs[14], chs[15], chs[16], chs[17]]);
-		let p4 = Self::parse_block([chs[19], chs[20], chs[21], chs[22]]);
-		let p5 = Self::parse_block([chs[24], chs[25], chs[26], chs[27], chs[28],
-			chs[29], chs[30], chs[31], chs[32], chs[33], chs[34], chs[35]]);
+		let p1 = Self::parse_block([chs[o], chs[o + 1], chs[o + 2], chs[o + 3],
+			chs[o + 4], chs[o + 5], chs[o + 6], chs[o + 7]]);
+		let p2 = Self::parse_block([chs[o + 9], chs[o + 10], chs[o + 11],
+			chs[o + 12]]);
+		let p3 = Self::parse_block([chs[o + 14], chs[o + 15], chs[o + 16],
+			chs[o + 17]]);
+		let p4 = Self::parse_block([chs[o + 19], chs[o + 20], chs[o + 21],
+			chs[o + 22]]);
+		let p5 = Self::parse_block([chs[o + 24], chs[o + 25], chs[o + 26],
+			chs[o + 27], chs[o + 28], chs[o + 29], chs[o + 30], chs[o + 31],
+			chs[o + 32], chs[o + 33], chs[o + 34], chs[o + 35]]);
 
 		Self {
 			data1: p1 as _,
@@ -147,6 +198,32 @@ impl GUID {
 		}
 	}
 
+	/// Creates a new `GUID` from its binary representation, as laid out in
+	/// memory.
+	#[must_use]
+	pub const fn from_bytes(b: [u8; 16]) -> Self {
+		Self {
+			data1: u32::from_le_bytes([b[0], b[1], b[2], b[3]]),
+			data2: u16::from_le_bytes([b[4], b[5]]),
+			data3: u16::from_le_bytes([b[6], b[7]]),
+			data4: u64::from_le_bytes([b[8], b[9], b[10], b[11], b[12], b[13],
+				b[14], b[15]]),
+		}
+	}
+
+	/// Returns the binary representation of the `GUID`, as laid out in memory.
+	///
+	/// This is the inverse of [`GUID::from_bytes`](crate::GUID::from_bytes).
+	#[must_use]
+	pub const fn to_bytes(&self) -> [u8; 16] {
+		let d1 = self.data1.to_le_bytes();
+		let d2 = self.data2.to_le_bytes();
+		let d3 = self.data3.to_le_bytes();
+		let d4 = self.data4.to_le_bytes();
+		[d1[0], d1[1], d1[2], d1[3], d2[0], d2[1], d3[0], d3[1],
+			d4[0], d4[1], d4[2], d4[3], d4[4], d4[5], d4[6], d4[7]]
+	}
+
 	const fn parse_block<const N: usize>(chars: [u8; N]) -> u64 {
 		let mut res: u64 = 0;
 		let mut idx: usize = 0;
@@ -404,16 +481,44 @@ impl OSVERSIONINFOEX {
 
 /// [`OVERLAPPED`](https://learn.microsoft.com/en-us/windows/win32/api/minwinbase/ns-minwinbase-overlapped)
 /// struct.
+///
+/// The `Pointer` field shares its memory with the `Offset` and `OffsetHigh`
+/// fields, which are accessed through [`Offset`](crate::OVERLAPPED::Offset)
+/// and [`set_Offset`](crate::OVERLAPPED::set_Offset).
 #[repr(C)]
 pub struct OVERLAPPED {
 	pub Internal: usize,
 	pub InternalHigh: usize,
 	pub Pointer: usize,
+	#[cfg(target_pointer_width = "32")]
+	OffsetHigh: u32, // Pointer is only 32 bits, the union is 64
 	pub hEvent: HEVENT,
 }
 
 impl_default!(OVERLAPPED);
 
+impl OVERLAPPED {
+	/// Returns the Offset and OffsetHigh fields.
+	#[must_use]
+	pub const fn Offset(&self) -> u64 {
+		#[cfg(target_pointer_width = "64")]
+		{ self.Pointer as _ }
+		#[cfg(target_pointer_width = "32")]
+		{ MAKEQWORD(self.Pointer as _, self.OffsetHigh) }
+	}
+
+	/// Sets the Offset and OffsetHigh fields.
+	pub fn set_Offset(&mut self, offset: u64) {
+		#[cfg(target_pointer_width = "64")]
+		{ self.Pointer = offset as _; }
+		#[cfg(target_pointer_width = "32")]
+		{
+			self.Pointer = LODWORD(offset) as _;
+			self.OffsetHigh = HIDWORD(offset);
+		}
+	}
+}
+
 /// [`PROCESS_HEAP_ENTRY`](https://learn.microsoft.com/en-us/windows/win32/api/minwinbase/ns-minwinbase-process_heap_entry)
 /// struct.
 #[repr(C)]
@@ -486,6 +591,24 @@ pub struct PROCESS_INFORMATION {
 
 impl_default!(PROCESS_INFORMATION);
 
+/// [`PROCESS_MEMORY_COUNTERS`](https://learn.microsoft.com/en-us/windows/win32/api/psapi/ns-psapi-process_memory_counters)
+/// struct.
+#[repr(C)]
+pub struct PROCESS_MEMORY_COUNTERS {
+	cb: u32,
+	pub PageFaultCount: u32,
+	pub PeakWorkingSetSize: usize,
+	pub WorkingSetSize: usize,
+	pub QuotaPeakPagedPoolUsage: usize,
+	pub QuotaPagedPoolUsage: usize,
+	pub QuotaPeakNonPagedPoolUsage: usize,
+	pub QuotaNonPagedPoolUsage: usize,
+	pub PagefileUsage: usize,
+	pub PeakPagefileUsage: usize,
+}
+
+impl_default_with_size!(PROCESS_MEMORY_COUNTERS, cb);
+
 /// [`PROCESSENTRY32`](https://learn.microsoft.com/en-us/windows/win32/api/tlhelp32/ns-tlhelp32-processentry32w)
 /// struct.
 #[repr(C)]
@@ -1004,6 +1127,7 @@ impl VALENT {
 /// [`WIN32_FIND_DATA`](https://learn.microsoft.com/en-us/windows/win32/api/minwinbase/ns-minwinbase-win32_find_dataw)
 /// struct.
 #[repr(C)]
+#[derive(Clone)]
 pub struct WIN32_FIND_DATA {
 	pub dwFileAttributes: co::FILE_ATTRIBUTE,
 	pub ftCreationTime: FILETIME,
@@ -0,0 +1,11 @@
+use crate::co;
+
+/// A change in a directory, returned by
+/// [`HFILE::ReadDirectoryChanges`](crate::prelude::kernel_Hfile::ReadDirectoryChanges).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DirChange {
+	/// The kind of change.
+	pub action: co::FILE_ACTION,
+	/// The file name, relative to the watched directory.
+	pub file_name: String,
+}
@@ -85,6 +85,40 @@ impl FileMapped {
 		self.hview.as_slice(self.size as _)
 	}
 
+	/// Returns a bounds-checked slice to the mapped memory, or `None` if the
+	/// range goes beyond the file size.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let f = w::FileMapped::open(
+	///     "C:\\Temp\\foo.txt",
+	///     w::FileAccess::ExistingReadOnly,
+	/// )?;
+	///
+	/// if let Some(header) = f.get(0..4) {
+	///     println!("{:?}", header);
+	/// }
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	pub fn get<R>(&self, range: R) -> Option<&[u8]>
+		where R: std::slice::SliceIndex<[u8], Output = [u8]>,
+	{
+		self.as_slice().get(range)
+	}
+
+	/// Returns a bounds-checked mutable slice to the mapped memory, or `None`
+	/// if the range goes beyond the file size.
+	#[must_use]
+	pub fn get_mut<R>(&mut self, range: R) -> Option<&mut [u8]>
+		where R: std::slice::SliceIndex<[u8], Output = [u8]>,
+	{
+		self.as_mut_slice().get_mut(range)
+	}
+
 	/// Returns the underlying file handle.
 	#[must_use]
 	pub fn hfile(&self) -> &HFILE {
@@ -0,0 +1,47 @@
+use crate::decl::*;
+
+/// High-resolution timer based on
+/// [`QueryPerformanceCounter`](crate::QueryPerformanceCounter) and
+/// [`QueryPerformanceFrequency`](crate::QueryPerformanceFrequency).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let timer = w::HiResTimer::start()?;
+///
+/// // perform some operation...
+///
+/// println!("Operation lasted {:?}", timer.elapsed()?);
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct HiResTimer {
+	freq: i64,
+	t0: i64,
+}
+
+impl HiResTimer {
+	/// Retrieves the counter frequency and starts counting.
+	#[must_use]
+	pub fn start() -> SysResult<Self> {
+		let freq = QueryPerformanceFrequency()?;
+		let t0 = QueryPerformanceCounter()?;
+		Ok(Self { freq, t0 })
+	}
+
+	/// Returns the time elapsed since the timer was started.
+	#[must_use]
+	pub fn elapsed(&self) -> SysResult<std::time::Duration> {
+		let ticks = (QueryPerformanceCounter()? - self.t0) as u128;
+		let nanos = ticks * 1_000_000_000 / self.freq as u128;
+		Ok(std::time::Duration::from_nanos(nanos as _))
+	}
+
+	/// Starts counting again from now.
+	pub fn restart(&mut self) -> SysResult<()> {
+		self.t0 = QueryPerformanceCounter()?;
+		Ok(())
+	}
+}
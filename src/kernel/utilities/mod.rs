@@ -1,15 +1,23 @@
+mod dir_change;
 mod encoding;
 mod file_mapped;
 mod file;
 mod heap_block;
+mod hi_res_timer;
 mod ini;
+mod named_pipe_opts;
+mod overlapped;
 mod w_string;
 
 pub mod path;
 
+pub use dir_change::DirChange;
 pub use encoding::Encoding;
 pub use file_mapped::FileMapped;
 pub use file::{File, FileAccess};
 pub use heap_block::HeapBlock;
+pub use hi_res_timer::HiResTimer;
 pub use ini::{Ini, IniEntry, IniSection};
+pub use named_pipe_opts::NamedPipeOpts;
+pub use overlapped::Overlapped;
 pub use w_string::WString;
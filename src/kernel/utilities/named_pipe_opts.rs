@@ -0,0 +1,34 @@
+/// Buffer sizes and limits of a named pipe, passed to
+/// [`HPIPE::CreateNamedPipe`](crate::prelude::kernel_Hpipe::CreateNamedPipe).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NamedPipeOpts {
+	/// Maximum number of instances which can be created for the pipe, from 1
+	/// to 255; 255 means unlimited.
+	///
+	/// Defaults to `255`.
+	pub max_instances: u32,
+	/// Number of bytes to reserve for the output buffer.
+	///
+	/// Defaults to `4096`.
+	pub out_buffer_size: u32,
+	/// Number of bytes to reserve for the input buffer.
+	///
+	/// Defaults to `4096`.
+	pub in_buffer_size: u32,
+	/// Default time-out value, in milliseconds, used by
+	/// [`WaitNamedPipe`](crate::WaitNamedPipe); zero means 50 milliseconds.
+	///
+	/// Defaults to `0`.
+	pub default_timeout: u32,
+}
+
+impl Default for NamedPipeOpts {
+	fn default() -> Self {
+		Self {
+			max_instances: 255, // PIPE_UNLIMITED_INSTANCES
+			out_buffer_size: 4096,
+			in_buffer_size: 4096,
+			default_timeout: 0,
+		}
+	}
+}
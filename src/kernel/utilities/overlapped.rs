@@ -0,0 +1,283 @@
+#![allow(non_snake_case)]
+
+use std::pin::Pin;
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::{ffi, ffi_types::*, privs::*};
+use crate::prelude::*;
+
+/// An overlapped (asynchronous) read or write operation on a file or pipe
+/// opened with [`FILE_FLAG::OVERLAPPED`](crate::co::FILE_FLAG::OVERLAPPED).
+///
+/// The object owns the [`OVERLAPPED`](crate::OVERLAPPED) struct, which is
+/// pinned on the heap, the data buffer and a manual-reset event, so they stay
+/// alive and in place while the system is using them. If the object is
+/// dropped before the operation completes, the operation is cancelled with
+/// [`CancelIoEx`](https://learn.microsoft.com/en-us/windows/win32/fileio/cancelioex-func)
+/// and the destructor waits until the system releases the memory.
+///
+/// # Examples
+///
+/// Writing asynchronously at a given file offset:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let (hfile, _) = w::HFILE::CreateFile(
+///     "C:\\Temp\\test.bin",
+///     co::GENERIC::WRITE,
+///     None,
+///     None,
+///     co::DISPOSITION::CREATE_ALWAYS,
+///     co::FILE_ATTRIBUTE::NORMAL,
+///     Some(co::FILE_FLAG::OVERLAPPED),
+///     None,
+///     None,
+/// )?;
+///
+/// let mut op = w::Overlapped::WriteFile(&*hfile, &[0xff; 1024], 4096)?;
+///
+/// if op.GetOverlappedResult(false)?.is_none() {
+///     println!("Still pending...");
+/// }
+///
+/// let written = op.GetOverlappedResult(true)?.unwrap();
+/// println!("{} bytes written", written);
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub struct Overlapped<'a, H>
+	where H: Handle,
+{
+	hfile: &'a H,
+	ov: Pin<Box<OVERLAPPED>>,
+	buf: Vec<u8>,
+	hevent: CloseHandleGuard<HEVENT>,
+	result: Option<SysResult<u32>>,
+}
+
+impl<'a, H> Drop for Overlapped<'a, H>
+	where H: Handle,
+{
+	fn drop(&mut self) {
+		if self.result.is_none() { // operation may still be in flight
+			let pov = self.ov_ptr();
+			let mut bytes_transferred = u32::default();
+			unsafe {
+				ffi::CancelIoEx(self.hfile.ptr(), pov); // ignore errors
+				ffi::GetOverlappedResult(self.hfile.ptr(), pov, &mut bytes_transferred, 1); // wait for the system to let go
+			}
+		}
+	}
+}
+
+impl<'a, H> Overlapped<'a, H>
+	where H: Handle,
+{
+	/// Starts an overlapped
+	/// [`ReadFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-readfile)
+	/// of up to `num_bytes`, at the given file offset.
+	///
+	/// When the operation completes, the data can be retrieved with
+	/// [`buffer`](crate::Overlapped::buffer) or
+	/// [`into_buffer`](crate::Overlapped::into_buffer).
+	#[must_use]
+	pub fn ReadFile(hfile: &'a H, num_bytes: usize, offset: u64) -> SysResult<Self> {
+		let mut obj = Self::new(hfile, vec![0; num_bytes], offset)?;
+		let ret = unsafe {
+			ffi::ReadFile(
+				hfile.ptr(),
+				obj.buf.as_mut_ptr() as _,
+				obj.buf.len() as _,
+				std::ptr::null_mut(),
+				obj.ov_ptr(),
+			)
+		};
+		obj.started(ret)
+	}
+
+	/// Starts an overlapped
+	/// [`WriteFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-writefile)
+	/// of a copy of `data`, at the given file offset.
+	#[must_use]
+	pub fn WriteFile(hfile: &'a H, data: &[u8], offset: u64) -> SysResult<Self> {
+		let mut obj = Self::new(hfile, data.to_vec(), offset)?;
+		let ret = unsafe {
+			ffi::WriteFile(
+				hfile.ptr(),
+				obj.buf.as_ptr() as _,
+				obj.buf.len() as _,
+				std::ptr::null_mut(),
+				obj.ov_ptr(),
+			)
+		};
+		obj.started(ret)
+	}
+
+	fn new(hfile: &'a H, buf: Vec<u8>, offset: u64) -> SysResult<Self> {
+		let hevent = HEVENT::CreateEvent(None, true, false, None)?;
+		let mut ov = Box::pin(OVERLAPPED::default());
+		ov.set_Offset(offset);
+		ov.hEvent = unsafe { hevent.raw_copy() };
+		Ok(Self { hfile, ov, buf, hevent, result: None })
+	}
+
+	fn started(mut self, ret: BOOL) -> SysResult<Self> {
+		match bool_to_sysresult(ret) {
+			Ok(_) | Err(co::ERROR::IO_PENDING) => Ok(self),
+			Err(e) => {
+				self.result = Some(Err(e)); // never queued, nothing to wait for
+				Err(e)
+			},
+		}
+	}
+
+	fn ov_ptr(&mut self) -> *mut std::ffi::c_void {
+		&mut *self.ov as *mut OVERLAPPED as _
+	}
+
+	/// Returns the data buffer, once the operation has successfully completed.
+	///
+	/// For a read operation, the buffer is truncated to the number of bytes
+	/// actually read.
+	#[must_use]
+	pub fn buffer(&self) -> Option<&[u8]> {
+		match self.result {
+			Some(Ok(num_bytes)) => Some(&self.buf[..num_bytes as usize]),
+			_ => None,
+		}
+	}
+
+	/// [`CancelIoEx`](https://learn.microsoft.com/en-us/windows/win32/fileio/cancelioex-func)
+	/// function.
+	///
+	/// The operation is not immediately finished: call
+	/// [`GetOverlappedResult`](crate::Overlapped::GetOverlappedResult) to
+	/// wait for it.
+	pub fn CancelIoEx(&mut self) -> SysResult<()> {
+		let pov = self.ov_ptr();
+		bool_to_sysresult(unsafe { ffi::CancelIoEx(self.hfile.ptr(), pov) })
+	}
+
+	/// Returns the manual-reset event which is signaled when the operation
+	/// completes, which can be used to wait for multiple operations at once.
+	#[must_use]
+	pub fn event(&self) -> &HEVENT {
+		&self.hevent
+	}
+
+	/// [`GetOverlappedResult`](https://learn.microsoft.com/en-us/windows/win32/api/ioapiset/nf-ioapiset-getoverlappedresult)
+	/// function.
+	///
+	/// Returns the number of bytes transferred, or `None` if `wait` is `false`
+	/// and the operation is still pending – that is,
+	/// [`ERROR::IO_INCOMPLETE`](crate::co::ERROR::IO_INCOMPLETE).
+	pub fn GetOverlappedResult(&mut self, wait: bool) -> SysResult<Option<u32>> {
+		if let Some(res) = self.result {
+			return res.map(Some); // already finished
+		}
+
+		let pov = self.ov_ptr();
+		let mut bytes_transferred = u32::default();
+		match bool_to_sysresult(
+			unsafe {
+				ffi::GetOverlappedResult(
+					self.hfile.ptr(),
+					pov,
+					&mut bytes_transferred,
+					wait as _,
+				)
+			},
+		) {
+			Err(co::ERROR::IO_INCOMPLETE) => Ok(None), // not an error, still pending
+			res => {
+				let res = res.map(|_| bytes_transferred);
+				self.result = Some(res);
+				res.map(Some)
+			},
+		}
+	}
+
+	/// Waits until the operation completes, then returns the data buffer.
+	///
+	/// For a read operation, the buffer is truncated to the number of bytes
+	/// actually read.
+	#[must_use]
+	pub fn into_buffer(mut self) -> SysResult<Vec<u8>> {
+		let num_bytes = self.GetOverlappedResult(true)?.unwrap_or_default();
+		let mut buf = std::mem::take(&mut self.buf);
+		buf.truncate(num_bytes as _);
+		Ok(buf)
+	}
+
+	/// Returns the file offset where the operation started.
+	#[must_use]
+	pub fn offset(&self) -> u64 {
+		self.ov.Offset()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::co;
+	use crate::decl::*;
+	use crate::guard::*;
+	use crate::prelude::*;
+
+	/// Returns an overlapped inbound pipe and a connected client to write to.
+	fn pipe_pair(tag: &str) -> (CloseHandleGuard<HPIPE>, CloseHandleGuard<HFILE>) {
+		let name = format!("\\\\.\\pipe\\winsafe_overlapped_{}_{}",
+			tag, GetCurrentProcessId());
+		let server = HPIPE::CreateNamedPipe(
+			&name,
+			co::PIPE_ACCESS::INBOUND | co::PIPE_ACCESS::OVERLAPPED,
+			co::PIPE::TYPE_BYTE | co::PIPE::READMODE_BYTE | co::PIPE::WAIT,
+			&NamedPipeOpts { max_instances: 1, ..Default::default() },
+			None,
+		).unwrap();
+		let (client, _) = HFILE::CreateFile(
+			&name,
+			co::GENERIC::WRITE,
+			None,
+			None,
+			co::DISPOSITION::OPEN_EXISTING,
+			co::FILE_ATTRIBUTE::NORMAL,
+			None,
+			None,
+			None,
+		).unwrap();
+		(server, client)
+	}
+
+	#[test]
+	fn completed_op_reports_count() {
+		let (server, client) = pipe_pair("completed");
+		let mut op = Overlapped::ReadFile(&*server, 16, 0).unwrap();
+		client.WriteFile(b"abc", None).unwrap();
+
+		assert_eq!(op.GetOverlappedResult(true).unwrap(), Some(3));
+		assert_eq!(op.buffer(), Some(&b"abc"[..]));
+	}
+
+	#[test]
+	fn in_flight_op_reports_pending() {
+		let (server, _client) = pipe_pair("pending");
+		let mut op = Overlapped::ReadFile(&*server, 16, 0).unwrap();
+
+		assert_eq!(op.GetOverlappedResult(false).unwrap(), None); // nothing written
+		assert_eq!(op.buffer(), None);
+	}
+
+	#[test]
+	fn drop_cancels_in_flight_op() {
+		let (server, client) = pipe_pair("drop");
+		let op = Overlapped::ReadFile(&*server, 16, 0).unwrap();
+		drop(op); // must not hang
+
+		// The cancelled read must not consume data into the freed buffer.
+		client.WriteFile(b"xyz", None).unwrap();
+		let op = Overlapped::ReadFile(&*server, 16, 0).unwrap();
+		assert_eq!(op.into_buffer().unwrap(), b"xyz");
+	}
+}
@@ -1,4 +1,6 @@
 use std::cmp::Ordering;
+use std::ffi::{OsStr, OsString};
+use std::os::windows::ffi::{OsStrExt, OsStringExt};
 
 use crate::co;
 use crate::decl::*;
@@ -23,7 +25,7 @@ pub struct WString {
 
 impl std::fmt::Display for WString {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		std::fmt::Display::fmt(&self.buf.to_string_checked().unwrap(), f)
+		std::fmt::Display::fmt(&self.buf.to_string_lossy(), f)
 	}
 }
 impl std::fmt::Debug for WString {
@@ -66,6 +68,16 @@ impl WString {
 		Self { buf: Buffer::from_opt_str(s) }
 	}
 
+	/// Stores an UTF-16 null-terminated string from an [`&OsStr`](OsStr).
+	///
+	/// Unlike [`from_str`](crate::WString::from_str), no lossy conversion
+	/// happens, so unpaired surrogates – which may appear in Windows file
+	/// names – are preserved.
+	#[must_use]
+	pub fn from_os_str(s: impl AsRef<OsStr>) -> Self {
+		Self::from_wchars_slice(&s.as_ref().encode_wide().collect::<Vec<_>>())
+	}
+
 	/// Stores an UTF-16 null-terminated string from a [`&str`](str).
 	#[must_use]
 	pub fn from_str(s: impl AsRef<str>) -> Self {
@@ -115,6 +127,22 @@ impl WString {
 		Self { buf: Buffer::new_alloc_buf(sz) }
 	}
 
+	/// Allocates an UTF-16 buffer with room for `num_chars` chars, plus the
+	/// terminating null. All elements will be set to zero.
+	///
+	/// If `num_chars` is zero, no allocation is made.
+	///
+	/// The buffer can be reused afterwards with
+	/// [`set_str`](crate::WString::set_str).
+	#[must_use]
+	pub fn with_capacity(num_chars: usize) -> Self {
+		Self {
+			buf: Buffer::new_alloc_buf(
+				if num_chars == 0 { 0 } else { num_chars + 1 }, // room for terminating null
+			),
+		}
+	}
+
 	/// Returns a mutable
 	/// [`LPWSTR`](https://learn.microsoft.com/en-us/windows/win32/learnwin32/working-with-strings)
 	/// pointer to the internal UTF-16 string buffer, to be passed to native
@@ -164,6 +192,12 @@ impl WString {
 		self.buf.buf_len()
 	}
 
+	/// Sets the content to an empty string, keeping the allocated buffer, so it
+	/// can be reused.
+	pub fn clear(&mut self) {
+		self.fill_with_zero();
+	}
+
 	/// Copies the content into an external buffer. A terminating null will be
 	/// appended.
 	///
@@ -195,13 +229,25 @@ impl WString {
 		self.buf.is_allocated()
 	}
 
+	/// Converts into [`OsString`](std::ffi::OsString), without lossy
+	/// conversion. Any unpaired surrogates are preserved.
+	///
+	/// This is the preferred way to build a [`PathBuf`](std::path::PathBuf)
+	/// from a Windows path.
+	#[must_use]
+	pub fn to_os_string(&self) -> OsString {
+		OsString::from_wide(self.buf.str_slice())
+	}
+
 	/// Converts into [`String`](std::string::String) by calling
-	/// [`String::from_utf16`](std::string::String::from_utf16). An uncallocated
-	/// will simply be converted into an empty string.
+	/// [`String::from_utf16`](std::string::String::from_utf16). An unallocated
+	/// buffer will simply be converted into an empty string.
 	///
 	/// This method is useful if you're parsing raw data which may contain
-	/// invalid characters. If you're dealing with a string known to be valid,
-	/// [`to_string`](std::string::ToString::to_string) is more practical.
+	/// invalid characters, like unpaired surrogates, and you want to detect
+	/// them. Note that [`to_string`](std::string::ToString::to_string) performs
+	/// a lossy conversion instead, replacing invalid characters with
+	/// [`U+FFFD`](std::char::REPLACEMENT_CHARACTER).
 	#[must_use]
 	pub fn to_string_checked(&self
 	) -> Result<String, std::string::FromUtf16Error>
@@ -209,6 +255,37 @@ impl WString {
 		self.buf.to_string_checked()
 	}
 
+	/// Replaces the content with the given [`&str`](str).
+	///
+	/// If the current buffer is large enough to hold the new string plus its
+	/// terminating null, it's reused and no allocation is made; otherwise a new
+	/// buffer is allocated.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let mut wstr = w::WString::with_capacity(260);
+	/// for name in ["foo.txt", "bar.txt"] {
+	///     wstr.set_str(name); // buffer is reused
+	/// }
+	/// ```
+	pub fn set_str(&mut self, s: impl AsRef<str>) {
+		let s_len = s.as_ref().encode_utf16().count();
+		if s_len < self.buf_len() { // room for terminating null
+			let dest = self.as_mut_slice();
+			s.as_ref()
+				.encode_utf16()
+				.zip(dest.iter_mut())
+				.for_each(|(src, dest)| *dest = src);
+			dest[s_len..].iter_mut()
+				.for_each(|dest| *dest = 0x0000); // fill the rest with zero
+		} else {
+			self.buf = Buffer::from_str(s);
+		}
+	}
+
 	/// Wrapper to
 	/// [`lstrlen`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-lstrlenw).
 	///
@@ -343,10 +420,10 @@ impl std::fmt::Debug for Buffer {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		write!(f, "{}", match self {
 			Self::Stack(arr) =>
-				format!("STACK({}) \"{}\"", arr.len(), self.to_string_checked().unwrap()),
+				format!("STACK({}) \"{}\"", arr.len(), self.to_string_lossy()),
 			Self::Heap(block) =>
 				format!("HEAP({}) \"{}\"",
-					block.len() / std::mem::size_of::<u16>(), self.to_string_checked().unwrap()),
+					block.len() / std::mem::size_of::<u16>(), self.to_string_lossy()),
 			Self::Unallocated =>
 				"UNALLOCATED \"\"".to_owned(),
 		})
@@ -485,16 +562,19 @@ impl Buffer {
 		}
 	}
 
+	fn str_slice(&self) -> &[u16] {
+		let slice = self.as_slice();
+		let len = slice.iter()
+			.position(|ch| *ch == 0x0000) // remove all trailing zeros
+			.unwrap_or(slice.len());
+		&slice[..len]
+	}
+
 	fn to_string_checked(&self) -> Result<String, std::string::FromUtf16Error> {
-		match self {
-			Self::Unallocated => Ok(String::default()),
-			_ => String::from_utf16(
-				&self.as_slice()
-					.into_iter()
-					.take_while(|ch| **ch != 0x0000) // remove all trailing zeros
-					.map(|ch| *ch)
-					.collect::<Vec<_>>(),
-			),
-		}
+		String::from_utf16(self.str_slice())
+	}
+
+	fn to_string_lossy(&self) -> String {
+		String::from_utf16_lossy(self.str_slice())
 	}
 }
@@ -15,6 +15,7 @@
 #[cfg(feature = "gdi")] mod gdi;
 #[cfg(feature = "kernel")] mod kernel;
 #[cfg(feature = "mf")] mod mf;
+#[cfg(feature = "mmdevice")] mod mmdevice;
 #[cfg(feature = "ole")] mod ole;
 #[cfg(feature = "oleaut")] mod oleaut;
 #[cfg(feature = "shell")] mod shell;
@@ -25,6 +26,8 @@
 #[cfg(all(feature = "comctl", feature = "gdi"))] mod comctl_gdi;
 #[cfg(all(feature = "comctl", feature = "shell"))] mod comctl_shell;
 #[cfg(all(feature = "gdi", feature = "mf"))] mod gdi_mf;
+#[cfg(all(feature = "gdi", feature = "ole"))] mod gdi_ole;
+#[cfg(all(feature = "gdi", feature = "shell"))] mod gdi_shell;
 
 // The gui module itself is public.
 
@@ -40,6 +43,7 @@ mod decl {
 	#[cfg(feature = "gdi")] pub use super::gdi::decl::*;
 	#[cfg(feature = "kernel")] pub use super::kernel::decl::*;
 	#[cfg(feature = "mf")] pub use super::mf::decl::*;
+	#[cfg(feature = "mmdevice")] pub use super::mmdevice::decl::*;
 	#[cfg(feature = "ole")] pub use super::ole::decl::*;
 	#[cfg(feature = "oleaut")] pub use super::oleaut::decl::*;
 	#[cfg(feature = "shell")] pub use super::shell::decl::*;
@@ -70,6 +74,7 @@ pub mod co {
 	#[cfg(feature = "gdi")] pub use super::gdi::co::*;
 	#[cfg(feature = "kernel")] pub use super::kernel::co::*;
 	#[cfg(feature = "mf")] pub use super::mf::co::*;
+	#[cfg(feature = "mmdevice")] pub use super::mmdevice::co::*;
 	#[cfg(feature = "ole")] pub use super::ole::co::*;
 	#[cfg(feature = "oleaut")] pub use super::oleaut::co::*;
 	#[cfg(feature = "shell")] pub use super::shell::co::*;
@@ -318,6 +323,7 @@ pub mod prelude {
 	#[cfg(feature = "gui")] pub use super::gui::traits::*;
 	#[cfg(feature = "kernel")] pub use super::kernel::traits::*;
 	#[cfg(feature = "mf")] pub use super::mf::traits::*;
+	#[cfg(feature = "mmdevice")] pub use super::mmdevice::traits::*;
 	#[cfg(feature = "ole")] pub use super::ole::traits::*;
 	#[cfg(feature = "oleaut")] pub use super::oleaut::traits::*;
 	#[cfg(feature = "shell")] pub use super::shell::traits::*;
@@ -326,6 +332,8 @@ pub mod prelude {
 	#[cfg(feature = "uxtheme")] pub use super::uxtheme::traits::*;
 	#[cfg(all(feature = "comctl", feature = "shell"))] pub use super::comctl_shell::traits::*;
 	#[cfg(all(feature = "gdi", feature = "mf"))] pub use super::gdi_mf::traits::*;
+	#[cfg(all(feature = "gdi", feature = "ole"))] pub use super::gdi_ole::traits::*;
+	#[cfg(all(feature = "gdi", feature = "shell"))] pub use super::gdi_shell::traits::*;
 }
 
 #[cfg(feature = "ole")]
@@ -335,6 +343,7 @@ pub mod vt {
 	#[cfg(feature = "dshow")] pub use super::dshow::vt::*;
 	#[cfg(feature = "dxgi")] pub use super::dxgi::vt::*;
 	#[cfg(feature = "mf")] pub use super::mf::vt::*;
+	#[cfg(feature = "mmdevice")] pub use super::mmdevice::vt::*;
 	#[cfg(feature = "ole")] pub use super::ole::vt::*;
 	#[cfg(feature = "oleaut")] pub use super::oleaut::vt::*;
 	#[cfg(feature = "shell")] pub use super::shell::vt::*;
@@ -0,0 +1,25 @@
+/// Declares a [`GUID`](crate::GUID) from a string literal, which is parsed at
+/// compile time. A malformed string fails the build.
+///
+/// The string may be enclosed in curly braces, so it can be copied straight
+/// from standard `GUID` declarations.
+///
+/// This macro requires the `kernel` feature.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, guid};
+///
+/// const MY_IID: w::GUID = guid!("{43826d1e-e718-42ee-bc55-a1e261c37bfe}");
+/// let null_guid = guid!("00000000-0000-0000-0000-000000000000");
+/// ```
+#[macro_export]
+macro_rules! guid {
+	($guid_str:literal) => {
+		{
+			const GUID_VAL: $crate::GUID = $crate::GUID::new($guid_str);
+			GUID_VAL
+		}
+	};
+}
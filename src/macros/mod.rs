@@ -6,7 +6,9 @@
 #[macro_use] mod consts;
 #[macro_use] mod ffis;
 #[macro_use] mod gui_events;
+#[macro_use] mod guid;
 #[macro_use] mod handles;
 #[macro_use] mod messages;
+#[macro_use] mod odbg;
 #[macro_use] mod seq_ids;
 #[macro_use] mod structs;
@@ -0,0 +1,23 @@
+/// Formats a string like [`println!`](std::println), and sends it to the
+/// debugger with [`OutputDebugString`](crate::OutputDebugString). A line break
+/// is appended to the text.
+///
+/// The output can be seen in the debugger output window, or in tools like
+/// [DebugView](https://learn.microsoft.com/en-us/sysinternals/downloads/debugview).
+///
+/// This macro requires the `kernel` feature.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::odbg;
+///
+/// let count = 42;
+/// odbg!("Processed {} items.", count);
+/// ```
+#[macro_export]
+macro_rules! odbg {
+	($($arg:tt)*) => {
+		$crate::OutputDebugString(&::std::format!("{}\n", ::std::format_args!($($arg)*)))
+	};
+}
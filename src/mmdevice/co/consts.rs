@@ -0,0 +1,33 @@
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+const_bitflag! { DEVICE_STATE: u32;
+	/// [`DEVICE_STATE_XXX`](https://learn.microsoft.com/en-us/windows/win32/coreaudio/device-state-xxx-constants)
+	/// constants (`u32`).
+	=>
+	=>
+	ACTIVE 0x0000_0001
+	DISABLED 0x0000_0002
+	NOTPRESENT 0x0000_0004
+	UNPLUGGED 0x0000_0008
+	MASK_ALL 0x0000_000f
+}
+
+const_ordinary! { EDataFlow: u32;
+	/// [`EDataFlow`](https://learn.microsoft.com/en-us/windows/win32/api/mmdeviceapi/ne-mmdeviceapi-edataflow)
+	/// enumeration (`u32`).
+	=>
+	=>
+	Render 0
+	Capture 1
+	All 2
+}
+
+const_ordinary! { ERole: u32;
+	/// [`ERole`](https://learn.microsoft.com/en-us/windows/win32/api/mmdeviceapi/ne-mmdeviceapi-erole)
+	/// enumeration (`u32`).
+	=>
+	=>
+	Console 0
+	Multimedia 1
+	Communications 2
+}
@@ -0,0 +1,7 @@
+#![allow(non_upper_case_globals)]
+
+use crate::co::*;
+
+const_guid_values! { CLSID;
+	MMDeviceEnumerator "bcde0395-e52f-467c-8e3d-c4579291692e"
+}
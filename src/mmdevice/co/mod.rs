@@ -0,0 +1,4 @@
+mod consts;
+mod guids;
+
+pub use consts::*;
@@ -0,0 +1,302 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::decl::*;
+use crate::kernel::ffi_types::*;
+use crate::ole::privs::*;
+use crate::prelude::*;
+use crate::vt::*;
+
+/// [`IAudioEndpointVolume`](crate::IAudioEndpointVolume) virtual table.
+#[repr(C)]
+pub struct IAudioEndpointVolumeVT {
+	pub IUnknownVT: IUnknownVT,
+	pub RegisterControlChangeNotify: fn(COMPTR, COMPTR) -> HRES,
+	pub UnregisterControlChangeNotify: fn(COMPTR, COMPTR) -> HRES,
+	pub GetChannelCount: fn(COMPTR, *mut u32) -> HRES,
+	pub SetMasterVolumeLevel: fn(COMPTR, f32, PCVOID) -> HRES,
+	pub SetMasterVolumeLevelScalar: fn(COMPTR, f32, PCVOID) -> HRES,
+	pub GetMasterVolumeLevel: fn(COMPTR, *mut f32) -> HRES,
+	pub GetMasterVolumeLevelScalar: fn(COMPTR, *mut f32) -> HRES,
+	pub SetChannelVolumeLevel: fn(COMPTR, u32, f32, PCVOID) -> HRES,
+	pub SetChannelVolumeLevelScalar: fn(COMPTR, u32, f32, PCVOID) -> HRES,
+	pub GetChannelVolumeLevel: fn(COMPTR, u32, *mut f32) -> HRES,
+	pub GetChannelVolumeLevelScalar: fn(COMPTR, u32, *mut f32) -> HRES,
+	pub SetMute: fn(COMPTR, BOOL, PCVOID) -> HRES,
+	pub GetMute: fn(COMPTR, *mut BOOL) -> HRES,
+	pub GetVolumeStepInfo: fn(COMPTR, *mut u32, *mut u32) -> HRES,
+	pub VolumeStepUp: fn(COMPTR, PCVOID) -> HRES,
+	pub VolumeStepDown: fn(COMPTR, PCVOID) -> HRES,
+	pub QueryHardwareSupport: fn(COMPTR, *mut u32) -> HRES,
+	pub GetVolumeRange: fn(COMPTR, *mut f32, *mut f32, *mut f32) -> HRES,
+}
+
+com_interface! { IAudioEndpointVolume: "5cdf2c82-841e-4546-9722-0cf74078229a";
+	/// [`IAudioEndpointVolume`](https://learn.microsoft.com/en-us/windows/win32/api/endpointvolume/nn-endpointvolume-iaudioendpointvolume)
+	/// COM interface over
+	/// [`IAudioEndpointVolumeVT`](crate::vt::IAudioEndpointVolumeVT).
+	///
+	/// Automatically calls
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// Retrieving the endpoint volume of the default audio output device, with
+	/// [`default_audio_endpoint`](crate::default_audio_endpoint):
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let endpoint_vol = w::default_audio_endpoint()?;
+	///
+	/// let level = endpoint_vol.GetMasterVolumeLevelScalar()?;
+	/// assert!((0.0..=1.0).contains(&level));
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+}
+
+impl mmdevice_IAudioEndpointVolume for IAudioEndpointVolume {}
+
+/// This trait is enabled with the `mmdevice` feature, and provides methods for
+/// [`IAudioEndpointVolume`](crate::IAudioEndpointVolume).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait mmdevice_IAudioEndpointVolume: ole_IUnknown {
+	/// [`IAudioEndpointVolume::GetChannelCount`](https://learn.microsoft.com/en-us/windows/win32/api/endpointvolume/nf-endpointvolume-iaudioendpointvolume-getchannelcount)
+	/// method.
+	#[must_use]
+	fn GetChannelCount(&self) -> HrResult<u32> {
+		let mut count = u32::default();
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IAudioEndpointVolumeVT>(self).GetChannelCount)(
+					self.ptr(),
+					&mut count,
+				)
+			},
+		).map(|_| count)
+	}
+
+	/// [`IAudioEndpointVolume::GetChannelVolumeLevelScalar`](https://learn.microsoft.com/en-us/windows/win32/api/endpointvolume/nf-endpointvolume-iaudioendpointvolume-getchannelvolumelevelscalar)
+	/// method.
+	#[must_use]
+	fn GetChannelVolumeLevelScalar(&self, channel: u32) -> HrResult<f32> {
+		let mut level = f32::default();
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IAudioEndpointVolumeVT>(self).GetChannelVolumeLevelScalar)(
+					self.ptr(),
+					channel,
+					&mut level,
+				)
+			},
+		).map(|_| level)
+	}
+
+	/// [`IAudioEndpointVolume::GetMasterVolumeLevel`](https://learn.microsoft.com/en-us/windows/win32/api/endpointvolume/nf-endpointvolume-iaudioendpointvolume-getmastervolumelevel)
+	/// method.
+	///
+	/// Returns the volume level in decibels.
+	#[must_use]
+	fn GetMasterVolumeLevel(&self) -> HrResult<f32> {
+		let mut level_db = f32::default();
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IAudioEndpointVolumeVT>(self).GetMasterVolumeLevel)(
+					self.ptr(),
+					&mut level_db,
+				)
+			},
+		).map(|_| level_db)
+	}
+
+	/// [`IAudioEndpointVolume::GetMasterVolumeLevelScalar`](https://learn.microsoft.com/en-us/windows/win32/api/endpointvolume/nf-endpointvolume-iaudioendpointvolume-getmastervolumelevelscalar)
+	/// method.
+	///
+	/// Returns the volume level normalized to the range `0.0` to `1.0`.
+	#[must_use]
+	fn GetMasterVolumeLevelScalar(&self) -> HrResult<f32> {
+		let mut level = f32::default();
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IAudioEndpointVolumeVT>(self).GetMasterVolumeLevelScalar)(
+					self.ptr(),
+					&mut level,
+				)
+			},
+		).map(|_| level)
+	}
+
+	/// [`IAudioEndpointVolume::GetMute`](https://learn.microsoft.com/en-us/windows/win32/api/endpointvolume/nf-endpointvolume-iaudioendpointvolume-getmute)
+	/// method.
+	#[must_use]
+	fn GetMute(&self) -> HrResult<bool> {
+		let mut mute: BOOL = 0;
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IAudioEndpointVolumeVT>(self).GetMute)(
+					self.ptr(),
+					&mut mute,
+				)
+			},
+		).map(|_| mute != 0)
+	}
+
+	/// [`IAudioEndpointVolume::GetVolumeRange`](https://learn.microsoft.com/en-us/windows/win32/api/endpointvolume/nf-endpointvolume-iaudioendpointvolume-getvolumerange)
+	/// method.
+	///
+	/// Returns the minimum, maximum and increment values, in decibels.
+	#[must_use]
+	fn GetVolumeRange(&self) -> HrResult<(f32, f32, f32)> {
+		let (mut min_db, mut max_db, mut incr_db) = (0.0f32, 0.0f32, 0.0f32);
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IAudioEndpointVolumeVT>(self).GetVolumeRange)(
+					self.ptr(),
+					&mut min_db,
+					&mut max_db,
+					&mut incr_db,
+				)
+			},
+		).map(|_| (min_db, max_db, incr_db))
+	}
+
+	/// [`IAudioEndpointVolume::GetVolumeStepInfo`](https://learn.microsoft.com/en-us/windows/win32/api/endpointvolume/nf-endpointvolume-iaudioendpointvolume-getvolumestepinfo)
+	/// method.
+	///
+	/// Returns the current step and the number of steps.
+	#[must_use]
+	fn GetVolumeStepInfo(&self) -> HrResult<(u32, u32)> {
+		let (mut step, mut step_count) = (u32::default(), u32::default());
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IAudioEndpointVolumeVT>(self).GetVolumeStepInfo)(
+					self.ptr(),
+					&mut step,
+					&mut step_count,
+				)
+			},
+		).map(|_| (step, step_count))
+	}
+
+	/// [`IAudioEndpointVolume::SetChannelVolumeLevelScalar`](https://learn.microsoft.com/en-us/windows/win32/api/endpointvolume/nf-endpointvolume-iaudioendpointvolume-setchannelvolumelevelscalar)
+	/// method.
+	fn SetChannelVolumeLevelScalar(&self,
+		channel: u32,
+		level: f32,
+		event_context: Option<&GUID>,
+	) -> HrResult<()>
+	{
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IAudioEndpointVolumeVT>(self).SetChannelVolumeLevelScalar)(
+					self.ptr(),
+					channel,
+					level,
+					event_context.map_or(std::ptr::null(), |g| g as *const _ as _),
+				)
+			},
+		)
+	}
+
+	/// [`IAudioEndpointVolume::SetMasterVolumeLevel`](https://learn.microsoft.com/en-us/windows/win32/api/endpointvolume/nf-endpointvolume-iaudioendpointvolume-setmastervolumelevel)
+	/// method.
+	///
+	/// The level is given in decibels, within the range returned by
+	/// [`GetVolumeRange`](crate::prelude::mmdevice_IAudioEndpointVolume::GetVolumeRange).
+	fn SetMasterVolumeLevel(&self,
+		level_db: f32,
+		event_context: Option<&GUID>,
+	) -> HrResult<()>
+	{
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IAudioEndpointVolumeVT>(self).SetMasterVolumeLevel)(
+					self.ptr(),
+					level_db,
+					event_context.map_or(std::ptr::null(), |g| g as *const _ as _),
+				)
+			},
+		)
+	}
+
+	/// [`IAudioEndpointVolume::SetMasterVolumeLevelScalar`](https://learn.microsoft.com/en-us/windows/win32/api/endpointvolume/nf-endpointvolume-iaudioendpointvolume-setmastervolumelevelscalar)
+	/// method.
+	///
+	/// The level must be within the range `0.0` to `1.0`.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let endpoint_vol: w::IAudioEndpointVolume; // initialized somewhere
+	/// # let endpoint_vol = unsafe { w::IAudioEndpointVolume::null() };
+	///
+	/// endpoint_vol.SetMasterVolumeLevelScalar(0.5, None)?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	fn SetMasterVolumeLevelScalar(&self,
+		level: f32,
+		event_context: Option<&GUID>,
+	) -> HrResult<()>
+	{
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IAudioEndpointVolumeVT>(self).SetMasterVolumeLevelScalar)(
+					self.ptr(),
+					level,
+					event_context.map_or(std::ptr::null(), |g| g as *const _ as _),
+				)
+			},
+		)
+	}
+
+	/// [`IAudioEndpointVolume::SetMute`](https://learn.microsoft.com/en-us/windows/win32/api/endpointvolume/nf-endpointvolume-iaudioendpointvolume-setmute)
+	/// method.
+	fn SetMute(&self,
+		mute: bool,
+		event_context: Option<&GUID>,
+	) -> HrResult<()>
+	{
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IAudioEndpointVolumeVT>(self).SetMute)(
+					self.ptr(),
+					mute as _,
+					event_context.map_or(std::ptr::null(), |g| g as *const _ as _),
+				)
+			},
+		)
+	}
+
+	/// [`IAudioEndpointVolume::VolumeStepDown`](https://learn.microsoft.com/en-us/windows/win32/api/endpointvolume/nf-endpointvolume-iaudioendpointvolume-volumestepdown)
+	/// method.
+	fn VolumeStepDown(&self, event_context: Option<&GUID>) -> HrResult<()> {
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IAudioEndpointVolumeVT>(self).VolumeStepDown)(
+					self.ptr(),
+					event_context.map_or(std::ptr::null(), |g| g as *const _ as _),
+				)
+			},
+		)
+	}
+
+	/// [`IAudioEndpointVolume::VolumeStepUp`](https://learn.microsoft.com/en-us/windows/win32/api/endpointvolume/nf-endpointvolume-iaudioendpointvolume-volumestepup)
+	/// method.
+	fn VolumeStepUp(&self, event_context: Option<&GUID>) -> HrResult<()> {
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IAudioEndpointVolumeVT>(self).VolumeStepUp)(
+					self.ptr(),
+					event_context.map_or(std::ptr::null(), |g| g as *const _ as _),
+				)
+			},
+		)
+	}
+}
@@ -0,0 +1,120 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::ffi_types::*;
+use crate::ole::privs::*;
+use crate::prelude::*;
+use crate::vt::*;
+
+/// [`IMMDevice`](crate::IMMDevice) virtual table.
+#[repr(C)]
+pub struct IMMDeviceVT {
+	pub IUnknownVT: IUnknownVT,
+	pub Activate: fn(COMPTR, PCVOID, u32, PVOID, *mut COMPTR) -> HRES,
+	pub OpenPropertyStore: fn(COMPTR, u32, *mut COMPTR) -> HRES,
+	pub GetId: fn(COMPTR, *mut PSTR) -> HRES,
+	pub GetState: fn(COMPTR, *mut u32) -> HRES,
+}
+
+com_interface! { IMMDevice: "d666063f-1587-4e43-81f1-b948e807363f";
+	/// [`IMMDevice`](https://learn.microsoft.com/en-us/windows/win32/api/mmdeviceapi/nn-mmdeviceapi-immdevice)
+	/// COM interface over [`IMMDeviceVT`](crate::vt::IMMDeviceVT).
+	///
+	/// Automatically calls
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl mmdevice_IMMDevice for IMMDevice {}
+
+/// This trait is enabled with the `mmdevice` feature, and provides methods for
+/// [`IMMDevice`](crate::IMMDevice).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait mmdevice_IMMDevice: ole_IUnknown {
+	/// [`IMMDevice::Activate`](https://learn.microsoft.com/en-us/windows/win32/api/mmdeviceapi/nf-mmdeviceapi-immdevice-activate)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let device: w::IMMDevice; // initialized somewhere
+	/// # let device = unsafe { w::IMMDevice::null() };
+	///
+	/// let endpoint_vol = device
+	///     .Activate::<w::IAudioEndpointVolume>(co::CLSCTX::INPROC_SERVER)?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn Activate<T>(&self, cls_context: co::CLSCTX) -> HrResult<T>
+		where T: ole_IUnknown,
+	{
+		let mut queried = unsafe { T::null() };
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IMMDeviceVT>(self).Activate)(
+					self.ptr(),
+					&T::IID as *const _ as _,
+					cls_context.raw(),
+					std::ptr::null_mut(),
+					queried.as_mut(),
+				)
+			},
+		).map(|_| queried)
+	}
+
+	/// [`IMMDevice::GetId`](https://learn.microsoft.com/en-us/windows/win32/api/mmdeviceapi/nf-mmdeviceapi-immdevice-getid)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// Retrieving the ID of the default audio output device:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let enumerator = w::CoCreateInstance::<w::IMMDeviceEnumerator>(
+	///     &co::CLSID::MMDeviceEnumerator,
+	///     None,
+	///     co::CLSCTX::INPROC_SERVER,
+	/// )?;
+	///
+	/// let device = enumerator.GetDefaultAudioEndpoint(
+	///     co::EDataFlow::Render,
+	///     co::ERole::Console,
+	/// )?;
+	/// println!("Default device: {}", device.GetId()?);
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn GetId(&self) -> HrResult<String> {
+		let mut pstr = std::ptr::null_mut::<u16>();
+		ok_to_hrresult(
+			unsafe { (vt::<IMMDeviceVT>(self).GetId)(self.ptr(), &mut pstr) },
+		).map(|_| {
+			let id = WString::from_wchars_nullt(pstr);
+			let _ = unsafe { CoTaskMemFreeGuard::new(pstr as _, 0) };
+			id.to_string()
+		})
+	}
+
+	/// [`IMMDevice::GetState`](https://learn.microsoft.com/en-us/windows/win32/api/mmdeviceapi/nf-mmdeviceapi-immdevice-getstate)
+	/// method.
+	#[must_use]
+	fn GetState(&self) -> HrResult<co::DEVICE_STATE> {
+		let mut state = co::DEVICE_STATE::default();
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IMMDeviceVT>(self).GetState)(self.ptr(), state.as_mut())
+			},
+		).map(|_| state)
+	}
+}
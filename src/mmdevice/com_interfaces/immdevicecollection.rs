@@ -0,0 +1,101 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::decl::*;
+use crate::kernel::ffi_types::*;
+use crate::mmdevice::iterators::*;
+use crate::ole::privs::*;
+use crate::prelude::*;
+use crate::vt::*;
+
+/// [`IMMDeviceCollection`](crate::IMMDeviceCollection) virtual table.
+#[repr(C)]
+pub struct IMMDeviceCollectionVT {
+	pub IUnknownVT: IUnknownVT,
+	pub GetCount: fn(COMPTR, *mut u32) -> HRES,
+	pub Item: fn(COMPTR, u32, *mut COMPTR) -> HRES,
+}
+
+com_interface! { IMMDeviceCollection: "0bd7a1be-7a1a-44db-8397-cc5392387b5e";
+	/// [`IMMDeviceCollection`](https://learn.microsoft.com/en-us/windows/win32/api/mmdeviceapi/nn-mmdeviceapi-immdevicecollection)
+	/// COM interface over
+	/// [`IMMDeviceCollectionVT`](crate::vt::IMMDeviceCollectionVT).
+	///
+	/// Automatically calls
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl mmdevice_IMMDeviceCollection for IMMDeviceCollection {}
+
+/// This trait is enabled with the `mmdevice` feature, and provides methods for
+/// [`IMMDeviceCollection`](crate::IMMDeviceCollection).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait mmdevice_IMMDeviceCollection: ole_IUnknown {
+	/// Returns an iterator over the [`IMMDevice`](crate::IMMDevice) elements
+	/// by calling
+	/// [`IMMDeviceCollection::GetCount`](crate::prelude::mmdevice_IMMDeviceCollection::GetCount)
+	/// and
+	/// [`IMMDeviceCollection::Item`](crate::prelude::mmdevice_IMMDeviceCollection::Item)
+	/// consecutively.
+	///
+	/// # Examples
+	///
+	/// Listing the IDs of the active render endpoints:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let enumerator: w::IMMDeviceEnumerator; // initialized somewhere
+	/// # let enumerator = unsafe { w::IMMDeviceEnumerator::null() };
+	///
+	/// let devices = enumerator.EnumAudioEndpoints(
+	///     co::EDataFlow::Render,
+	///     co::DEVICE_STATE::ACTIVE,
+	/// )?;
+	///
+	/// for device in devices.iter()? {
+	///     let device = device?;
+	///     println!("{}", device.GetId()?);
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn iter(&self,
+	) -> HrResult<Box<dyn Iterator<Item = HrResult<IMMDevice>> + '_>>
+	{
+		Ok(Box::new(ImmdevicecollectionIter::new(self)?))
+	}
+
+	/// [`IMMDeviceCollection::GetCount`](https://learn.microsoft.com/en-us/windows/win32/api/mmdeviceapi/nf-mmdeviceapi-immdevicecollection-getcount)
+	/// method.
+	#[must_use]
+	fn GetCount(&self) -> HrResult<u32> {
+		let mut count = u32::default();
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IMMDeviceCollectionVT>(self).GetCount)(self.ptr(), &mut count)
+			},
+		).map(|_| count)
+	}
+
+	/// [`IMMDeviceCollection::Item`](https://learn.microsoft.com/en-us/windows/win32/api/mmdeviceapi/nf-mmdeviceapi-immdevicecollection-item)
+	/// method.
+	#[must_use]
+	fn Item(&self, index: u32) -> HrResult<IMMDevice> {
+		let mut queried = unsafe { IMMDevice::null() };
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IMMDeviceCollectionVT>(self).Item)(
+					self.ptr(),
+					index,
+					queried.as_mut(),
+				)
+			},
+		).map(|_| queried)
+	}
+}
@@ -0,0 +1,128 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::kernel::ffi_types::*;
+use crate::ole::privs::*;
+use crate::prelude::*;
+use crate::vt::*;
+
+/// [`IMMDeviceEnumerator`](crate::IMMDeviceEnumerator) virtual table.
+#[repr(C)]
+pub struct IMMDeviceEnumeratorVT {
+	pub IUnknownVT: IUnknownVT,
+	pub EnumAudioEndpoints: fn(COMPTR, u32, u32, *mut COMPTR) -> HRES,
+	pub GetDefaultAudioEndpoint: fn(COMPTR, u32, u32, *mut COMPTR) -> HRES,
+	pub GetDevice: fn(COMPTR, PCSTR, *mut COMPTR) -> HRES,
+	pub RegisterEndpointNotificationCallback: fn(COMPTR, COMPTR) -> HRES,
+	pub UnregisterEndpointNotificationCallback: fn(COMPTR, COMPTR) -> HRES,
+}
+
+com_interface! { IMMDeviceEnumerator: "a95664d2-9614-4f35-a746-de8db63617e6";
+	/// [`IMMDeviceEnumerator`](https://learn.microsoft.com/en-us/windows/win32/api/mmdeviceapi/nn-mmdeviceapi-immdeviceenumerator)
+	/// COM interface over
+	/// [`IMMDeviceEnumeratorVT`](crate::vt::IMMDeviceEnumeratorVT).
+	///
+	/// Automatically calls
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let obj = w::CoCreateInstance::<w::IMMDeviceEnumerator>(
+	///     &co::CLSID::MMDeviceEnumerator,
+	///     None,
+	///     co::CLSCTX::INPROC_SERVER,
+	/// )?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+}
+
+impl mmdevice_IMMDeviceEnumerator for IMMDeviceEnumerator {}
+
+/// This trait is enabled with the `mmdevice` feature, and provides methods for
+/// [`IMMDeviceEnumerator`](crate::IMMDeviceEnumerator).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait mmdevice_IMMDeviceEnumerator: ole_IUnknown {
+	/// [`IMMDeviceEnumerator::EnumAudioEndpoints`](https://learn.microsoft.com/en-us/windows/win32/api/mmdeviceapi/nf-mmdeviceapi-immdeviceenumerator-enumaudioendpoints)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let enumerator: w::IMMDeviceEnumerator; // initialized somewhere
+	/// # let enumerator = unsafe { w::IMMDeviceEnumerator::null() };
+	///
+	/// let active_outputs = enumerator.EnumAudioEndpoints(
+	///     co::EDataFlow::Render,
+	///     co::DEVICE_STATE::ACTIVE,
+	/// )?;
+	/// println!("{} active outputs", active_outputs.GetCount()?);
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn EnumAudioEndpoints(&self,
+		flow: co::EDataFlow,
+		state_mask: co::DEVICE_STATE,
+	) -> HrResult<IMMDeviceCollection>
+	{
+		let mut queried = unsafe { IMMDeviceCollection::null() };
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IMMDeviceEnumeratorVT>(self).EnumAudioEndpoints)(
+					self.ptr(),
+					flow.raw(),
+					state_mask.raw(),
+					queried.as_mut(),
+				)
+			},
+		).map(|_| queried)
+	}
+
+	/// [`IMMDeviceEnumerator::GetDefaultAudioEndpoint`](https://learn.microsoft.com/en-us/windows/win32/api/mmdeviceapi/nf-mmdeviceapi-immdeviceenumerator-getdefaultaudioendpoint)
+	/// method.
+	#[must_use]
+	fn GetDefaultAudioEndpoint(&self,
+		flow: co::EDataFlow,
+		role: co::ERole,
+	) -> HrResult<IMMDevice>
+	{
+		let mut queried = unsafe { IMMDevice::null() };
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IMMDeviceEnumeratorVT>(self).GetDefaultAudioEndpoint)(
+					self.ptr(),
+					flow.raw(),
+					role.raw(),
+					queried.as_mut(),
+				)
+			},
+		).map(|_| queried)
+	}
+
+	/// [`IMMDeviceEnumerator::GetDevice`](https://learn.microsoft.com/en-us/windows/win32/api/mmdeviceapi/nf-mmdeviceapi-immdeviceenumerator-getdevice)
+	/// method.
+	#[must_use]
+	fn GetDevice(&self, id: &str) -> HrResult<IMMDevice> {
+		let mut queried = unsafe { IMMDevice::null() };
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IMMDeviceEnumeratorVT>(self).GetDevice)(
+					self.ptr(),
+					WString::from_str(id).as_ptr(),
+					queried.as_mut(),
+				)
+			},
+		).map(|_| queried)
+	}
+}
@@ -0,0 +1,25 @@
+mod iaudioendpointvolume;
+mod immdevice;
+mod immdevicecollection;
+mod immdeviceenumerator;
+
+pub mod decl {
+	pub use super::iaudioendpointvolume::IAudioEndpointVolume;
+	pub use super::immdevice::IMMDevice;
+	pub use super::immdevicecollection::IMMDeviceCollection;
+	pub use super::immdeviceenumerator::IMMDeviceEnumerator;
+}
+
+pub mod traits {
+	pub use super::iaudioendpointvolume::mmdevice_IAudioEndpointVolume;
+	pub use super::immdevice::mmdevice_IMMDevice;
+	pub use super::immdevicecollection::mmdevice_IMMDeviceCollection;
+	pub use super::immdeviceenumerator::mmdevice_IMMDeviceEnumerator;
+}
+
+pub mod vt {
+	pub use super::iaudioendpointvolume::IAudioEndpointVolumeVT;
+	pub use super::immdevice::IMMDeviceVT;
+	pub use super::immdevicecollection::IMMDeviceCollectionVT;
+	pub use super::immdeviceenumerator::IMMDeviceEnumeratorVT;
+}
@@ -0,0 +1,44 @@
+#![allow(non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::prelude::*;
+
+/// Retrieves the [`IAudioEndpointVolume`](crate::IAudioEndpointVolume) of the
+/// default audio rendering device.
+///
+/// High-level helper which creates an
+/// [`IMMDeviceEnumerator`](crate::IMMDeviceEnumerator), retrieves the default
+/// console render endpoint with
+/// [`GetDefaultAudioEndpoint`](crate::prelude::mmdevice_IMMDeviceEnumerator::GetDefaultAudioEndpoint),
+/// then calls
+/// [`Activate`](crate::prelude::mmdevice_IMMDevice::Activate) on it.
+///
+/// COM must have been initialized with
+/// [`CoInitializeEx`](crate::CoInitializeEx).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let _com_lib = w::CoInitializeEx( // keep guard alive
+///     co::COINIT::APARTMENTTHREADED
+///     | co::COINIT::DISABLE_OLE1DDE,
+/// )?;
+///
+/// let endpoint_vol = w::default_audio_endpoint()?;
+/// endpoint_vol.SetMute(true, None)?;
+/// # Ok::<_, co::HRESULT>(())
+/// ```
+#[must_use]
+pub fn default_audio_endpoint() -> HrResult<IAudioEndpointVolume> {
+	let enumerator = CoCreateInstance::<IMMDeviceEnumerator>(
+		&co::CLSID::MMDeviceEnumerator,
+		None,
+		co::CLSCTX::INPROC_SERVER,
+	)?;
+	let device = enumerator.GetDefaultAudioEndpoint(
+		co::EDataFlow::Render, co::ERole::Console)?;
+	device.Activate::<IAudioEndpointVolume>(co::CLSCTX::INPROC_SERVER)
+}
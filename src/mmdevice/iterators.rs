@@ -0,0 +1,42 @@
+use crate::decl::*;
+use crate::prelude::*;
+
+pub(in crate::mmdevice) struct ImmdevicecollectionIter<'a, I>
+	where I: mmdevice_IMMDeviceCollection,
+{
+	collection: &'a I,
+	count: u32,
+	current: u32,
+}
+
+impl<'a, I> Iterator for ImmdevicecollectionIter<'a, I>
+	where I: mmdevice_IMMDeviceCollection,
+{
+	type Item = HrResult<IMMDevice>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.current == self.count {
+			return None;
+		}
+
+		match self.collection.Item(self.current) {
+			Err(e) => {
+				self.current = self.count; // no further iterations will be made
+				Some(Err(e))
+			},
+			Ok(device) => {
+				self.current += 1;
+				Some(Ok(device))
+			},
+		}
+	}
+}
+
+impl<'a, I> ImmdevicecollectionIter<'a, I>
+	where I: mmdevice_IMMDeviceCollection,
+{
+	pub(in crate::mmdevice) fn new(collection: &'a I) -> HrResult<Self> {
+		let count = collection.GetCount()?;
+		Ok(Self { collection, count, current: 0 })
+	}
+}
@@ -0,0 +1,20 @@
+#![cfg(feature = "mmdevice")]
+
+mod com_interfaces;
+mod funcs;
+
+pub(in crate::mmdevice) mod iterators;
+pub mod co;
+
+pub mod decl {
+	pub use super::com_interfaces::decl::*;
+	pub use super::funcs::*;
+}
+
+pub mod traits {
+	pub use super::com_interfaces::traits::*;
+}
+
+pub mod vt {
+	pub use super::com_interfaces::vt::*;
+}
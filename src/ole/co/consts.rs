@@ -1,5 +1,17 @@
 #![allow(non_camel_case_types)]
 
+const_ordinary! { CALLTYPE: u32;
+	/// [`CALLTYPE`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/ne-objidl-calltype)
+	/// enumeration (`u32`).
+	=>
+	=>
+	TOPLEVEL 1
+	NESTED 2
+	ASYNC 3
+	TOPLEVEL_CALLPENDING 4
+	ASYNC_CALLPENDING 5
+}
+
 const_ordinary! { CLSCTX: u32;
 	/// [`CLSCTX`](https://learn.microsoft.com/en-us/windows/win32/api/wtypesbase/ne-wtypesbase-clsctx)
 	/// enumeration (`u32`).
@@ -83,7 +95,40 @@ const_bitflag! { COINIT: u32;
 	SPEED_OVER_MEMORY 0x8
 }
 
-const_ordinary! { DROPEFFECT: u32;
+const_bitflag! { COWAIT: u32;
+	/// [`COWAIT_FLAGS`](https://learn.microsoft.com/en-us/windows/win32/api/combaseapi/ne-combaseapi-cowait_flags)
+	/// enumeration (`u32`).
+	=>
+	=>
+	DEFAULT 0x0
+	WAITALL 0x1
+	ALERTABLE 0x2
+	INPUTAVAILABLE 0x4
+	DISPATCH_CALLS 0x8
+	DISPATCH_WINDOW_MESSAGES 0x10
+}
+
+const_ordinary! { DATADIR: u32;
+	/// [`DATADIR`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/ne-objidl-datadir)
+	/// enumeration (`u32`).
+	=>
+	=>
+	GET 1
+	SET 2
+}
+
+const_ordinary! { DVASPECT: u32;
+	/// [`DVASPECT`](https://learn.microsoft.com/en-us/windows/win32/api/wtypes/ne-wtypes-dvaspect)
+	/// enumeration (`u32`).
+	=>
+	=>
+	CONTENT 1
+	THUMBNAIL 2
+	ICON 4
+	DOCPRINT 8
+}
+
+const_bitflag! { DROPEFFECT: u32;
 	/// [`DROPEFFECT`](https://learn.microsoft.com/en-us/windows/win32/com/dropeffect-constants)
 	/// constants (`u32`).
 	=>
@@ -283,6 +328,25 @@ const_ordinary! { MKSYS: u32;
 	LUAMONIKER 10
 }
 
+const_ordinary! { PENDINGMSG: u32;
+	/// [`PENDINGMSG`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/ne-objidl-pendingmsg)
+	/// enumeration (`u32`).
+	=>
+	=>
+	CANCELCALL 0
+	WAITNOPROCESS 1
+	WAITDEFPROCESS 2
+}
+
+const_ordinary! { PENDINGTYPE: u32;
+	/// [`PENDINGTYPE`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/ne-objidl-pendingtype)
+	/// enumeration (`u32`).
+	=>
+	=>
+	TOPLEVEL 1
+	NESTED 2
+}
+
 const_ordinary! { PICTYPE: i16;
 	/// [`PICTYPE`](https://learn.microsoft.com/en-us/windows/win32/com/pictype-constants)
 	/// constants (`i16`).
@@ -371,6 +435,16 @@ const_ordinary! { SEC_WINNT_AUTH_IDENTITY: u32;
 	UNICODE 0x2
 }
 
+const_ordinary! { SERVERCALL: u32;
+	/// [`SERVERCALL`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/ne-objidl-servercall)
+	/// enumeration (`u32`).
+	=>
+	=>
+	ISHANDLED 0
+	REJECTED 1
+	RETRYLATER 2
+}
+
 const_ordinary! { SEVERITY: u8;
 	/// [`HRESULT`](crate::co::HRESULT) severity (`u8`).
 	=>
@@ -391,6 +465,31 @@ const_bitflag! { STGC: u32;
 	CONSOLIDATE 8
 }
 
+const_bitflag! { STGM: u32;
+	/// [`STGM`](https://learn.microsoft.com/en-us/windows/win32/stg/stgm-constants)
+	/// constants (`u32`).
+	=>
+	=>
+	READ 0x0000_0000
+	WRITE 0x0000_0001
+	READWRITE 0x0000_0002
+	SHARE_DENY_NONE 0x0000_0040
+	SHARE_DENY_READ 0x0000_0030
+	SHARE_DENY_WRITE 0x0000_0020
+	SHARE_EXCLUSIVE 0x0000_0010
+	PRIORITY 0x0004_0000
+	CREATE 0x0000_1000
+	CONVERT 0x0002_0000
+	FAILIFTHERE 0x0000_0000
+	DIRECT 0x0000_0000
+	TRANSACTED 0x0001_0000
+	NOSCRATCH 0x0010_0000
+	NOSNAPSHOT 0x0020_0000
+	SIMPLE 0x0800_0000
+	DIRECT_SWMR 0x0040_0000
+	DELETEONRELEASE 0x0400_0000
+}
+
 const_ordinary! { STREAM_SEEK: u32;
 	/// [`STREAM_SEEK`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/ne-objidl-stream_seek)
 	/// enumeration (`u32`).
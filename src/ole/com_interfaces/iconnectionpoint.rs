@@ -0,0 +1,268 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::ffi_types::*;
+use crate::ole::privs::*;
+use crate::prelude::*;
+use crate::vt::*;
+
+/// [`IConnectionPoint`](crate::IConnectionPoint) virtual table.
+#[repr(C)]
+pub struct IConnectionPointVT {
+	pub IUnknownVT: IUnknownVT,
+	pub GetConnectionInterface: fn(COMPTR, PVOID) -> HRES,
+	pub GetConnectionPointContainer: fn(COMPTR, *mut COMPTR) -> HRES,
+	pub Advise: fn(COMPTR, COMPTR, *mut u32) -> HRES,
+	pub Unadvise: fn(COMPTR, u32) -> HRES,
+	pub EnumConnections: fn(COMPTR, *mut COMPTR) -> HRES,
+}
+
+com_interface! { IConnectionPoint: "b196b286-bab4-101a-b69c-00aa00341d07";
+	/// [`IConnectionPoint`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nn-ocidl-iconnectionpoint)
+	/// COM interface over [`IConnectionPointVT`](crate::vt::IConnectionPointVT).
+	///
+	/// Automatically calls
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// Usually obtained with
+	/// [`IConnectionPointContainer::FindConnectionPoint`](crate::prelude::ole_IConnectionPointContainer::FindConnectionPoint).
+}
+
+impl ole_IConnectionPoint for IConnectionPoint {}
+
+/// This trait is enabled with the `ole` feature, and provides methods for
+/// [`IConnectionPoint`](crate::IConnectionPoint).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait ole_IConnectionPoint: ole_IUnknown {
+	/// [`IConnectionPoint::Advise`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-iconnectionpoint-advise)
+	/// method.
+	///
+	/// Returns a guard which automatically calls
+	/// [`IConnectionPoint::Unadvise`](crate::prelude::ole_IConnectionPoint::Unadvise)
+	/// when the object goes out of scope. The guard holds its own reference to
+	/// the connection point, so it can be stored alongside the event sink.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let container: w::IConnectionPointContainer; // initialized somewhere
+	/// # let container = unsafe { w::IConnectionPointContainer::null() };
+	/// let sink: w::IUnknown; // event sink implemented somewhere
+	/// # let sink = unsafe { w::IUnknown::null() };
+	/// let events_iid: co::IID; // IID of the outgoing interface
+	/// # let events_iid = co::IID::default();
+	///
+	/// let cp = container.FindConnectionPoint(&events_iid)?;
+	/// let _advise = cp.Advise(&sink)?; // Unadvise() called when dropped
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn Advise(&self, sink: &impl ole_IUnknown) -> HrResult<UnadviseGuard<Self>> {
+		let mut cookie = u32::default();
+		unsafe {
+			ok_to_hrresult(
+				(vt::<IConnectionPointVT>(self).Advise)(
+					self.ptr(),
+					sink.ptr(),
+					&mut cookie,
+				),
+			).map(|_| UnadviseGuard::new(self.clone(), cookie))
+		}
+	}
+
+	/// [`IConnectionPoint::GetConnectionInterface`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-iconnectionpoint-getconnectioninterface)
+	/// method.
+	#[must_use]
+	fn GetConnectionInterface(&self) -> HrResult<co::IID> {
+		let mut iid = co::IID::default();
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IConnectionPointVT>(self).GetConnectionInterface)(
+					self.ptr(),
+					&mut iid as *mut _ as _,
+				)
+			},
+		).map(|_| iid)
+	}
+
+	fn_com_interface_get! { GetConnectionPointContainer: IConnectionPointVT, IConnectionPointContainer;
+		/// [`IConnectionPoint::GetConnectionPointContainer`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-iconnectionpoint-getconnectionpointcontainer)
+		/// method.
+	}
+
+	/// [`IConnectionPoint::Unadvise`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-iconnectionpoint-unadvise)
+	/// method.
+	///
+	/// Paired with
+	/// [`IConnectionPoint::Advise`](crate::prelude::ole_IConnectionPoint::Advise),
+	/// whose returned guard already calls this method; call it manually only
+	/// when the guard has been leaked with
+	/// [`UnadviseGuard::leak`](crate::guard::UnadviseGuard::leak).
+	fn Unadvise(&self, cookie: u32) -> HrResult<()> {
+		ok_to_hrresult(
+			unsafe { (vt::<IConnectionPointVT>(self).Unadvise)(self.ptr(), cookie) },
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::cell::RefCell;
+	use std::rc::Rc;
+
+	use crate::co;
+	use crate::decl::*;
+	use crate::kernel::ffi_types::*;
+	use crate::ole::privs::*;
+	use crate::prelude::*;
+
+	const EVENTS_IID: co::IID = unsafe { co::IID::from_raw("a1b2c3d4-0000-0000-0000-000000000001") };
+
+	/// Connected sinks, by cookie.
+	type Sinks = Rc<RefCell<Vec<(u32, IUnknown)>>>;
+
+	struct MockCp { sinks: Sinks }
+	struct MockContainer { cp: IConnectionPoint }
+
+	#[repr(C)]
+	struct MockCpVT {
+		IUnknownVT: ComObjUnknownVT<MockCp>,
+		GetConnectionInterface: extern "system" fn(*mut ComObj<MockCp>, PVOID) -> HRES,
+		GetConnectionPointContainer: extern "system" fn(*mut ComObj<MockCp>, *mut COMPTR) -> HRES,
+		Advise: extern "system" fn(*mut ComObj<MockCp>, COMPTR, *mut u32) -> HRES,
+		Unadvise: extern "system" fn(*mut ComObj<MockCp>, u32) -> HRES,
+		EnumConnections: extern "system" fn(*mut ComObj<MockCp>, *mut COMPTR) -> HRES,
+	}
+
+	#[repr(C)]
+	struct MockContainerVT {
+		IUnknownVT: ComObjUnknownVT<MockContainer>,
+		EnumConnectionPoints: extern "system" fn(*mut ComObj<MockContainer>, *mut COMPTR) -> HRES,
+		FindConnectionPoint: extern "system" fn(*mut ComObj<MockContainer>, PCVOID, *mut COMPTR) -> HRES,
+	}
+
+	static MOCK_CP_VT: MockCpVT = MockCpVT {
+		IUnknownVT: ComObjUnknownVT::new(),
+		GetConnectionInterface: cp_get_connection_interface,
+		GetConnectionPointContainer: cp_not_impl,
+		Advise: cp_advise,
+		Unadvise: cp_unadvise,
+		EnumConnections: cp_not_impl,
+	};
+
+	static MOCK_CONTAINER_VT: MockContainerVT = MockContainerVT {
+		IUnknownVT: ComObjUnknownVT::new(),
+		EnumConnectionPoints: container_not_impl,
+		FindConnectionPoint: container_find_connection_point,
+	};
+
+	extern "system" fn cp_get_connection_interface(_p: *mut ComObj<MockCp>, piid: PVOID) -> HRES {
+		unsafe { *(piid as *mut co::IID) = EVENTS_IID; }
+		co::HRESULT::S_OK.raw()
+	}
+
+	extern "system" fn cp_advise(p: *mut ComObj<MockCp>, sink: COMPTR, cookie: *mut u32) -> HRES {
+		let sink = std::mem::ManuallyDrop::new(unsafe { IUnknown::from_ptr(sink) });
+		let mut sinks = unsafe { ComObj::imp(p) }.sinks.borrow_mut();
+		let new_cookie = sinks.iter().map(|(c, _)| *c).max().unwrap_or_default() + 1;
+		sinks.push((new_cookie, (*sink).clone())); // AddRef the sink
+		unsafe { *cookie = new_cookie; }
+		co::HRESULT::S_OK.raw()
+	}
+
+	extern "system" fn cp_unadvise(p: *mut ComObj<MockCp>, cookie: u32) -> HRES {
+		let mut sinks = unsafe { ComObj::imp(p) }.sinks.borrow_mut();
+		match sinks.iter().position(|(c, _)| *c == cookie) {
+			Some(idx) => {
+				sinks.remove(idx); // Release the sink
+				co::HRESULT::S_OK.raw()
+			},
+			None => co::HRESULT::E_INVALIDARG.raw(),
+		}
+	}
+
+	extern "system" fn cp_not_impl(_p: *mut ComObj<MockCp>, _pp: *mut COMPTR) -> HRES {
+		co::HRESULT::E_NOTIMPL.raw()
+	}
+
+	extern "system" fn container_find_connection_point(
+		p: *mut ComObj<MockContainer>,
+		riid: PCVOID,
+		pp: *mut COMPTR,
+	) -> HRES
+	{
+		if unsafe { *(riid as *const co::IID) } != EVENTS_IID {
+			return co::HRESULT::E_NOINTERFACE.raw();
+		}
+		let cp = unsafe { ComObj::imp(p) }.cp.clone();
+		unsafe { *pp = std::mem::ManuallyDrop::new(cp).ptr(); }
+		co::HRESULT::S_OK.raw()
+	}
+
+	extern "system" fn container_not_impl(_p: *mut ComObj<MockContainer>, _pp: *mut COMPTR) -> HRES {
+		co::HRESULT::E_NOTIMPL.raw()
+	}
+
+	fn mock_container(sinks: &Sinks) -> IConnectionPointContainer {
+		let cp = unsafe {
+			IConnectionPoint::from_ptr(
+				ComObj::new_ptr(
+					&MOCK_CP_VT,
+					vec![IConnectionPoint::IID],
+					Box::new(MockCp { sinks: sinks.clone() }),
+				),
+			)
+		};
+		unsafe {
+			IConnectionPointContainer::from_ptr(
+				ComObj::new_ptr(
+					&MOCK_CONTAINER_VT,
+					vec![IConnectionPointContainer::IID],
+					Box::new(MockContainer { cp }),
+				),
+			)
+		}
+	}
+
+	struct Sink;
+	impl ole_IMessageFilterImpl for Sink {}
+
+	#[test]
+	fn advise_and_unadvise() {
+		let sinks = Sinks::default();
+		let container = mock_container(&sinks);
+		let sink = IMessageFilter::from_impl(Sink); // any COM object will do
+
+		assert_eq!(
+			container.FindConnectionPoint(&co::IID::default()).err(),
+			Some(co::HRESULT::E_NOINTERFACE),
+		);
+
+		// The guard outlives the connection point it came from.
+		let guard = container.FindConnectionPoint(&EVENTS_IID).unwrap()
+			.Advise(&sink).unwrap();
+		assert_eq!(sinks.borrow().len(), 1);
+		assert_eq!(sinks.borrow()[0].0, guard.cookie());
+		assert_eq!(sinks.borrow()[0].1.ptr(), sink.ptr());
+
+		drop(guard);
+		assert!(sinks.borrow().is_empty());
+
+		let cp = container.FindConnectionPoint(&EVENTS_IID).unwrap();
+		assert_eq!(cp.GetConnectionInterface().unwrap(), EVENTS_IID);
+		let cookie = cp.Advise(&sink).unwrap().leak();
+		assert_eq!(sinks.borrow().len(), 1);
+		cp.Unadvise(cookie).unwrap();
+		assert!(sinks.borrow().is_empty());
+	}
+}
@@ -0,0 +1,58 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::kernel::ffi_types::*;
+use crate::ole::privs::*;
+use crate::prelude::*;
+use crate::vt::*;
+
+/// [`IConnectionPointContainer`](crate::IConnectionPointContainer) virtual
+/// table.
+#[repr(C)]
+pub struct IConnectionPointContainerVT {
+	pub IUnknownVT: IUnknownVT,
+	pub EnumConnectionPoints: fn(COMPTR, *mut COMPTR) -> HRES,
+	pub FindConnectionPoint: fn(COMPTR, PCVOID, *mut COMPTR) -> HRES,
+}
+
+com_interface! { IConnectionPointContainer: "b196b284-bab4-101a-b69c-00aa00341d07";
+	/// [`IConnectionPointContainer`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nn-ocidl-iconnectionpointcontainer)
+	/// COM interface over
+	/// [`IConnectionPointContainerVT`](crate::vt::IConnectionPointContainerVT).
+	///
+	/// Automatically calls
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// Usually obtained by querying a COM object which fires events, with
+	/// [`QueryInterface`](crate::prelude::ole_IUnknown::QueryInterface).
+}
+
+impl ole_IConnectionPointContainer for IConnectionPointContainer {}
+
+/// This trait is enabled with the `ole` feature, and provides methods for
+/// [`IConnectionPointContainer`](crate::IConnectionPointContainer).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait ole_IConnectionPointContainer: ole_IUnknown {
+	/// [`IConnectionPointContainer::FindConnectionPoint`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-iconnectionpointcontainer-findconnectionpoint)
+	/// method.
+	#[must_use]
+	fn FindConnectionPoint(&self, iid: &co::IID) -> HrResult<IConnectionPoint> {
+		let mut queried = unsafe { IConnectionPoint::null() };
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IConnectionPointContainerVT>(self).FindConnectionPoint)(
+					self.ptr(),
+					iid as *const _ as _,
+					queried.as_mut(),
+				)
+			},
+		).map(|_| queried)
+	}
+}
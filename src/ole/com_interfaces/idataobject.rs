@@ -1,6 +1,9 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
+use crate::co;
+use crate::decl::*;
 use crate::kernel::ffi_types::*;
+use crate::ole::privs::*;
 use crate::prelude::*;
 use crate::vt::*;
 
@@ -39,5 +42,36 @@ impl ole_IDataObject for IDataObject {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait ole_IDataObject: ole_IUnknown {
-
+	/// [`IDataObject::EnumFormatEtc`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/nf-objidl-idataobject-enumformatetc)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// Listing the formats offered by a data object:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let data_obj: w::IDataObject; // initialized somewhere
+	/// # let data_obj = unsafe { w::IDataObject::null() };
+	///
+	/// for fmt in data_obj.EnumFormatEtc(co::DATADIR::GET)?.iter() {
+	///     let fmt = fmt?;
+	///     println!("{}", fmt.cfFormat());
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn EnumFormatEtc(&self, direction: co::DATADIR) -> HrResult<IEnumFORMATETC> {
+		let mut queried = unsafe { IEnumFORMATETC::null() };
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IDataObjectVT>(self).EnumFormatEtc)(
+					self.ptr(),
+					direction.raw(),
+					queried.as_mut(),
+				)
+			},
+		).map(|_| queried)
+	}
 }
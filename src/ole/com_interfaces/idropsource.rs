@@ -0,0 +1,151 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::*;
+use crate::ole::privs::*;
+use crate::prelude::*;
+
+com_interface! { IDropSource: "00000121-0000-0000-c000-000000000046";
+	/// [`IDropSource`](https://learn.microsoft.com/en-us/windows/win32/api/oleidl/nn-oleidl-idropsource)
+	/// COM interface.
+	///
+	/// Automatically calls
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// To start a drag operation, your own drop source must be created with
+	/// [`IDropSource::from_impl`](crate::IDropSource::from_impl), and passed
+	/// to [`DoDragDrop`](crate::DoDragDrop).
+}
+
+impl ole_IDropSource for IDropSource {}
+
+/// This trait is enabled with the `ole` feature, and provides methods for
+/// [`IDropSource`](crate::IDropSource).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait ole_IDropSource: ole_IUnknown {}
+
+impl IDropSource {
+	/// Creates a new COM object which implements `IDropSource` by forwarding
+	/// the calls to the given
+	/// [`ole_IDropSourceImpl`](crate::prelude::ole_IDropSourceImpl) object.
+	///
+	/// # Examples
+	///
+	/// Using the default behavior, which drops when the left mouse button is
+	/// released, and cancels when ESC is pressed:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// struct MySource;
+	/// impl ole_IDropSourceImpl for MySource {}
+	///
+	/// let data_obj: w::IDataObject; // initialized somewhere
+	/// # let data_obj = unsafe { w::IDataObject::null() };
+	///
+	/// let source = w::IDropSource::from_impl(MySource);
+	/// let effect = w::DoDragDrop(
+	///     &data_obj, &source, co::DROPEFFECT::COPY | co::DROPEFFECT::MOVE)?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	pub fn from_impl(obj: impl ole_IDropSourceImpl + 'static) -> Self {
+		let pobj = DropSourceObj::new_ptr(
+			&DROP_SOURCE_VT,
+			vec![Self::IID],
+			Box::new(obj),
+		);
+		unsafe { Self::from_ptr(pobj) }
+	}
+}
+
+/// User-implemented behavior of an [`IDropSource`](crate::IDropSource) object,
+/// created with [`IDropSource::from_impl`](crate::IDropSource::from_impl).
+///
+/// Both methods have default implementations, which reproduce the standard
+/// drag behavior.
+///
+/// This trait is enabled with the `ole` feature.
+pub trait ole_IDropSourceImpl {
+	/// [`IDropSource::QueryContinueDrag`](https://learn.microsoft.com/en-us/windows/win32/api/oleidl/nf-oleidl-idropsource-querycontinuedrag)
+	/// method.
+	///
+	/// Must return [`S_OK`](crate::co::HRESULT::S_OK) to continue the drag,
+	/// [`DRAGDROP_S_DROP`](crate::co::HRESULT::DRAGDROP_S_DROP) to drop, or
+	/// [`DRAGDROP_S_CANCEL`](crate::co::HRESULT::DRAGDROP_S_CANCEL) to
+	/// cancel.
+	///
+	/// The default implementation cancels if ESC was pressed, and drops when
+	/// the left mouse button is released.
+	fn query_continue_drag(&self,
+		escape_pressed: bool,
+		key_state: co::MK,
+	) -> co::HRESULT
+	{
+		if escape_pressed {
+			co::HRESULT::DRAGDROP_S_CANCEL
+		} else if !key_state.has(co::MK::LBUTTON) {
+			co::HRESULT::DRAGDROP_S_DROP
+		} else {
+			co::HRESULT::S_OK
+		}
+	}
+
+	/// [`IDropSource::GiveFeedback`](https://learn.microsoft.com/en-us/windows/win32/api/oleidl/nf-oleidl-idropsource-givefeedback)
+	/// method.
+	///
+	/// Must return [`S_OK`](crate::co::HRESULT::S_OK) if the cursor was set
+	/// by this method, or
+	/// [`DRAGDROP_S_USEDEFAULTCURSORS`](crate::co::HRESULT::DRAGDROP_S_USEDEFAULTCURSORS)
+	/// to use the default cursors, which is what the default implementation
+	/// does.
+	fn give_feedback(&self, effect: co::DROPEFFECT) -> co::HRESULT {
+		let _ = effect;
+		co::HRESULT::DRAGDROP_S_USEDEFAULTCURSORS
+	}
+}
+
+//------------------------------------------------------------------------------
+
+type DropSourceObj = ComObj<dyn ole_IDropSourceImpl>;
+
+#[repr(C)]
+struct DropSourceImplVT {
+	IUnknownVT: ComObjUnknownVT<dyn ole_IDropSourceImpl>,
+	QueryContinueDrag: extern "system" fn(*mut DropSourceObj, BOOL, u32) -> HRES,
+	GiveFeedback: extern "system" fn(*mut DropSourceObj, u32) -> HRES,
+}
+
+static DROP_SOURCE_VT: DropSourceImplVT = DropSourceImplVT {
+	IUnknownVT: ComObjUnknownVT::new(),
+	QueryContinueDrag: drop_source_query_continue_drag,
+	GiveFeedback: drop_source_give_feedback,
+};
+
+extern "system" fn drop_source_query_continue_drag(
+	p: *mut DropSourceObj,
+	escape_pressed: BOOL,
+	key_state: u32,
+) -> HRES
+{
+	unsafe { DropSourceObj::imp(p) }.query_continue_drag(
+		escape_pressed != 0,
+		unsafe { co::MK::from_raw(key_state as _) },
+	).raw()
+}
+
+extern "system" fn drop_source_give_feedback(
+	p: *mut DropSourceObj,
+	effect: u32,
+) -> HRES
+{
+	unsafe { DropSourceObj::imp(p) }.give_feedback(
+		unsafe { co::DROPEFFECT::from_raw(effect) },
+	).raw()
+}
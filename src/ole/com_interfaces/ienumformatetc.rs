@@ -0,0 +1,128 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::ffi_types::*;
+use crate::ole::iterators::*;
+use crate::ole::privs::*;
+use crate::prelude::*;
+use crate::vt::*;
+
+/// [`IEnumFORMATETC`](crate::IEnumFORMATETC) virtual table.
+#[repr(C)]
+pub struct IEnumFORMATETCVT {
+	pub IUnknownVT: IUnknownVT,
+	pub Next: fn(COMPTR, u32, PVOID, *mut u32) -> HRES,
+	pub Skip: fn(COMPTR, u32) -> HRES,
+	pub Reset: fn(COMPTR) -> HRES,
+	pub Clone: fn(COMPTR, *mut COMPTR) -> HRES,
+}
+
+com_interface! { IEnumFORMATETC: "00000103-0000-0000-c000-000000000046";
+	/// [`IEnumFORMATETC`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/nn-objidl-ienumformatetc)
+	/// COM interface over [`IEnumFORMATETCVT`](crate::vt::IEnumFORMATETCVT).
+	///
+	/// Automatically calls
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl ole_IEnumFORMATETC for IEnumFORMATETC {}
+
+/// This trait is enabled with the `ole` feature, and provides methods for
+/// [`IEnumFORMATETC`](crate::IEnumFORMATETC).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait ole_IEnumFORMATETC: ole_IUnknown {
+	/// Returns an iterator over the [`FORMATETC`](crate::FORMATETC) elements
+	/// which calls
+	/// [`IEnumFORMATETC::Next`](crate::prelude::ole_IEnumFORMATETC::Next)
+	/// internally.
+	///
+	/// For an example, see
+	/// [`IDataObject::EnumFormatEtc`](crate::prelude::ole_IDataObject::EnumFormatEtc).
+	#[must_use]
+	fn iter(&self,
+	) -> Box<dyn Iterator<Item = HrResult<FORMATETC<'static>>> + '_>
+	{
+		Box::new(IenumIter::new(move || self.Next()))
+	}
+
+	/// [`IEnumFORMATETC::Next`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/nf-objidl-ienumformatetc-next)
+	/// method, which retrieves up to `count` elements in a single call.
+	///
+	/// When the end of the enumeration is reached, fewer elements – possibly
+	/// none – are returned. The target devices, if any, are freed, and `ptd`
+	/// fields are returned as null.
+	#[must_use]
+	fn next_batch(&self, count: u32) -> HrResult<Vec<FORMATETC<'static>>> {
+		ienum_next_batch(
+			count,
+			FORMATETC::default,
+			|count, fmts, fetched| unsafe {
+				(vt::<IEnumFORMATETCVT>(self).Next)(self.ptr(), count, fmts as _, fetched)
+			},
+		).map(|mut fmts| {
+			for fmt in fmts.iter_mut() {
+				if let Some(ptd) = fmt.ptd() {
+					let _ = unsafe { CoTaskMemFreeGuard::new(ptd as *mut _ as _, 0) };
+					fmt.set_ptd(None);
+				}
+			}
+			fmts
+		})
+	}
+
+	/// [`IEnumFORMATETC::Next`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/nf-objidl-ienumformatetc-next)
+	/// method.
+	///
+	/// The target device, if any, is freed, and `ptd` is returned as null.
+	///
+	/// Prefer using
+	/// [`IEnumFORMATETC::iter`](crate::prelude::ole_IEnumFORMATETC::iter),
+	/// which is simpler.
+	#[must_use]
+	fn Next(&self) -> HrResult<Option<FORMATETC<'static>>> {
+		let mut fmt = FORMATETC::default();
+		let mut fetched = u32::default();
+
+		match unsafe {
+			co::HRESULT::from_raw(
+				(vt::<IEnumFORMATETCVT>(self).Next)(
+					self.ptr(),
+					1, // retrieve only 1
+					&mut fmt as *mut _ as _,
+					&mut fetched,
+				),
+			)
+		} {
+			co::HRESULT::S_OK => {
+				if let Some(ptd) = fmt.ptd() {
+					let _ = unsafe { CoTaskMemFreeGuard::new(ptd as *mut _ as _, 0) };
+					fmt.set_ptd(None);
+				}
+				Ok(Some(fmt))
+			},
+			co::HRESULT::S_FALSE => Ok(None), // no item found
+			hr => Err(hr), // actual error
+		}
+	}
+
+	fn_com_noparm! { Reset: IEnumFORMATETCVT;
+		/// [`IEnumFORMATETC::Reset`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/nf-objidl-ienumformatetc-reset)
+		/// method.
+	}
+
+	/// [`IEnumFORMATETC::Skip`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/nf-objidl-ienumformatetc-skip)
+	/// method.
+	fn Skip(&self, count: u32) -> HrResult<bool> {
+		okfalse_to_hrresult(
+			unsafe { (vt::<IEnumFORMATETCVT>(self).Skip)(self.ptr(), count) },
+		)
+	}
+}
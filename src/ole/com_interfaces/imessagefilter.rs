@@ -0,0 +1,191 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::*;
+use crate::ole::privs::*;
+use crate::prelude::*;
+
+com_interface! { IMessageFilter: "00000016-0000-0000-c000-000000000046";
+	/// [`IMessageFilter`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/nn-objidl-imessagefilter)
+	/// COM interface.
+	///
+	/// Automatically calls
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// To handle rejected calls, your own message filter must be created with
+	/// [`IMessageFilter::from_impl`](crate::IMessageFilter::from_impl), and
+	/// registered with
+	/// [`CoRegisterMessageFilter`](crate::CoRegisterMessageFilter).
+}
+
+impl ole_IMessageFilter for IMessageFilter {}
+
+/// This trait is enabled with the `ole` feature, and provides methods for
+/// [`IMessageFilter`](crate::IMessageFilter).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait ole_IMessageFilter: ole_IUnknown {}
+
+impl IMessageFilter {
+	/// Creates a new COM object which implements `IMessageFilter` by
+	/// forwarding the calls to the given
+	/// [`ole_IMessageFilterImpl`](crate::prelude::ole_IMessageFilterImpl)
+	/// object.
+	///
+	/// # Examples
+	///
+	/// Retrying rejected calls every 250 milliseconds, for up to 10 seconds:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// struct MyFilter;
+	/// impl ole_IMessageFilterImpl for MyFilter {
+	///     fn retry_rejected_call(&self,
+	///         tick_count: u32,
+	///         reject_type: co::SERVERCALL,
+	///     ) -> Option<u32>
+	///     {
+	///         if reject_type == co::SERVERCALL::RETRYLATER && tick_count < 10_000 {
+	///             Some(250)
+	///         } else {
+	///             None
+	///         }
+	///     }
+	/// }
+	///
+	/// let filter = w::IMessageFilter::from_impl(MyFilter);
+	/// let prev_filter = w::CoRegisterMessageFilter(Some(&filter))?;
+	///
+	/// // make out-of-process COM calls...
+	///
+	/// w::CoRegisterMessageFilter(prev_filter.as_ref())?; // restore previous
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	pub fn from_impl(obj: impl ole_IMessageFilterImpl + 'static) -> Self {
+		let pobj = MessageFilterObj::new_ptr(
+			&MESSAGE_FILTER_VT,
+			vec![Self::IID],
+			Box::new(obj),
+		);
+		unsafe { Self::from_ptr(pobj) }
+	}
+}
+
+/// User-implemented behavior of an [`IMessageFilter`](crate::IMessageFilter)
+/// object, created with
+/// [`IMessageFilter::from_impl`](crate::IMessageFilter::from_impl).
+///
+/// All methods have default implementations, which reproduce the behavior of
+/// COM when no message filter is registered.
+///
+/// This trait is enabled with the `ole` feature.
+pub trait ole_IMessageFilterImpl {
+	/// [`IMessageFilter::HandleInComingCall`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/nf-objidl-imessagefilter-handleincomingcall)
+	/// method.
+	///
+	/// The default implementation accepts the call, returning
+	/// [`SERVERCALL::ISHANDLED`](crate::co::SERVERCALL::ISHANDLED).
+	fn handle_in_coming_call(&self,
+		call_type: co::CALLTYPE,
+		tick_count: u32,
+	) -> co::SERVERCALL
+	{
+		let _ = (call_type, tick_count);
+		co::SERVERCALL::ISHANDLED
+	}
+
+	/// [`IMessageFilter::RetryRejectedCall`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/nf-objidl-imessagefilter-retryrejectedcall)
+	/// method.
+	///
+	/// Must return the number of milliseconds to wait before retrying the
+	/// call – values below 100 retry immediately –, or `None` to cancel it,
+	/// which is what the default implementation does.
+	fn retry_rejected_call(&self,
+		tick_count: u32,
+		reject_type: co::SERVERCALL,
+	) -> Option<u32>
+	{
+		let _ = (tick_count, reject_type);
+		None
+	}
+
+	/// [`IMessageFilter::MessagePending`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/nf-objidl-imessagefilter-messagepending)
+	/// method.
+	///
+	/// The default implementation returns
+	/// [`PENDINGMSG::WAITDEFPROCESS`](crate::co::PENDINGMSG::WAITDEFPROCESS).
+	fn message_pending(&self,
+		tick_count: u32,
+		pending_type: co::PENDINGTYPE,
+	) -> co::PENDINGMSG
+	{
+		let _ = (tick_count, pending_type);
+		co::PENDINGMSG::WAITDEFPROCESS
+	}
+}
+
+//------------------------------------------------------------------------------
+
+type MessageFilterObj = ComObj<dyn ole_IMessageFilterImpl>;
+
+#[repr(C)]
+struct MessageFilterImplVT {
+	IUnknownVT: ComObjUnknownVT<dyn ole_IMessageFilterImpl>,
+	HandleInComingCall: extern "system" fn(*mut MessageFilterObj, u32, HANDLE, u32, PVOID) -> u32,
+	RetryRejectedCall: extern "system" fn(*mut MessageFilterObj, HANDLE, u32, u32) -> u32,
+	MessagePending: extern "system" fn(*mut MessageFilterObj, HANDLE, u32, u32) -> u32,
+}
+
+static MESSAGE_FILTER_VT: MessageFilterImplVT = MessageFilterImplVT {
+	IUnknownVT: ComObjUnknownVT::new(),
+	HandleInComingCall: message_filter_handle_in_coming_call,
+	RetryRejectedCall: message_filter_retry_rejected_call,
+	MessagePending: message_filter_message_pending,
+};
+
+extern "system" fn message_filter_handle_in_coming_call(
+	p: *mut MessageFilterObj,
+	call_type: u32,
+	_htask_caller: HANDLE,
+	tick_count: u32,
+	_interface_info: PVOID,
+) -> u32
+{
+	unsafe { MessageFilterObj::imp(p) }.handle_in_coming_call(
+		unsafe { co::CALLTYPE::from_raw(call_type) },
+		tick_count,
+	).raw()
+}
+
+extern "system" fn message_filter_retry_rejected_call(
+	p: *mut MessageFilterObj,
+	_htask_callee: HANDLE,
+	tick_count: u32,
+	reject_type: u32,
+) -> u32
+{
+	unsafe { MessageFilterObj::imp(p) }.retry_rejected_call(
+		tick_count,
+		unsafe { co::SERVERCALL::from_raw(reject_type) },
+	).unwrap_or(u32::MAX) // -1 cancels the call
+}
+
+extern "system" fn message_filter_message_pending(
+	p: *mut MessageFilterObj,
+	_htask_callee: HANDLE,
+	tick_count: u32,
+	pending_type: u32,
+) -> u32
+{
+	unsafe { MessageFilterObj::imp(p) }.message_pending(
+		tick_count,
+		unsafe { co::PENDINGTYPE::from_raw(pending_type) },
+	).raw()
+}
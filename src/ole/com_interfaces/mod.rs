@@ -1,6 +1,11 @@
 mod ibindctx;
+mod iconnectionpoint;
+mod iconnectionpointcontainer;
 mod idataobject;
+mod idropsource;
 mod idroptarget;
+mod ienumformatetc;
+mod imessagefilter;
 mod imoniker;
 mod ipersist;
 mod ipersiststream;
@@ -11,8 +16,13 @@ mod iunknown;
 
 pub mod decl {
 	pub use super::ibindctx::IBindCtx;
+	pub use super::iconnectionpoint::IConnectionPoint;
+	pub use super::iconnectionpointcontainer::IConnectionPointContainer;
 	pub use super::idataobject::IDataObject;
+	pub use super::idropsource::IDropSource;
 	pub use super::idroptarget::IDropTarget;
+	pub use super::ienumformatetc::IEnumFORMATETC;
+	pub use super::imessagefilter::IMessageFilter;
 	pub use super::imoniker::IMoniker;
 	pub use super::ipersist::IPersist;
 	pub use super::ipersiststream::IPersistStream;
@@ -24,8 +34,13 @@ pub mod decl {
 
 pub mod traits {
 	pub use super::ibindctx::ole_IBindCtx;
+	pub use super::iconnectionpoint::ole_IConnectionPoint;
+	pub use super::iconnectionpointcontainer::ole_IConnectionPointContainer;
 	pub use super::idataobject::ole_IDataObject;
+	pub use super::idropsource::{ole_IDropSource, ole_IDropSourceImpl};
 	pub use super::idroptarget::ole_IDropTarget;
+	pub use super::ienumformatetc::ole_IEnumFORMATETC;
+	pub use super::imessagefilter::{ole_IMessageFilter, ole_IMessageFilterImpl};
 	pub use super::imoniker::ole_IMoniker;
 	pub use super::ipersist::ole_IPersist;
 	pub use super::ipersiststream::ole_IPersistStream;
@@ -37,8 +52,11 @@ pub mod traits {
 
 pub mod vt {
 	pub use super::ibindctx::IBindCtxVT;
+	pub use super::iconnectionpoint::IConnectionPointVT;
+	pub use super::iconnectionpointcontainer::IConnectionPointContainerVT;
 	pub use super::idataobject::IDataObjectVT;
 	pub use super::idroptarget::IDropTargetVT;
+	pub use super::ienumformatetc::IEnumFORMATETCVT;
 	pub use super::imoniker::IMonikerVT;
 	pub use super::ipersist::IPersistVT;
 	pub use super::ipersiststream::IPersistStreamVT;
@@ -4,20 +4,24 @@ extern_sys! { "ole32";
 	CLSIDFromProgID(PCSTR, PVOID) -> HRES
 	CLSIDFromProgIDEx(PCSTR, PVOID) -> HRES
 	CLSIDFromString(PCSTR, PVOID) -> HRES
+	CoAllowSetForegroundWindow(COMPTR, PVOID) -> HRES
 	CoCreateGuid(PVOID) -> HRES
 	CoCreateInstance(PCVOID, *mut COMPTR, u32, PCVOID, *mut COMPTR) -> HRES
 	CoCreateInstanceEx(PCVOID, *mut COMPTR, u32, PCVOID, u32, PVOID) -> HRES
 	CoInitializeEx(PVOID, u32) -> HRES
 	CoLockObjectExternal(COMPTR, BOOL, BOOL) -> HRES
+	CoRegisterMessageFilter(COMPTR, *mut COMPTR) -> HRES
 	CoTaskMemAlloc(usize) -> PVOID
 	CoTaskMemFree(PVOID)
 	CoTaskMemRealloc(PVOID, usize) -> PVOID
 	CoUninitialize()
+	CoWaitForMultipleHandles(u32, u32, u32, *const HANDLE, *mut u32) -> HRES
 	CreateClassMoniker(PCVOID, *mut COMPTR) -> HRES
 	CreateFileMoniker(PCSTR, *mut COMPTR) -> HRES
 	CreateItemMoniker(PCSTR, PCSTR, *mut COMPTR) -> HRES
 	CreateObjrefMoniker(COMPTR, *mut COMPTR) -> HRES
 	CreatePointerMoniker(COMPTR, *mut COMPTR) -> HRES
+	DoDragDrop(COMPTR, COMPTR, u32, *mut u32) -> HRES
 	RegisterDragDrop(HANDLE, COMPTR) -> HRES
 	RevokeDragDrop(HANDLE) -> HRES
 	StringFromCLSID(PCVOID, *mut PSTR) -> HRES
@@ -3,6 +3,7 @@
 use crate::co;
 use crate::decl::*;
 use crate::guard::*;
+use crate::kernel::privs::*;
 use crate::ole::ffi;
 use crate::ole::privs::*;
 use crate::prelude::*;
@@ -52,6 +53,24 @@ pub fn CLSIDFromString(prog_id: &str) -> HrResult<co::CLSID> {
 	).map(|_| clsid)
 }
 
+/// [`CoAllowSetForegroundWindow`](https://learn.microsoft.com/en-us/windows/win32/api/combaseapi/nf-combaseapi-coallowsetforegroundwindow)
+/// function.
+///
+/// Allows the COM server process behind the given proxy to take the foreground
+/// window, so a subsequent
+/// [`HWND::SetForegroundWindow`](crate::prelude::user_Hwnd::SetForegroundWindow)
+/// call made by the server is not blocked by the foreground lock timeout. Must
+/// be called by the process which currently owns the foreground window.
+///
+/// See also [`AllowSetForegroundWindow`](crate::AllowSetForegroundWindow).
+pub fn CoAllowSetForegroundWindow(unk: &impl ole_IUnknown) -> HrResult<()> {
+	ok_to_hrresult(
+		unsafe {
+			ffi::CoAllowSetForegroundWindow(unk.ptr(), std::ptr::null_mut())
+		},
+	)
+}
+
 /// [`CoCreateGuid`](https://learn.microsoft.com/en-us/windows/win32/api/combaseapi/nf-combaseapi-cocreateguid)
 /// function.
 ///
@@ -207,6 +226,70 @@ pub fn CoLockObjectExternal<T>(
 	}
 }
 
+/// Calls the given closure, retrying it while it fails with
+/// [`RPC_E_CALL_REJECTED`](crate::co::HRESULT::RPC_E_CALL_REJECTED) or
+/// [`RPC_E_SERVERCALL_RETRYLATER`](crate::co::HRESULT::RPC_E_SERVERCALL_RETRYLATER),
+/// which are transient errors often returned by busy out-of-process COM
+/// servers.
+///
+/// The closure is called at most `attempts` times – but at least once –,
+/// sleeping `delay_ms` milliseconds between the calls. Any other error is
+/// returned immediately.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let obj: w::IPersist; // initialized somewhere
+/// # let obj = unsafe { w::IPersist::null() };
+///
+/// let clsid = w::com_retry(5, 200, || obj.GetClassID())?;
+/// # Ok::<_, co::HRESULT>(())
+/// ```
+pub fn com_retry<T>(
+	attempts: u32,
+	delay_ms: u32,
+	mut f: impl FnMut() -> HrResult<T>,
+) -> HrResult<T>
+{
+	let mut remaining = attempts;
+	loop {
+		match f() {
+			Err(co::HRESULT::RPC_E_CALL_REJECTED | co::HRESULT::RPC_E_SERVERCALL_RETRYLATER)
+				if remaining > 1 =>
+			{
+				remaining -= 1;
+				std::thread::sleep(std::time::Duration::from_millis(delay_ms as _));
+			},
+			res => return res,
+		}
+	}
+}
+
+/// [`CoRegisterMessageFilter`](https://learn.microsoft.com/en-us/windows/win32/api/objbase/nf-objbase-coregistermessagefilter)
+/// function.
+///
+/// Passing `None` revokes the current filter. Returns the previously
+/// registered filter, if any.
+///
+/// For an example, see
+/// [`IMessageFilter::from_impl`](crate::IMessageFilter::from_impl).
+pub fn CoRegisterMessageFilter(
+	filter: Option<&impl ole_IMessageFilter>,
+) -> HrResult<Option<IMessageFilter>>
+{
+	let mut queried = unsafe { IMessageFilter::null() };
+	ok_to_hrresult(
+		unsafe {
+			ffi::CoRegisterMessageFilter(
+				filter.map_or(std::ptr::null_mut(), |f| f.ptr()),
+				queried.as_mut(),
+			)
+		},
+	).map(|_| if queried.ptr().is_null() { None } else { Some(queried) })
+}
+
 /// [`CoTaskMemAlloc`](https://learn.microsoft.com/en-us/windows/win32/api/combaseapi/nf-combaseapi-cotaskmemalloc)
 /// function.
 ///
@@ -265,6 +348,56 @@ pub fn CoTaskMemRealloc(
 	}
 }
 
+/// [`CoWaitForMultipleHandles`](https://learn.microsoft.com/en-us/windows/win32/api/combaseapi/nf-combaseapi-cowaitformultiplehandles)
+/// function.
+///
+/// Unlike a plain wait, this function keeps dispatching COM calls and window
+/// messages while waiting, thus avoiding deadlocks in STA threads.
+///
+/// Returns the zero-based index of the handle which was signaled. If the
+/// timeout elapses, returns
+/// [`HRESULT::RPC_S_CALLPENDING`](crate::co::HRESULT::RPC_S_CALLPENDING).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let hevent: w::HEVENT; // initialized somewhere
+/// # let hevent = w::HEVENT::NULL;
+///
+/// let idx = w::CoWaitForMultipleHandles(
+///     co::COWAIT::DISPATCH_CALLS | co::COWAIT::DISPATCH_WINDOW_MESSAGES,
+///     Some(5000),
+///     &[&hevent],
+/// )?;
+/// # Ok::<_, co::HRESULT>(())
+/// ```
+pub fn CoWaitForMultipleHandles<H>(
+	flags: co::COWAIT,
+	timeout_ms: Option<u32>,
+	handles: &[&H],
+) -> HrResult<u32>
+	where H: Handle,
+{
+	let raw_handles = handles.iter()
+		.map(|h| h.ptr())
+		.collect::<Vec<_>>();
+	let mut index = u32::default();
+
+	ok_to_hrresult(
+		unsafe {
+			ffi::CoWaitForMultipleHandles(
+				flags.raw(),
+				timeout_ms.unwrap_or(INFINITE),
+				raw_handles.len() as _,
+				raw_handles.as_ptr(),
+				&mut index,
+			)
+		},
+	).map(|_| index)
+}
+
 /// [`CreateClassMoniker`](https://learn.microsoft.com/en-us/windows/win32/api/objbase/nf-objbase-createclassmoniker)
 /// function.
 #[must_use]
@@ -328,6 +461,38 @@ pub fn CreatePointerMoniker(unk: &impl ole_IUnknown) -> HrResult<IMoniker> {
 	).map(|_| queried)
 }
 
+/// [`DoDragDrop`](https://learn.microsoft.com/en-us/windows/win32/api/ole2/nf-ole2-dodragdrop)
+/// function.
+///
+/// Blocks until the drag operation ends, returning the effect which occurred;
+/// if the drag was cancelled, returns
+/// [`DROPEFFECT::NONE`](crate::co::DROPEFFECT::NONE).
+///
+/// For an example, see
+/// [`IDropSource::from_impl`](crate::IDropSource::from_impl).
+pub fn DoDragDrop(
+	data_obj: &impl ole_IDataObject,
+	drop_source: &impl ole_IDropSource,
+	ok_effects: co::DROPEFFECT,
+) -> HrResult<co::DROPEFFECT>
+{
+	let mut effect = co::DROPEFFECT::NONE;
+	match unsafe {
+		co::HRESULT::from_raw(
+			ffi::DoDragDrop(
+				data_obj.ptr(),
+				drop_source.ptr(),
+				ok_effects.raw(),
+				effect.as_mut(),
+			),
+		)
+	} {
+		co::HRESULT::DRAGDROP_S_DROP => Ok(effect),
+		co::HRESULT::DRAGDROP_S_CANCEL => Ok(co::DROPEFFECT::NONE),
+		hr => Err(hr),
+	}
+}
+
 /// [`StringFromCLSID`](https://learn.microsoft.com/en-us/windows/win32/api/combaseapi/nf-combaseapi-stringfromclsid)
 /// function.
 #[must_use]
@@ -341,3 +506,59 @@ pub fn StringFromCLSID(clsid: &co::CLSID) -> HrResult<String> {
 		name.to_string()
 	})
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::co;
+	use crate::decl::*;
+	use crate::prelude::*;
+
+	#[test]
+	fn co_register_message_filter_returns_previous() {
+		struct Filter;
+		impl ole_IMessageFilterImpl for Filter {}
+
+		let _com = CoInitializeEx(
+			co::COINIT::APARTMENTTHREADED | co::COINIT::DISABLE_OLE1DDE).unwrap();
+		let filter1 = IMessageFilter::from_impl(Filter);
+		let filter2 = IMessageFilter::from_impl(Filter);
+
+		assert!(CoRegisterMessageFilter(Some(&filter1)).unwrap().is_none());
+		let prev = CoRegisterMessageFilter(Some(&filter2)).unwrap().unwrap();
+		assert_eq!(prev.ptr(), filter1.ptr());
+		let prev = CoRegisterMessageFilter(None::<&IMessageFilter>).unwrap().unwrap();
+		assert_eq!(prev.ptr(), filter2.ptr());
+	}
+
+	#[test]
+	fn com_retry_succeeds_after_rejections() {
+		let mut calls = 0;
+		let res = com_retry(3, 0, || {
+			calls += 1;
+			if calls < 3 { Err(co::HRESULT::RPC_E_CALL_REJECTED) } else { Ok(calls) }
+		});
+		assert_eq!(res, Ok(3));
+	}
+
+	#[test]
+	fn com_retry_gives_up() {
+		let mut calls = 0;
+		let res = com_retry(3, 0, || -> HrResult<()> {
+			calls += 1;
+			Err(co::HRESULT::RPC_E_SERVERCALL_RETRYLATER)
+		});
+		assert_eq!(res, Err(co::HRESULT::RPC_E_SERVERCALL_RETRYLATER));
+		assert_eq!(calls, 3);
+	}
+
+	#[test]
+	fn com_retry_propagates_other_errors() {
+		let mut calls = 0;
+		let res = com_retry(3, 0, || -> HrResult<()> {
+			calls += 1;
+			Err(co::HRESULT::E_FAIL)
+		});
+		assert_eq!(res, Err(co::HRESULT::E_FAIL));
+		assert_eq!(calls, 1);
+	}
+}
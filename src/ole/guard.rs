@@ -117,3 +117,63 @@ impl CoUninitializeGuard {
 		self.hr
 	}
 }
+
+//------------------------------------------------------------------------------
+
+/// RAII implementation which automatically calls
+/// [`IConnectionPoint::Unadvise`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-iconnectionpoint-unadvise)
+/// when the object goes out of scope.
+///
+/// The guard keeps its own reference to the connection point, so it can be
+/// stored independently of it.
+pub struct UnadviseGuard<T>
+	where T: ole_IConnectionPoint,
+{
+	com_obj: T,
+	cookie: u32,
+}
+
+impl<T> Drop for UnadviseGuard<T>
+	where T: ole_IConnectionPoint,
+{
+	fn drop(&mut self) {
+		if self.cookie != 0 { // 0 is never a valid cookie
+			let _ = self.com_obj.Unadvise(self.cookie); // ignore errors
+		}
+	}
+}
+
+impl<T> UnadviseGuard<T>
+	where T: ole_IConnectionPoint,
+{
+	/// Constructs the guard by taking ownership of the COM object reference
+	/// and the cookie.
+	///
+	/// # Safety
+	///
+	/// Be sure the cookie has been returned by a previous call to
+	/// [`IConnectionPoint::Advise`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-iconnectionpoint-advise)
+	/// on the same COM object.
+	#[must_use]
+	pub const unsafe fn new(com_obj: T, cookie: u32) -> Self {
+		Self { com_obj, cookie }
+	}
+
+	/// Ejects the cookie, so that
+	/// [`IConnectionPoint::Unadvise`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-iconnectionpoint-unadvise)
+	/// won't be called.
+	///
+	/// Since the internal cookie will be invalidated, the destructor will not
+	/// run. It's your responsibility to run it, otherwise the sink will stay
+	/// connected.
+	#[must_use]
+	pub fn leak(&mut self) -> u32 {
+		std::mem::take(&mut self.cookie)
+	}
+
+	/// Returns the cookie which identifies the connection.
+	#[must_use]
+	pub const fn cookie(&self) -> u32 {
+		self.cookie
+	}
+}
@@ -0,0 +1,93 @@
+use crate::decl::*;
+use crate::kernel::ffi_types::*;
+use crate::ole::privs::*;
+
+/// Generic iterator over COM enumerators – `IEnumXXX` interfaces – whose
+/// `Next` method retrieves one element at a time, returning `None` when
+/// `S_FALSE` is reached. Iteration stops at the first error.
+pub(crate) struct IenumIter<F> {
+	next_one: F,
+	done: bool,
+}
+
+impl<T, F> Iterator for IenumIter<F>
+	where F: FnMut() -> HrResult<Option<T>>,
+{
+	type Item = HrResult<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+
+		match (self.next_one)() {
+			Err(err) => {
+				self.done = true; // no further iterations will be made
+				Some(Err(err))
+			},
+			Ok(None) => {
+				self.done = true;
+				None
+			},
+			Ok(Some(item)) => Some(Ok(item)),
+		}
+	}
+}
+
+impl<T, F> IenumIter<F>
+	where F: FnMut() -> HrResult<Option<T>>,
+{
+	pub(crate) const fn new(next_one: F) -> Self {
+		Self { next_one, done: false }
+	}
+}
+
+/// Calls the `Next` method of a COM enumerator – an `IEnumXXX` interface –
+/// retrieving up to `count` elements in a single call. When the end of the
+/// enumeration is reached, fewer elements – possibly none – are returned.
+///
+/// The buffer is filled with `new_elem`, then `next` is called with the
+/// element count, the buffer and the pointer to the fetched count.
+pub(crate) fn ienum_next_batch<T>(
+	count: u32,
+	new_elem: impl FnMut() -> T,
+	next: impl FnOnce(u32, *mut T, *mut u32) -> HRES,
+) -> HrResult<Vec<T>>
+{
+	let mut elems = std::iter::repeat_with(new_elem)
+		.take(count as _)
+		.collect::<Vec<_>>();
+	let mut fetched = u32::default();
+
+	okfalse_to_hrresult(next(count, elems.as_mut_ptr(), &mut fetched))
+		.map(|_| {
+			elems.truncate(fetched as _); // remaining elements were not written
+			elems
+		})
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::co;
+	use super::*;
+
+	#[test]
+	fn next_batch_returns_short_vec_at_end() {
+		let source = [10, 20, 30];
+		let batch = ienum_next_batch(10, || 0, |count, elems, fetched| {
+			let n = source.len().min(count as _);
+			unsafe {
+				std::ptr::copy_nonoverlapping(source.as_ptr(), elems, n);
+				*fetched = n as _;
+			}
+			if n < count as _ { co::HRESULT::S_FALSE } else { co::HRESULT::S_OK }.raw()
+		}).unwrap();
+		assert_eq!(batch, [10, 20, 30]);
+	}
+
+	#[test]
+	fn next_batch_propagates_errors() {
+		let res = ienum_next_batch(10, || 0, |_, _, _| co::HRESULT::E_FAIL.raw());
+		assert_eq!(res, Err(co::HRESULT::E_FAIL));
+	}
+}
@@ -5,8 +5,10 @@ mod com_interfaces;
 mod funcs;
 mod handles;
 mod structs;
+mod utilities;
 
 pub(in crate::ole) mod ffi;
+pub(crate) mod iterators;
 pub(crate) mod privs;
 pub mod co;
 pub mod guard;
@@ -16,6 +18,7 @@ pub mod decl {
 	pub use super::com_interfaces::decl::*;
 	pub use super::funcs::*;
 	pub use super::structs::*;
+	pub use super::utilities::*;
 }
 
 pub mod traits {
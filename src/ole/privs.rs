@@ -1,10 +1,16 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
 use crate::co;
 use crate::decl::*;
 use crate::kernel::ffi_types::*;
 use crate::prelude::*;
 
 /// Returns a reference to the virtual table of the COM object.
+///
+/// In debug builds, panics if the COM pointer is null, instead of letting the
+/// call crash with an access violation.
 pub(crate) unsafe fn vt<T>(obj: &impl ole_IUnknown) -> &T {
+	debug_assert!(!obj.ptr().is_null(), "Method called on a null COM pointer.");
 	let ppvt = obj.ptr() as *mut *mut T;
 	&**ppvt
 }
@@ -26,3 +32,103 @@ pub(crate) const fn okfalse_to_hrresult(hr: HRES) -> HrResult<bool> {
 		hr => Err(hr),
 	}
 }
+
+//------------------------------------------------------------------------------
+
+/// A COM object implemented in Rust, which forwards the interface methods to
+/// the user-implemented `imp` object.
+///
+/// `IUnknown` is implemented here. Each interface declares a static virtual
+/// table which starts with a [`ComObjUnknownVT`], followed by its own entries,
+/// whose functions retrieve the implementation with [`ComObj::imp`].
+#[repr(C)]
+pub(crate) struct ComObj<I: ?Sized> {
+	vt: *const std::ffi::c_void,
+	refs: AtomicU32,
+	iids: Vec<co::IID>, // answered by QueryInterface, besides IUnknown
+	imp: Box<I>,
+}
+
+impl<I: ?Sized> ComObj<I> {
+	/// Allocates a new object with a reference count of 1, returning its COM
+	/// pointer.
+	pub(crate) fn new_ptr<V>(
+		vt: &'static V,
+		iids: Vec<co::IID>,
+		imp: Box<I>,
+	) -> COMPTR
+	{
+		Box::into_raw(
+			Box::new(
+				Self {
+					vt: vt as *const V as _,
+					refs: AtomicU32::new(1),
+					iids,
+					imp,
+				},
+			),
+		) as _
+	}
+
+	/// Returns the user implementation of the object.
+	///
+	/// # Safety
+	///
+	/// The pointer must have been returned by [`ComObj::new_ptr`], and the
+	/// object must be alive.
+	pub(crate) unsafe fn imp<'a>(p: *mut Self) -> &'a I {
+		&(*p).imp
+	}
+}
+
+/// `IUnknown` entries of the virtual table of a [`ComObj`].
+#[allow(non_snake_case)]
+#[repr(C)]
+pub(crate) struct ComObjUnknownVT<I: ?Sized> {
+	QueryInterface: extern "system" fn(*mut ComObj<I>, PCVOID, *mut COMPTR) -> HRES,
+	AddRef: extern "system" fn(*mut ComObj<I>) -> u32,
+	Release: extern "system" fn(*mut ComObj<I>) -> u32,
+}
+
+impl<I: ?Sized> ComObjUnknownVT<I> {
+	pub(crate) const fn new() -> Self {
+		Self {
+			QueryInterface: com_obj_query_interface::<I>,
+			AddRef: com_obj_add_ref::<I>,
+			Release: com_obj_release::<I>,
+		}
+	}
+}
+
+extern "system" fn com_obj_query_interface<I: ?Sized>(
+	p: *mut ComObj<I>,
+	riid: PCVOID,
+	ppv: *mut COMPTR,
+) -> HRES
+{
+	if ppv.is_null() {
+		return co::HRESULT::E_POINTER.raw();
+	}
+
+	let iid = unsafe { *(riid as *const co::IID) };
+	if iid == IUnknown::IID || unsafe { &*p }.iids.contains(&iid) {
+		com_obj_add_ref(p);
+		unsafe { *ppv = p as _; }
+		co::HRESULT::S_OK.raw()
+	} else {
+		unsafe { *ppv = std::ptr::null_mut(); }
+		co::HRESULT::E_NOINTERFACE.raw()
+	}
+}
+
+extern "system" fn com_obj_add_ref<I: ?Sized>(p: *mut ComObj<I>) -> u32 {
+	unsafe { &*p }.refs.fetch_add(1, Ordering::Relaxed) + 1
+}
+
+extern "system" fn com_obj_release<I: ?Sized>(p: *mut ComObj<I>) -> u32 {
+	let refs = unsafe { &*p }.refs.fetch_sub(1, Ordering::AcqRel) - 1;
+	if refs == 0 {
+		let _ = unsafe { Box::from_raw(p) }; // last reference, free the object
+	}
+	refs
+}
@@ -91,6 +91,74 @@ pub struct FORMATETC<'a> {
 impl_default!(FORMATETC, 'a);
 
 impl<'a> FORMATETC<'a> {
+	/// Creates a new `FORMATETC` for the given clipboard format and storage
+	/// medium, rendering the whole content of the data, with
+	/// [`DVASPECT::CONTENT`](crate::co::DVASPECT::CONTENT) and `lindex` -1.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, co};
+	///
+	/// let fmt = w::FORMATETC::new(co::CF::DIB, co::TYMED::HGLOBAL);
+	///
+	/// let fmt_text = w::FORMATETC::unicode_text(); // same as above, for text
+	/// ```
+	#[must_use]
+	pub fn new(cf: co::CF, tymed: co::TYMED) -> Self {
+		let mut fmt = Self::default();
+		fmt.set_cfFormat(cf);
+		fmt.set_aspect(co::DVASPECT::CONTENT);
+		fmt.lindex = -1;
+		fmt.tymed = tymed;
+		fmt
+	}
+
+	/// Creates a new `FORMATETC` for
+	/// [`CF::BITMAP`](crate::co::CF::BITMAP) in
+	/// [`TYMED::GDI`](crate::co::TYMED::GDI).
+	#[must_use]
+	pub fn bitmap() -> Self {
+		Self::new(co::CF::BITMAP, co::TYMED::GDI)
+	}
+
+	/// Creates a new `FORMATETC` for
+	/// [`CF::HDROP`](crate::co::CF::HDROP) in
+	/// [`TYMED::HGLOBAL`](crate::co::TYMED::HGLOBAL).
+	#[must_use]
+	pub fn hdrop() -> Self {
+		Self::new(co::CF::HDROP, co::TYMED::HGLOBAL)
+	}
+
+	/// Creates a new `FORMATETC` for
+	/// [`CF::TEXT`](crate::co::CF::TEXT) in
+	/// [`TYMED::HGLOBAL`](crate::co::TYMED::HGLOBAL).
+	#[must_use]
+	pub fn text() -> Self {
+		Self::new(co::CF::TEXT, co::TYMED::HGLOBAL)
+	}
+
+	/// Creates a new `FORMATETC` for
+	/// [`CF::UNICODETEXT`](crate::co::CF::UNICODETEXT) in
+	/// [`TYMED::HGLOBAL`](crate::co::TYMED::HGLOBAL).
+	#[must_use]
+	pub fn unicode_text() -> Self {
+		Self::new(co::CF::UNICODETEXT, co::TYMED::HGLOBAL)
+	}
+
+	/// Returns the `dwAspect` field as a
+	/// [`co::DVASPECT`](crate::co::DVASPECT).
+	#[must_use]
+	pub fn aspect(&self) -> co::DVASPECT {
+		unsafe { co::DVASPECT::from_raw(self.dwAspect) }
+	}
+
+	/// Sets the `dwAspect` field from a
+	/// [`co::DVASPECT`](crate::co::DVASPECT).
+	pub fn set_aspect(&mut self, val: co::DVASPECT) {
+		self.dwAspect = val.raw();
+	}
+
 	/// Returns the `cfFormat` field.
 	#[must_use]
 	pub fn cfFormat(&self) -> co::CF {
@@ -141,3 +209,18 @@ impl<'a> MULTI_QI<'a> {
 	pub_fn_ptr_get_set!('a, pIID, set_pIID, co::IID);
 	pub_fn_comptr_get_set!(pItf, set_pItf, ole_IUnknown);
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::co;
+	use crate::decl::*;
+
+	#[test]
+	fn formatetc_unicode_text() {
+		let fmt = FORMATETC::unicode_text();
+		assert_eq!(fmt.cfFormat(), co::CF::UNICODETEXT);
+		assert_eq!(fmt.tymed, co::TYMED::HGLOBAL);
+		assert_eq!(fmt.aspect(), co::DVASPECT::CONTENT);
+		assert_eq!(fmt.lindex, -1);
+	}
+}
@@ -0,0 +1,3 @@
+mod mta_thread;
+
+pub use mta_thread::spawn_mta_thread;
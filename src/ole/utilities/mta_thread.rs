@@ -0,0 +1,38 @@
+use crate::co;
+use crate::decl::*;
+
+/// Spawns a new thread which runs within a
+/// [multithreaded apartment](https://learn.microsoft.com/en-us/windows/win32/com/multithreaded-apartments)
+/// (MTA).
+///
+/// The new thread calls [`CoInitializeEx`](crate::CoInitializeEx) with
+/// [`COINIT::MULTITHREADED`](crate::co::COINIT::MULTITHREADED), runs the given
+/// closure, then uninitializes the COM library. If `CoInitializeEx` fails, the
+/// closure is not called and the error is returned.
+///
+/// Since the COM library is uninitialized when the thread finishes, the
+/// returned value must not hold any COM object.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let handle = w::spawn_mta_thread(|| {
+///     let guid = w::CoCreateGuid()?;
+///     Ok(guid.to_string())
+/// });
+///
+/// let guid_str = handle.join().unwrap()?;
+/// println!("{}", guid_str);
+/// # Ok::<_, winsafe::co::HRESULT>(())
+/// ```
+pub fn spawn_mta_thread<F, T>(func: F) -> std::thread::JoinHandle<HrResult<T>>
+	where F: FnOnce() -> HrResult<T> + Send + 'static,
+		T: Send + 'static,
+{
+	std::thread::spawn(move || {
+		let _com_lib = CoInitializeEx(co::COINIT::MULTITHREADED)?; // keep guard alive
+		func()
+	})
+}
@@ -24,6 +24,9 @@ com_interface! { IDispatch: "00020400-0000-0000-c000-000000000046";
 	/// Automatically calls
 	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
 	/// when the object goes out of scope.
+	///
+	/// To receive events from an automation server, your own event sink must
+	/// be created with [`IDispatch::from_impl`](crate::IDispatch::from_impl).
 }
 
 impl oleaut_IDispatch for IDispatch {}
@@ -96,3 +99,247 @@ pub trait oleaut_IDispatch: ole_IUnknown {
 		).map(|_| queried)
 	}
 }
+
+impl IDispatch {
+	/// Creates a new COM object which implements `IDispatch` by forwarding the
+	/// calls to the given
+	/// [`oleaut_IDispatchImpl`](crate::prelude::oleaut_IDispatchImpl) object.
+	///
+	/// This is mostly used as an event sink for dispinterfaces, passed to
+	/// [`IConnectionPoint::Advise`](crate::prelude::ole_IConnectionPoint::Advise).
+	/// The object has no type information, so
+	/// [`GetTypeInfoCount`](https://learn.microsoft.com/en-us/windows/win32/api/oaidl/nf-oaidl-idispatch-gettypeinfocount)
+	/// returns zero, while
+	/// [`GetTypeInfo`](https://learn.microsoft.com/en-us/windows/win32/api/oaidl/nf-oaidl-idispatch-gettypeinfo)
+	/// and
+	/// [`GetIDsOfNames`](https://learn.microsoft.com/en-us/windows/win32/api/oaidl/nf-oaidl-idispatch-getidsofnames)
+	/// fail with [`E_NOTIMPL`](crate::co::HRESULT::E_NOTIMPL).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// struct MySink {
+	///     events_iid: co::IID,
+	/// }
+	///
+	/// impl oleaut_IDispatchImpl for MySink {
+	///     fn dispinterface_iid(&self) -> Option<co::IID> {
+	///         Some(self.events_iid)
+	///     }
+	///
+	///     fn invoke(&self, dispid: i32, args: &[w::VARIANT]) -> w::HrResult<w::VARIANT> {
+	///         println!("Event {} fired with {} argument(s).", dispid, args.len());
+	///         Ok(w::VARIANT::default())
+	///     }
+	/// }
+	///
+	/// let container: w::IConnectionPointContainer; // initialized somewhere
+	/// # let container = unsafe { w::IConnectionPointContainer::null() };
+	/// let events_iid: co::IID; // IID of the outgoing dispinterface
+	/// # let events_iid = co::IID::default();
+	///
+	/// let sink = w::IDispatch::from_impl(MySink { events_iid });
+	/// let cp = container.FindConnectionPoint(&events_iid)?;
+	/// let _advise = cp.Advise(&sink)?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	pub fn from_impl(obj: impl oleaut_IDispatchImpl + 'static) -> Self {
+		let mut iids = vec![Self::IID];
+		iids.extend(obj.dispinterface_iid()); // required by connection points
+		let pobj = DispatchObj::new_ptr(&DISPATCH_VT, iids, Box::new(obj));
+		unsafe { Self::from_ptr(pobj) }
+	}
+}
+
+/// User-implemented behavior of an [`IDispatch`](crate::IDispatch) object,
+/// created with [`IDispatch::from_impl`](crate::IDispatch::from_impl).
+///
+/// Since the methods receive `&self`, mutable state must be kept in a
+/// [`Cell`](std::cell::Cell) or a [`RefCell`](std::cell::RefCell).
+///
+/// This trait is enabled with the `oleaut` feature.
+pub trait oleaut_IDispatchImpl {
+	/// Returns the IID of the dispinterface being implemented, if any. The
+	/// object will answer
+	/// [`QueryInterface`](crate::prelude::ole_IUnknown::QueryInterface) for
+	/// this IID, besides `IUnknown` and `IDispatch`, which is required by
+	/// connection points. Called once, when the object is created.
+	///
+	/// The default implementation returns `None`.
+	#[must_use]
+	fn dispinterface_iid(&self) -> Option<co::IID> {
+		None
+	}
+
+	/// [`IDispatch::Invoke`](https://learn.microsoft.com/en-us/windows/win32/api/oaidl/nf-oaidl-idispatch-invoke)
+	/// method.
+	///
+	/// Note that, as in
+	/// [`DISPPARAMS`](https://learn.microsoft.com/en-us/windows/win32/api/oaidl/ns-oaidl-dispparams),
+	/// the arguments are in reverse order. The returned value is passed back
+	/// to the caller, if it asked for one.
+	fn invoke(&self, dispid: i32, args: &[VARIANT]) -> HrResult<VARIANT>;
+}
+
+//------------------------------------------------------------------------------
+
+type DispatchObj = ComObj<dyn oleaut_IDispatchImpl>;
+
+#[repr(C)]
+struct DispatchImplVT {
+	IUnknownVT: ComObjUnknownVT<dyn oleaut_IDispatchImpl>,
+	GetTypeInfoCount: extern "system" fn(*mut DispatchObj, *mut u32) -> HRES,
+	GetTypeInfo: extern "system" fn(*mut DispatchObj, u32, u32, *mut COMPTR) -> HRES,
+	GetIDsOfNames: extern "system" fn(*mut DispatchObj, PCVOID, *const PCSTR, u32, u32, PVOID) -> HRES,
+	Invoke: extern "system" fn(*mut DispatchObj, i32, PCVOID, u32, u16, PVOID, PVOID, PVOID, *mut u32) -> HRES,
+}
+
+static DISPATCH_VT: DispatchImplVT = DispatchImplVT {
+	IUnknownVT: ComObjUnknownVT::new(),
+	GetTypeInfoCount: dispatch_get_type_info_count,
+	GetTypeInfo: dispatch_get_type_info,
+	GetIDsOfNames: dispatch_get_ids_of_names,
+	Invoke: dispatch_invoke,
+};
+
+/// [`DISPPARAMS`](https://learn.microsoft.com/en-us/windows/win32/api/oaidl/ns-oaidl-dispparams)
+/// struct, as received by `Invoke`.
+#[repr(C)]
+struct DispParams {
+	rgvarg: *mut VARIANT,
+	rgdispidNamedArgs: *mut i32,
+	cArgs: u32,
+	cNamedArgs: u32,
+}
+
+extern "system" fn dispatch_get_type_info_count(
+	_p: *mut DispatchObj,
+	pctinfo: *mut u32,
+) -> HRES
+{
+	if pctinfo.is_null() {
+		return co::HRESULT::E_POINTER.raw();
+	}
+	unsafe { *pctinfo = 0; } // no type information available
+	co::HRESULT::S_OK.raw()
+}
+
+extern "system" fn dispatch_get_type_info(
+	_p: *mut DispatchObj,
+	_itinfo: u32,
+	_lcid: u32,
+	pptinfo: *mut COMPTR,
+) -> HRES
+{
+	if !pptinfo.is_null() {
+		unsafe { *pptinfo = std::ptr::null_mut(); }
+	}
+	co::HRESULT::E_NOTIMPL.raw()
+}
+
+extern "system" fn dispatch_get_ids_of_names(
+	_p: *mut DispatchObj,
+	_riid: PCVOID,
+	_names: *const PCSTR,
+	_cnames: u32,
+	_lcid: u32,
+	_dispids: PVOID,
+) -> HRES
+{
+	co::HRESULT::E_NOTIMPL.raw()
+}
+
+extern "system" fn dispatch_invoke(
+	p: *mut DispatchObj,
+	dispid: i32,
+	_riid: PCVOID,
+	_lcid: u32,
+	_flags: u16,
+	pdispparams: PVOID,
+	pvarresult: PVOID,
+	_pexcepinfo: PVOID,
+	_puargerr: *mut u32,
+) -> HRES
+{
+	let args = match unsafe { (pdispparams as *const DispParams).as_ref() } {
+		Some(params) if !params.rgvarg.is_null() && params.cArgs > 0 => unsafe {
+			std::slice::from_raw_parts(params.rgvarg as *const VARIANT, params.cArgs as _)
+		},
+		_ => &[],
+	};
+
+	match unsafe { DispatchObj::imp(p) }.invoke(dispid, args) {
+		Ok(ret) => {
+			if !pvarresult.is_null() {
+				unsafe { std::ptr::write(pvarresult as *mut VARIANT, ret); } // ownership goes to the caller
+			}
+			co::HRESULT::S_OK.raw()
+		},
+		Err(hr) => hr.raw(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::cell::RefCell;
+	use std::rc::Rc;
+
+	use crate::co;
+	use crate::decl::*;
+	use crate::ole::privs::*;
+	use crate::prelude::*;
+	use crate::vt::*;
+	use super::DispParams;
+
+	/// Dispid and arguments of each call.
+	type Calls = Rc<RefCell<Vec<(i32, Vec<i32>)>>>;
+
+	struct Recorder {
+		calls: Calls,
+	}
+
+	impl oleaut_IDispatchImpl for Recorder {
+		fn invoke(&self, dispid: i32, args: &[VARIANT]) -> HrResult<VARIANT> {
+			let args = args.iter().map(|arg| arg.i32().unwrap()).collect::<Vec<_>>();
+			let sum = args.iter().sum();
+			self.calls.borrow_mut().push((dispid, args));
+			Ok(VARIANT::new_i32(sum))
+		}
+	}
+
+	#[test]
+	fn invoke_calls_handler() {
+		let calls = Calls::default();
+		let obj = IDispatch::from_impl(Recorder { calls: calls.clone() });
+
+		let mut args = [VARIANT::new_i32(20), VARIANT::new_i32(10)]; // f(10, 20), reversed
+		let mut params = DispParams {
+			rgvarg: args.as_mut_ptr(),
+			rgdispidNamedArgs: std::ptr::null_mut(),
+			cArgs: args.len() as _,
+			cNamedArgs: 0,
+		};
+		let mut result = VARIANT::default();
+
+		let hr = unsafe {
+			(vt::<IDispatchVT>(&obj).Invoke)(
+				obj.ptr(),
+				7,
+				&co::IID::default() as *const _ as _,
+				0,
+				1, // DISPATCH_METHOD
+				&mut params as *mut _ as _,
+				&mut result as *mut _ as _,
+				std::ptr::null_mut(),
+				std::ptr::null_mut(),
+			)
+		};
+
+		assert_eq!(hr, co::HRESULT::S_OK.raw());
+		assert_eq!(*calls.borrow(), [(7, vec![20, 10])]);
+		assert_eq!(result.i32(), Some(30));
+	}
+}
@@ -1,5 +1,7 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
+use std::collections::HashMap;
+
 use crate::co;
 use crate::decl::*;
 use crate::kernel::ffi_types::*;
@@ -70,6 +72,33 @@ pub trait oleaut_IPropertyStore: ole_IUnknown {
 		Ok(Box::new(IpropertystoreIter::new(self)?))
 	}
 
+	/// Reads all the properties at once, by calling
+	/// [`IPropertyStore::GetValue`](crate::prelude::oleaut_IPropertyStore::GetValue)
+	/// for each [`PROPERTYKEY`](crate::PROPERTYKEY) returned by
+	/// [`IPropertyStore::iter`](crate::prelude::oleaut_IPropertyStore::iter).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let pstore: w::IPropertyStore; // initialized somewhere
+	/// # let pstore = unsafe { w::IPropertyStore::null() };
+	///
+	/// let props = pstore.read_all()?;
+	/// println!("{} properties", props.len());
+	/// # Ok::<_, winsafe::co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn read_all(&self) -> HrResult<HashMap<PROPERTYKEY, PROPVARIANT>> {
+		self.iter()?
+			.map(|ppk| {
+				let ppk = ppk?;
+				self.GetValue(&ppk).map(|var| (ppk, var))
+			})
+			.collect()
+	}
+
 	fn_com_noparm! { Commit: IPropertyStoreVT;
 		/// [`IPropertyStore::Commit`](https://learn.microsoft.com/en-us/windows/win32/api/propsys/nf-propsys-ipropertystore-commit)
 		/// method.
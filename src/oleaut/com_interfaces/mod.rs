@@ -9,7 +9,7 @@ pub mod decl {
 }
 
 pub mod traits {
-	pub use super::idispatch::oleaut_IDispatch;
+	pub use super::idispatch::{oleaut_IDispatch, oleaut_IDispatchImpl};
 	pub use super::ipropertystore::oleaut_IPropertyStore;
 	pub use super::itypeinfo::oleaut_ITypeInfo;
 }
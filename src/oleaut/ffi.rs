@@ -9,6 +9,15 @@ extern_sys! { "ole32";
 extern_sys! { "oleaut32";
 	OleLoadPicture(COMPTR, i32, BOOL, PCVOID, *mut COMPTR) -> HRES
 	OleLoadPicturePath(PCSTR, COMPTR, u32, u32, PCVOID, *mut COMPTR) -> HRES
+	SafeArrayAccessData(PVOID, *mut PVOID) -> HRES
+	SafeArrayCreateVector(u16, i32, u32) -> PVOID
+	SafeArrayDestroy(PVOID) -> HRES
+	SafeArrayGetDim(PVOID) -> u32
+	SafeArrayGetElemsize(PVOID) -> u32
+	SafeArrayGetLBound(PVOID, u32, *mut i32) -> HRES
+	SafeArrayGetUBound(PVOID, u32, *mut i32) -> HRES
+	SafeArrayGetVartype(PVOID, *mut u16) -> HRES
+	SafeArrayUnaccessData(PVOID) -> HRES
 	SysAllocString(PCSTR) -> PSTR
 	SysFreeString(PSTR)
 	SysReAllocString(PSTR, PCSTR) -> PSTR
@@ -1,4 +1,8 @@
+use std::marker::PhantomData;
+
 use crate::decl::*;
+use crate::ole::privs::*;
+use crate::oleaut::ffi;
 use crate::prelude::*;
 
 pub(in crate::oleaut) struct IpropertystoreIter<'a, I>
@@ -40,3 +44,66 @@ impl<'a, I> IpropertystoreIter<'a, I>
 		Ok(Self { prop_st, count, current: 0 })
 	}
 }
+
+pub(in crate::oleaut) struct SafearrayIter<'a, T>
+	where T: oleaut_SafeArrayElement,
+{
+	psa: *mut std::ffi::c_void,
+	pdata: *const T,
+	count: usize,
+	current: usize,
+	_owner: PhantomData<&'a ()>,
+}
+
+impl<'a, T> Drop for SafearrayIter<'a, T>
+	where T: oleaut_SafeArrayElement,
+{
+	fn drop(&mut self) {
+		if !self.pdata.is_null() {
+			unsafe { ffi::SafeArrayUnaccessData(self.psa); } // ignore errors
+		}
+	}
+}
+
+impl<'a, T> Iterator for SafearrayIter<'a, T>
+	where T: oleaut_SafeArrayElement,
+{
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.current == self.count {
+			return None;
+		}
+
+		let elem = unsafe { *self.pdata.add(self.current) };
+		self.current += 1;
+		Some(elem)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = self.count - self.current;
+		(remaining, Some(remaining))
+	}
+}
+
+impl<'a, T> SafearrayIter<'a, T>
+	where T: oleaut_SafeArrayElement,
+{
+	pub(in crate::oleaut) fn new(
+		psa: *mut std::ffi::c_void,
+		count: usize,
+	) -> HrResult<Self>
+	{
+		let mut pdata = std::ptr::null_mut();
+		if count > 0 {
+			ok_to_hrresult(unsafe { ffi::SafeArrayAccessData(psa, &mut pdata) })?;
+		}
+		Ok(Self {
+			psa,
+			pdata: pdata as _,
+			count,
+			current: 0,
+			_owner: PhantomData,
+		})
+	}
+}
@@ -4,7 +4,7 @@ use crate::oleaut::privs::*;
 /// [`PROPERTYKEY`](https://learn.microsoft.com/en-us/windows/win32/api/wtypes/ns-wtypes-propertykey)
 /// struct.
 #[repr(C)]
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct PROPERTYKEY {
 	pub fmtid: GUID,
 	pub pid: u32,
@@ -0,0 +1,312 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use std::marker::PhantomData;
+
+use crate::co;
+use crate::decl::*;
+use crate::kernel::ffi_types::*;
+use crate::ole::privs::*;
+use crate::oleaut::{ffi, iterators::*};
+
+/// A one-dimensional
+/// [`SAFEARRAY`](https://learn.microsoft.com/en-us/windows/win32/api/oaidl/ns-oaidl-safearray)
+/// holding elements of type `T`, used with COM automation.
+///
+/// Only the primitive numeric element types are supported, see
+/// [`oleaut_SafeArrayElement`](crate::prelude::oleaut_SafeArrayElement).
+/// Arrays of `BSTR`, `VARIANT` or COM interfaces, whose elements must be
+/// copied and freed individually, are not supported yet; these can still be
+/// handled through the raw pointer returned by
+/// [`as_ptr`](crate::SAFEARRAY::as_ptr).
+///
+/// Automatically calls
+/// [`SafeArrayDestroy`](https://learn.microsoft.com/en-us/windows/win32/api/oleauto/nf-oleauto-safearraydestroy)
+/// when the object goes out of scope.
+///
+/// # Examples
+///
+/// Round-tripping a `Vec<f64>`:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let nums = vec![1.5, 2.5, 3.5];
+/// let arr = w::SAFEARRAY::try_from(nums.as_slice())?;
+///
+/// assert_eq!(arr.len()?, 3);
+/// assert_eq!(arr.to_vec()?, nums);
+///
+/// for num in arr.iter()? {
+///     println!("{}", num);
+/// }
+/// # Ok::<_, w::co::HRESULT>(())
+/// ```
+#[repr(transparent)]
+pub struct SAFEARRAY<T>
+	where T: oleaut_SafeArrayElement,
+{
+	ptr: PVOID,
+	_elem: PhantomData<T>,
+}
+
+impl<T> Drop for SAFEARRAY<T>
+	where T: oleaut_SafeArrayElement,
+{
+	fn drop(&mut self) {
+		if !self.ptr.is_null() {
+			unsafe { ffi::SafeArrayDestroy(self.ptr); } // ignore errors
+		}
+	}
+}
+
+impl<T> TryFrom<&[T]> for SAFEARRAY<T>
+	where T: oleaut_SafeArrayElement,
+{
+	type Error = co::HRESULT;
+
+	fn try_from(elems: &[T]) -> HrResult<Self> {
+		Self::from_slice(elems)
+	}
+}
+
+impl<T> TryFrom<Vec<T>> for SAFEARRAY<T>
+	where T: oleaut_SafeArrayElement,
+{
+	type Error = co::HRESULT;
+
+	fn try_from(elems: Vec<T>) -> HrResult<Self> {
+		Self::from_slice(&elems)
+	}
+}
+
+impl<T> SAFEARRAY<T>
+	where T: oleaut_SafeArrayElement,
+{
+	/// [`SafeArrayCreateVector`](https://learn.microsoft.com/en-us/windows/win32/api/oleauto/nf-oleauto-safearraycreatevector)
+	/// function.
+	///
+	/// Creates a zero-based array with `num_elements` zeroed elements.
+	#[must_use]
+	pub fn SafeArrayCreateVector(num_elements: u32) -> HrResult<Self> {
+		let ptr = unsafe {
+			ffi::SafeArrayCreateVector(T::VT.raw(), 0, num_elements)
+		};
+		if ptr.is_null() {
+			Err(co::HRESULT::E_OUTOFMEMORY)
+		} else {
+			Ok(Self { ptr, _elem: PhantomData })
+		}
+	}
+
+	/// Creates a zero-based array with a copy of the given elements.
+	///
+	/// Arrays can also be created with [`TryFrom`], from a slice or a `Vec`.
+	#[must_use]
+	pub fn from_slice(elems: &[T]) -> HrResult<Self> {
+		let arr = Self::SafeArrayCreateVector(elems.len() as _)?;
+		if !elems.is_empty() {
+			let mut pdata = std::ptr::null_mut();
+			ok_to_hrresult(unsafe { ffi::SafeArrayAccessData(arr.ptr, &mut pdata) })?;
+			unsafe {
+				std::ptr::copy_nonoverlapping(elems.as_ptr(), pdata as *mut T, elems.len());
+			}
+			ok_to_hrresult(unsafe { ffi::SafeArrayUnaccessData(arr.ptr) })?;
+		}
+		Ok(arr)
+	}
+
+	/// Creates a new `SAFEARRAY` by wrapping a pointer.
+	///
+	/// # Safety
+	///
+	/// Be sure the pointer has the correct type and isn't owned by anyone else,
+	/// otherwise you may cause memory access violations.
+	#[must_use]
+	pub const unsafe fn from_ptr(p: *mut std::ffi::c_void) -> Self {
+		Self { ptr: p, _elem: PhantomData }
+	}
+
+	/// Returns the underlying `SAFEARRAY` pointer.
+	#[must_use]
+	pub const fn as_ptr(&self) -> *mut std::ffi::c_void {
+		self.ptr
+	}
+
+	/// Ejects the underlying `SAFEARRAY` pointer leaving a null pointer in its
+	/// place, so that
+	/// [`SafeArrayDestroy`](https://learn.microsoft.com/en-us/windows/win32/api/oleauto/nf-oleauto-safearraydestroy)
+	/// won't be called.
+	///
+	/// Be sure to free the pointer, otherwise, as the name of this method
+	/// implies, you will cause a memory leak.
+	#[must_use]
+	pub fn leak(&mut self) -> *mut std::ffi::c_void {
+		std::mem::replace(&mut self.ptr, std::ptr::null_mut())
+	}
+
+	/// Returns an iterator over copies of the elements, in order.
+	///
+	/// The array data stays locked with
+	/// [`SafeArrayAccessData`](https://learn.microsoft.com/en-us/windows/win32/api/oleauto/nf-oleauto-safearrayaccessdata)
+	/// until the iterator is dropped.
+	///
+	/// Fails with [`DISP_E_TYPEMISMATCH`](crate::co::HRESULT::DISP_E_TYPEMISMATCH)
+	/// if the array is not one-dimensional, or its elements are not of type
+	/// `T`.
+	#[must_use]
+	pub fn iter(&self) -> HrResult<impl Iterator<Item = T> + '_> {
+		let count = self.len()?;
+		SafearrayIter::new(self.ptr, count)
+	}
+
+	/// Returns `true` if the array has no elements.
+	///
+	/// Fails with [`DISP_E_TYPEMISMATCH`](crate::co::HRESULT::DISP_E_TYPEMISMATCH)
+	/// if the array is not one-dimensional, or its elements are not of type
+	/// `T`.
+	#[must_use]
+	pub fn is_empty(&self) -> HrResult<bool> {
+		self.len().map(|count| count == 0)
+	}
+
+	/// Returns the number of elements.
+	///
+	/// Fails with [`DISP_E_TYPEMISMATCH`](crate::co::HRESULT::DISP_E_TYPEMISMATCH)
+	/// if the array is not one-dimensional, or its elements are not of type
+	/// `T`.
+	#[must_use]
+	pub fn len(&self) -> HrResult<usize> {
+		let vt = match self.SafeArrayGetVartype() {
+			Err(co::HRESULT::E_INVALIDARG) => None, // descriptor has no variant type
+			res => Some(res?),
+		};
+		if self.SafeArrayGetDim() != 1
+			|| vt != Some(T::VT)
+			|| self.SafeArrayGetElemsize() as usize != std::mem::size_of::<T>()
+		{
+			return Err(co::HRESULT::DISP_E_TYPEMISMATCH);
+		}
+
+		let lbound = self.SafeArrayGetLBound(1)?;
+		let ubound = self.SafeArrayGetUBound(1)?;
+		Ok((ubound as i64 - lbound as i64 + 1).max(0) as _)
+	}
+
+	/// Returns a copy of the elements, in order.
+	///
+	/// Fails with [`DISP_E_TYPEMISMATCH`](crate::co::HRESULT::DISP_E_TYPEMISMATCH)
+	/// if the array is not one-dimensional, or its elements are not of type
+	/// `T`.
+	#[must_use]
+	pub fn to_vec(&self) -> HrResult<Vec<T>> {
+		Ok(self.iter()?.collect())
+	}
+
+	/// [`SafeArrayGetDim`](https://learn.microsoft.com/en-us/windows/win32/api/oleauto/nf-oleauto-safearraygetdim)
+	/// function.
+	#[must_use]
+	pub fn SafeArrayGetDim(&self) -> u32 {
+		unsafe { ffi::SafeArrayGetDim(self.ptr) }
+	}
+
+	/// [`SafeArrayGetElemsize`](https://learn.microsoft.com/en-us/windows/win32/api/oleauto/nf-oleauto-safearraygetelemsize)
+	/// function.
+	#[must_use]
+	pub fn SafeArrayGetElemsize(&self) -> u32 {
+		unsafe { ffi::SafeArrayGetElemsize(self.ptr) }
+	}
+
+	/// [`SafeArrayGetLBound`](https://learn.microsoft.com/en-us/windows/win32/api/oleauto/nf-oleauto-safearraygetlbound)
+	/// function.
+	///
+	/// Dimensions are one-based.
+	#[must_use]
+	pub fn SafeArrayGetLBound(&self, dim: u32) -> HrResult<i32> {
+		let mut bound = i32::default();
+		ok_to_hrresult(
+			unsafe { ffi::SafeArrayGetLBound(self.ptr, dim, &mut bound) },
+		).map(|_| bound)
+	}
+
+	/// [`SafeArrayGetUBound`](https://learn.microsoft.com/en-us/windows/win32/api/oleauto/nf-oleauto-safearraygetubound)
+	/// function.
+	///
+	/// Dimensions are one-based.
+	#[must_use]
+	pub fn SafeArrayGetUBound(&self, dim: u32) -> HrResult<i32> {
+		let mut bound = i32::default();
+		ok_to_hrresult(
+			unsafe { ffi::SafeArrayGetUBound(self.ptr, dim, &mut bound) },
+		).map(|_| bound)
+	}
+
+	/// [`SafeArrayGetVartype`](https://learn.microsoft.com/en-us/windows/win32/api/oleauto/nf-oleauto-safearraygetvartype)
+	/// function.
+	#[must_use]
+	pub fn SafeArrayGetVartype(&self) -> HrResult<co::VT> {
+		let mut vt = u16::default();
+		ok_to_hrresult(
+			unsafe { ffi::SafeArrayGetVartype(self.ptr, &mut vt) },
+		).map(|_| unsafe { co::VT::from_raw(vt) })
+	}
+}
+
+/// Element type which can be stored in a [`SAFEARRAY`](crate::SAFEARRAY).
+///
+/// This trait is enabled with the `oleaut` feature, and it's implemented for
+/// the primitive integer and floating point types. Elements are copied
+/// bitwise, so types which own resources, like
+/// [`BSTR`](crate::BSTR), can't implement it.
+///
+/// # Safety
+///
+/// The type must have the exact memory layout of the
+/// [`co::VT`](crate::co::VT) it declares, and any bit pattern must be valid.
+pub unsafe trait oleaut_SafeArrayElement: Copy {
+	/// The variant type of the element.
+	const VT: co::VT;
+}
+
+macro_rules! impl_safearray_element {
+	($($ty:ty, $vt:ident;)*) => {
+		$(
+			unsafe impl oleaut_SafeArrayElement for $ty {
+				const VT: co::VT = co::VT::$vt;
+			}
+		)*
+	};
+}
+
+impl_safearray_element! {
+	i8, I1;
+	u8, UI1;
+	i16, I2;
+	u16, UI2;
+	i32, I4;
+	u32, UI4;
+	i64, I8;
+	u64, UI8;
+	f32, R4;
+	f64, R8;
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::decl::*;
+
+	#[test]
+	fn round_trip_vec_f64() {
+		let nums = vec![1.5, -2.5, 3.5, 0.0];
+		let arr = SAFEARRAY::try_from(nums.clone()).unwrap();
+		assert_eq!(arr.len().unwrap(), nums.len());
+		assert_eq!(arr.to_vec().unwrap(), nums);
+		assert_eq!(arr.iter().unwrap().collect::<Vec<_>>(), nums);
+	}
+
+	#[test]
+	fn empty_array() {
+		let arr = SAFEARRAY::<i32>::try_from([].as_slice()).unwrap();
+		assert!(arr.is_empty().unwrap());
+		assert_eq!(arr.to_vec().unwrap(), []);
+	}
+}
@@ -2,6 +2,27 @@
 
 use crate::co::*;
 
+const_ordinary! { AL: u32;
+	/// [`ASSOCIATIONLEVEL`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/ne-shobjidl_core-associationlevel)
+	/// enumeration (`u32`).
+	=>
+	=>
+	MACHINE 0
+	EFFECTIVE 1
+	USER 2
+}
+
+const_ordinary! { AT: u32;
+	/// [`ASSOCIATIONTYPE`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/ne-shobjidl_core-associationtype)
+	/// enumeration (`u32`).
+	=>
+	=>
+	FILEEXTENSION 0
+	URLPROTOCOL 1
+	STARTMENUCLIENT 2
+	MIMETYPE 3
+}
+
 const_ordinary! { FO: u32;
 	/// [`SHFILEOPSTRUCT`](crate::SHFILEOPSTRUCT) `wFunc` (`u32`).
 	=>
@@ -239,6 +260,33 @@ const_bitflag! { NIS: u32;
 	SHAREDICON 0x0000_0002
 }
 
+const_bitflag! { SEE_MASK: u32;
+	/// [`SHELLEXECUTEINFO`](crate::SHELLEXECUTEINFO) `fMask` (`u32`).
+	=>
+	=>
+	DEFAULT 0x0000_0000
+	CLASSNAME 0x0000_0001
+	CLASSKEY 0x0000_0003
+	IDLIST 0x0000_0004
+	INVOKEIDLIST 0x0000_000c
+	HOTKEY 0x0000_0020
+	NOCLOSEPROCESS 0x0000_0040
+	CONNECTNETDRV 0x0000_0080
+	NOASYNC 0x0000_0100
+	FLAG_DDEWAIT Self::NOASYNC.0
+	DOENVSUBST 0x0000_0200
+	FLAG_NO_UI 0x0000_0400
+	UNICODE 0x0000_4000
+	NO_CONSOLE 0x0000_8000
+	ASYNCOK 0x0010_0000
+	HMONITOR 0x0020_0000
+	NOZONECHECKS 0x0080_0000
+	NOQUERYCLASSSTORE 0x0100_0000
+	WAITFORINPUTIDLE 0x0200_0000
+	FLAG_LOG_USAGE 0x0400_0000
+	FLAG_HINST_IS_SITE 0x0800_0000
+}
+
 const_ordinary! { SE_ERR: u32;
 	/// [`HWND::ShellExecute`](crate::prelude::shell_Hwnd::ShellExecute) return
 	/// value (`u32`).
@@ -411,6 +459,36 @@ const_ordinary! { SIGDN: u32;
 	PARENTRELATIVEFORUI 0x8009_4001
 }
 
+const_bitflag! { SIIGBF: u32;
+	/// [`SIIGBF`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellitemimagefactory-getimage)
+	/// enumeration (`u32`).
+	=>
+	=>
+	/// Shrink the bitmap as necessary to fit, preserving its aspect ratio.
+	RESIZETOFIT 0x0000_0000
+	/// Passed by callers if they want to stretch the returned image themselves.
+	BIGGERSIZEOK 0x0000_0001
+	/// Return the item only if it is already in memory.
+	MEMORYONLY 0x0000_0002
+	/// Return only the icon, never the thumbnail.
+	ICONONLY 0x0000_0004
+	/// Return only the thumbnail, never the icon.
+	THUMBNAILONLY 0x0000_0008
+	/// Allows access to the disk, but only to retrieve a cached item.
+	INCACHEONLY 0x0000_0010
+	/// Introduced in Windows 8. If necessary, crop the bitmap to a square.
+	CROPTOSQUARE 0x0000_0020
+	/// Introduced in Windows 8. Stretch and crop the bitmap to a 0.7 aspect
+	/// ratio.
+	WIDETHUMBNAILS 0x0000_0040
+	/// Introduced in Windows 8. If returning an icon, paint a background using
+	/// the associated app's registered background color.
+	ICONBACKGROUND 0x0000_0080
+	/// Introduced in Windows 8. If necessary, stretch the bitmap so that the
+	/// height and width fit the given size.
+	SCALEUP 0x0000_0100
+}
+
 const_ordinary! { SIID: u32;
 	/// [`SHSTOCKICONID`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/ne-shellapi-shstockiconid)
 	/// enumeration, [`SHGetStockIconInfo`](crate::SHGetStockIconInfo) `siid`
@@ -513,6 +591,43 @@ const_ordinary! { SIID: u32;
 	MAX_ICONS 181
 }
 
+const_bitflag! { SLDF: u32;
+	/// [`SHELL_LINK_DATA_FLAGS`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/ne-shlobj_core-shell_link_data_flags)
+	/// enumeration (`u32`).
+	=>
+	=>
+	DEFAULT 0x0000_0000
+	HAS_ID_LIST 0x0000_0001
+	HAS_LINK_INFO 0x0000_0002
+	HAS_NAME 0x0000_0004
+	HAS_RELPATH 0x0000_0008
+	HAS_WORKINGDIR 0x0000_0010
+	HAS_ARGS 0x0000_0020
+	HAS_ICONLOCATION 0x0000_0040
+	UNICODE 0x0000_0080
+	FORCE_NO_LINKINFO 0x0000_0100
+	HAS_EXP_SZ 0x0000_0200
+	RUN_IN_SEPARATE 0x0000_0400
+	HAS_DARWINID 0x0000_1000
+	/// The target is run as a different user, which usually means "Run as
+	/// administrator".
+	RUNAS_USER 0x0000_2000
+	HAS_EXP_ICON_SZ 0x0000_4000
+	NO_PIDL_ALIAS 0x0000_8000
+	FORCE_UNCNAME 0x0001_0000
+	RUN_WITH_SHIMLAYER 0x0002_0000
+	FORCE_NO_LINKTRACK 0x0004_0000
+	ENABLE_TARGET_METADATA 0x0008_0000
+	DISABLE_LINK_PATH_TRACKING 0x0010_0000
+	DISABLE_KNOWNFOLDER_RELATIVE_TRACKING 0x0020_0000
+	NO_KF_ALIAS 0x0040_0000
+	ALLOW_LINK_TO_LINK 0x0080_0000
+	UNALIAS_ON_SAVE 0x0100_0000
+	PREFER_ENVIRONMENT_PATH 0x0200_0000
+	KEEP_LOCAL_IDLIST_FOR_UNC_TARGET 0x0400_0000
+	PERSIST_VOLUME_ID_RELATIVE 0x0800_0000
+}
+
 const_bitflag! { SLGP: u32;
 	/// [`IShellLink::GetPath`](crate::prelude::shell_IShellLink::GetPath)
 	/// `flags` (`u32`).
@@ -592,3 +707,27 @@ const_ordinary! { TBPF: u32;
 	/// generic percentage not indicative of actual progress.
 	PAUSED 0x8
 }
+
+const_bitflag! { THB: u32;
+	/// [`THUMBBUTTONMASK`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/ne-shobjidl_core-thumbbuttonmask)
+	/// enumeration (`u32`).
+	=>
+	=>
+	BITMAP 0x0000_0001
+	ICON 0x0000_0002
+	TOOLTIP 0x0000_0004
+	FLAGS 0x0000_0008
+}
+
+const_bitflag! { THBF: u32;
+	/// [`THUMBBUTTONFLAGS`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/ne-shobjidl_core-thumbbuttonflags)
+	/// enumeration (`u32`).
+	=>
+	=>
+	ENABLED 0x0000_0000
+	DISABLED 0x0000_0001
+	DISMISSONCLICK 0x0000_0002
+	NOBACKGROUND 0x0000_0004
+	HIDDEN 0x0000_0008
+	NONINTERACTIVE 0x0000_0010
+}
@@ -3,7 +3,9 @@
 use crate::co::*;
 
 const_guid_values! { CLSID;
+	ApplicationAssociationRegistration "591209c7-767b-42b2-9fba-44ee4615f2c7"
 	FileOpenDialog "dc1c5a9c-e88a-4dde-a5a1-60f82a20aef7"
+	FileOperation "3ad05575-8857-4850-9277-11b85bdb8e09"
 	FileSaveDialog "c0b4e2f3-ba21-4773-8dba-335ec946eb8b"
 	ShellLink "00021401-0000-0000-c000-000000000046"
 	TaskbarList "56fdf344-fd6d-11d0-958a-006097c9a090"
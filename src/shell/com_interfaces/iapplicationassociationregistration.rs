@@ -0,0 +1,191 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::ffi_types::*;
+use crate::ole::privs::*;
+use crate::prelude::*;
+use crate::vt::*;
+
+/// [`IApplicationAssociationRegistration`](crate::IApplicationAssociationRegistration)
+/// virtual table.
+#[repr(C)]
+pub struct IApplicationAssociationRegistrationVT {
+	pub IUnknownVT: IUnknownVT,
+	pub QueryCurrentDefault: fn(COMPTR, PCSTR, u32, u32, *mut PSTR) -> HRES,
+	pub QueryAppIsDefault: fn(COMPTR, PCSTR, u32, u32, PCSTR, *mut BOOL) -> HRES,
+	pub QueryAppIsDefaultAll: fn(COMPTR, u32, PCSTR, *mut BOOL) -> HRES,
+	pub SetAppAsDefault: fn(COMPTR, PCSTR, PCSTR, u32) -> HRES,
+	pub SetAppAsDefaultAll: fn(COMPTR, PCSTR) -> HRES,
+	pub ClearUserAssociations: fn(COMPTR) -> HRES,
+}
+
+com_interface! { IApplicationAssociationRegistration: "4e530b0a-e611-4c77-a3ac-9031d022281b";
+	/// [`IApplicationAssociationRegistration`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-iapplicationassociationregistration)
+	/// COM interface over
+	/// [`IApplicationAssociationRegistrationVT`](crate::vt::IApplicationAssociationRegistrationVT).
+	///
+	/// Automatically calls
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let reg = w::CoCreateInstance::<w::IApplicationAssociationRegistration>(
+	///     &co::CLSID::ApplicationAssociationRegistration,
+	///     None,
+	///     co::CLSCTX::INPROC_SERVER,
+	/// )?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+}
+
+impl shell_IApplicationAssociationRegistration for IApplicationAssociationRegistration {}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`IApplicationAssociationRegistration`](crate::IApplicationAssociationRegistration).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_IApplicationAssociationRegistration: ole_IUnknown {
+	/// [`IApplicationAssociationRegistration::ClearUserAssociations`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-iapplicationassociationregistration-clearuserassociations)
+	/// method.
+	fn ClearUserAssociations(&self) -> HrResult<()> {
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IApplicationAssociationRegistrationVT>(self).ClearUserAssociations)(
+					self.ptr(),
+				)
+			},
+		)
+	}
+
+	/// [`IApplicationAssociationRegistration::QueryAppIsDefault`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-iapplicationassociationregistration-queryappisdefault)
+	/// method.
+	#[must_use]
+	fn QueryAppIsDefault(&self,
+		query: &str,
+		query_type: co::AT,
+		level: co::AL,
+		app_registry_name: &str,
+	) -> HrResult<bool>
+	{
+		let mut is_default: BOOL = 0;
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IApplicationAssociationRegistrationVT>(self).QueryAppIsDefault)(
+					self.ptr(),
+					WString::from_str(query).as_ptr(),
+					query_type.raw(),
+					level.raw(),
+					WString::from_str(app_registry_name).as_ptr(),
+					&mut is_default,
+				)
+			},
+		).map(|_| is_default != 0)
+	}
+
+	/// [`IApplicationAssociationRegistration::QueryAppIsDefaultAll`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-iapplicationassociationregistration-queryappisdefaultall)
+	/// method.
+	#[must_use]
+	fn QueryAppIsDefaultAll(&self,
+		level: co::AL,
+		app_registry_name: &str,
+	) -> HrResult<bool>
+	{
+		let mut is_default: BOOL = 0;
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IApplicationAssociationRegistrationVT>(self).QueryAppIsDefaultAll)(
+					self.ptr(),
+					level.raw(),
+					WString::from_str(app_registry_name).as_ptr(),
+					&mut is_default,
+				)
+			},
+		).map(|_| is_default != 0)
+	}
+
+	/// [`IApplicationAssociationRegistration::QueryCurrentDefault`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-iapplicationassociationregistration-querycurrentdefault)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// Retrieving the ProgID of the default handler for `.txt` files:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let reg: w::IApplicationAssociationRegistration; // initialized somewhere
+	/// # let reg = unsafe { w::IApplicationAssociationRegistration::null() };
+	///
+	/// let prog_id = reg.QueryCurrentDefault(
+	///     ".txt", co::AT::FILEEXTENSION, co::AL::EFFECTIVE)?;
+	/// println!("{}", prog_id);
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn QueryCurrentDefault(&self,
+		query: &str,
+		query_type: co::AT,
+		level: co::AL,
+	) -> HrResult<String>
+	{
+		let mut pstr = std::ptr::null_mut::<u16>();
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IApplicationAssociationRegistrationVT>(self).QueryCurrentDefault)(
+					self.ptr(),
+					WString::from_str(query).as_ptr(),
+					query_type.raw(),
+					level.raw(),
+					&mut pstr,
+				)
+			},
+		).map(|_| {
+			let name = WString::from_wchars_nullt(pstr);
+			let _ = unsafe { CoTaskMemFreeGuard::new(pstr as _, 0) };
+			name.to_string()
+		})
+	}
+
+	/// [`IApplicationAssociationRegistration::SetAppAsDefault`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-iapplicationassociationregistration-setappasdefault)
+	/// method.
+	fn SetAppAsDefault(&self,
+		app_registry_name: &str,
+		set: &str,
+		set_type: co::AT,
+	) -> HrResult<()>
+	{
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IApplicationAssociationRegistrationVT>(self).SetAppAsDefault)(
+					self.ptr(),
+					WString::from_str(app_registry_name).as_ptr(),
+					WString::from_str(set).as_ptr(),
+					set_type.raw(),
+				)
+			},
+		)
+	}
+
+	/// [`IApplicationAssociationRegistration::SetAppAsDefaultAll`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-iapplicationassociationregistration-setappasdefaultall)
+	/// method.
+	fn SetAppAsDefaultAll(&self, app_registry_name: &str) -> HrResult<()> {
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IApplicationAssociationRegistrationVT>(self).SetAppAsDefaultAll)(
+					self.ptr(),
+					WString::from_str(app_registry_name).as_ptr(),
+				)
+			},
+		)
+	}
+}
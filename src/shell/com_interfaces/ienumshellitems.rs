@@ -3,9 +3,9 @@
 use crate::co;
 use crate::decl::*;
 use crate::kernel::ffi_types::*;
+use crate::ole::iterators::*;
 use crate::ole::privs::*;
 use crate::prelude::*;
-use crate::shell::iterators::*;
 use crate::vt::*;
 
 /// [`IEnumShellItems`](crate::IEnumShellItems) virtual table.
@@ -69,7 +69,23 @@ pub trait shell_IEnumShellItems: ole_IUnknown {
 	/// ```
 	#[must_use]
 	fn iter(&self) -> Box<dyn Iterator<Item = HrResult<IShellItem>> + '_> {
-		Box::new(IenumshellitemsIter::new(self))
+		Box::new(IenumIter::new(move || self.Next()))
+	}
+
+	/// [`IEnumShellItems::Next`](crate::prelude::shell_IEnumShellItems::Next)
+	/// method, which retrieves up to `count` elements in a single call.
+	///
+	/// When the end of the enumeration is reached, fewer elements – possibly
+	/// none – are returned.
+	#[must_use]
+	fn next_batch(&self, count: u32) -> HrResult<Vec<IShellItem>> {
+		ienum_next_batch(
+			count,
+			|| unsafe { IShellItem::null() },
+			|count, elems, fetched| unsafe {
+				(vt::<IEnumShellItemsVT>(self).Next)(self.ptr(), count, elems as _, fetched)
+			},
+		)
 	}
 
 	/// [`IEnumShellItems::Next`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ienumshellitems-next)
@@ -0,0 +1,155 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::kernel::ffi_types::*;
+use crate::ole::privs::*;
+use crate::prelude::*;
+use crate::vt::*;
+
+/// [`IInitializeWithItem`](crate::IInitializeWithItem) virtual table.
+#[repr(C)]
+pub struct IInitializeWithItemVT {
+	pub IUnknownVT: IUnknownVT,
+	pub Initialize: fn(COMPTR, COMPTR, u32) -> HRES,
+}
+
+com_interface! { IInitializeWithItem: "7f73be3f-fb79-493c-a6c7-7ee14e245841";
+	/// [`IInitializeWithItem`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-iinitializewithitem)
+	/// COM interface over
+	/// [`IInitializeWithItemVT`](crate::vt::IInitializeWithItemVT).
+	///
+	/// Automatically calls
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// To implement a handler, create your own object with
+	/// [`IInitializeWithItem::from_impl`](crate::IInitializeWithItem::from_impl).
+}
+
+impl shell_IInitializeWithItem for IInitializeWithItem {}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`IInitializeWithItem`](crate::IInitializeWithItem).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_IInitializeWithItem: ole_IUnknown {
+	/// [`IInitializeWithItem::Initialize`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-iinitializewithitem-initialize)
+	/// method.
+	fn Initialize(&self,
+		item: &impl shell_IShellItem,
+		mode: co::STGM,
+	) -> HrResult<()>
+	{
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IInitializeWithItemVT>(self).Initialize)(
+					self.ptr(),
+					item.ptr(),
+					mode.raw(),
+				)
+			},
+		)
+	}
+}
+
+impl IInitializeWithItem {
+	/// Creates a new COM object which implements `IInitializeWithItem` by
+	/// forwarding the calls to the given
+	/// [`shell_IInitializeWithItemImpl`](crate::prelude::shell_IInitializeWithItemImpl)
+	/// object.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use std::cell::RefCell;
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// #[derive(Default)]
+	/// struct MyHandler {
+	///     item: RefCell<Option<w::IShellItem>>,
+	/// }
+	///
+	/// impl shell_IInitializeWithItemImpl for MyHandler {
+	///     fn initialize(&self,
+	///         item: &w::IShellItem,
+	///         _mode: co::STGM,
+	///     ) -> w::HrResult<()>
+	///     {
+	///         // Keep a reference, since the caller owns the object.
+	///         *self.item.borrow_mut() = Some(item.clone());
+	///         Ok(())
+	///     }
+	/// }
+	///
+	/// let handler = w::IInitializeWithItem::from_impl(MyHandler::default());
+	/// let item = w::SHCreateItemFromParsingName::<w::IShellItem>(
+	///     "C:\\Temp\\foo.txt", None::<&w::IBindCtx>)?;
+	/// handler.Initialize(&item, co::STGM::READ)?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	pub fn from_impl(obj: impl shell_IInitializeWithItemImpl + 'static) -> Self {
+		let pobj = InitializeWithItemObj::new_ptr(
+			&INITIALIZE_WITH_ITEM_VT,
+			vec![Self::IID],
+			Box::new(obj),
+		);
+		unsafe { Self::from_ptr(pobj) }
+	}
+}
+
+/// User-implemented behavior of an [`IInitializeWithItem`](crate::IInitializeWithItem)
+/// object, created with
+/// [`IInitializeWithItem::from_impl`](crate::IInitializeWithItem::from_impl).
+///
+/// This trait is enabled with the `shell` feature.
+pub trait shell_IInitializeWithItemImpl {
+	/// [`IInitializeWithItem::Initialize`](crate::prelude::shell_IInitializeWithItem::Initialize)
+	/// method.
+	///
+	/// The [`IShellItem`](crate::IShellItem) is owned by the caller; clone it to
+	/// keep a reference.
+	fn initialize(&self, item: &IShellItem, mode: co::STGM) -> HrResult<()>;
+}
+
+//------------------------------------------------------------------------------
+
+type InitializeWithItemObj = ComObj<dyn shell_IInitializeWithItemImpl>;
+
+#[repr(C)]
+struct InitializeWithItemImplVT {
+	IUnknownVT: ComObjUnknownVT<dyn shell_IInitializeWithItemImpl>,
+	Initialize: extern "system" fn(*mut InitializeWithItemObj, COMPTR, u32) -> HRES,
+}
+
+static INITIALIZE_WITH_ITEM_VT: InitializeWithItemImplVT = InitializeWithItemImplVT {
+	IUnknownVT: ComObjUnknownVT::new(),
+	Initialize: initialize_with_item_initialize,
+};
+
+extern "system" fn initialize_with_item_initialize(
+	p: *mut InitializeWithItemObj,
+	item: COMPTR,
+	mode: u32,
+) -> HRES
+{
+	if item.is_null() {
+		return co::HRESULT::E_INVALIDARG.raw();
+	}
+
+	let item = std::mem::ManuallyDrop::new( // won't release the caller's pointer
+		unsafe { IShellItem::from_ptr(item) },
+	);
+	match unsafe { InitializeWithItemObj::imp(p) }.initialize(
+		&item,
+		unsafe { co::STGM::from_raw(mode) },
+	) {
+		Ok(_) => co::HRESULT::S_OK.raw(),
+		Err(hr) => hr.raw(),
+	}
+}
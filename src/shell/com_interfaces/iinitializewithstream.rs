@@ -0,0 +1,201 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::kernel::ffi_types::*;
+use crate::ole::privs::*;
+use crate::prelude::*;
+use crate::vt::*;
+
+/// [`IInitializeWithStream`](crate::IInitializeWithStream) virtual table.
+#[repr(C)]
+pub struct IInitializeWithStreamVT {
+	pub IUnknownVT: IUnknownVT,
+	pub Initialize: fn(COMPTR, COMPTR, u32) -> HRES,
+}
+
+com_interface! { IInitializeWithStream: "b824b49d-22ac-4161-ac8a-9916e8fa3f7f";
+	/// [`IInitializeWithStream`](https://learn.microsoft.com/en-us/windows/win32/api/propsys/nn-propsys-iinitializewithstream)
+	/// COM interface over
+	/// [`IInitializeWithStreamVT`](crate::vt::IInitializeWithStreamVT).
+	///
+	/// Automatically calls
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// To implement a handler, create your own object with
+	/// [`IInitializeWithStream::from_impl`](crate::IInitializeWithStream::from_impl).
+}
+
+impl shell_IInitializeWithStream for IInitializeWithStream {}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`IInitializeWithStream`](crate::IInitializeWithStream).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_IInitializeWithStream: ole_IUnknown {
+	/// [`IInitializeWithStream::Initialize`](https://learn.microsoft.com/en-us/windows/win32/api/propsys/nf-propsys-iinitializewithstream-initialize)
+	/// method.
+	fn Initialize(&self,
+		stream: &impl ole_IStream,
+		mode: co::STGM,
+	) -> HrResult<()>
+	{
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IInitializeWithStreamVT>(self).Initialize)(
+					self.ptr(),
+					stream.ptr(),
+					mode.raw(),
+				)
+			},
+		)
+	}
+}
+
+impl IInitializeWithStream {
+	/// Creates a new COM object which implements `IInitializeWithStream` by
+	/// forwarding the calls to the given
+	/// [`shell_IInitializeWithStreamImpl`](crate::prelude::shell_IInitializeWithStreamImpl)
+	/// object.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use std::cell::RefCell;
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// #[derive(Default)]
+	/// struct MyHandler {
+	///     stream: RefCell<Option<w::IStream>>,
+	/// }
+	///
+	/// impl shell_IInitializeWithStreamImpl for MyHandler {
+	///     fn initialize(&self,
+	///         stream: &w::IStream,
+	///         _mode: co::STGM,
+	///     ) -> w::HrResult<()>
+	///     {
+	///         // Keep a reference, since the caller owns the object.
+	///         *self.stream.borrow_mut() = Some(stream.clone());
+	///         Ok(())
+	///     }
+	/// }
+	///
+	/// let handler = w::IInitializeWithStream::from_impl(MyHandler::default());
+	/// let stream = w::SHCreateMemStream(&[])?;
+	/// handler.Initialize(&stream, co::STGM::READ)?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	pub fn from_impl(obj: impl shell_IInitializeWithStreamImpl + 'static) -> Self {
+		let pobj = InitializeWithStreamObj::new_ptr(
+			&INITIALIZE_WITH_STREAM_VT,
+			vec![Self::IID],
+			Box::new(obj),
+		);
+		unsafe { Self::from_ptr(pobj) }
+	}
+}
+
+/// User-implemented behavior of an [`IInitializeWithStream`](crate::IInitializeWithStream)
+/// object, created with
+/// [`IInitializeWithStream::from_impl`](crate::IInitializeWithStream::from_impl).
+///
+/// This trait is enabled with the `shell` feature.
+pub trait shell_IInitializeWithStreamImpl {
+	/// [`IInitializeWithStream::Initialize`](crate::prelude::shell_IInitializeWithStream::Initialize)
+	/// method.
+	///
+	/// The [`IStream`](crate::IStream) is owned by the caller; clone it to
+	/// keep a reference.
+	fn initialize(&self, stream: &IStream, mode: co::STGM) -> HrResult<()>;
+}
+
+//------------------------------------------------------------------------------
+
+type InitializeWithStreamObj = ComObj<dyn shell_IInitializeWithStreamImpl>;
+
+#[repr(C)]
+struct InitializeWithStreamImplVT {
+	IUnknownVT: ComObjUnknownVT<dyn shell_IInitializeWithStreamImpl>,
+	Initialize: extern "system" fn(*mut InitializeWithStreamObj, COMPTR, u32) -> HRES,
+}
+
+static INITIALIZE_WITH_STREAM_VT: InitializeWithStreamImplVT = InitializeWithStreamImplVT {
+	IUnknownVT: ComObjUnknownVT::new(),
+	Initialize: initialize_with_stream_initialize,
+};
+
+extern "system" fn initialize_with_stream_initialize(
+	p: *mut InitializeWithStreamObj,
+	stream: COMPTR,
+	mode: u32,
+) -> HRES
+{
+	if stream.is_null() {
+		return co::HRESULT::E_INVALIDARG.raw();
+	}
+
+	let stream = std::mem::ManuallyDrop::new( // won't release the caller's pointer
+		unsafe { IStream::from_ptr(stream) },
+	);
+	match unsafe { InitializeWithStreamObj::imp(p) }.initialize(
+		&stream,
+		unsafe { co::STGM::from_raw(mode) },
+	) {
+		Ok(_) => co::HRESULT::S_OK.raw(),
+		Err(hr) => hr.raw(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::cell::RefCell;
+	use std::rc::Rc;
+
+	use crate::co;
+	use crate::decl::*;
+	use crate::prelude::*;
+
+	/// Stream contents and mode received by `Initialize`.
+	type Received = Rc<RefCell<Option<(Vec<u8>, co::STGM)>>>;
+
+	struct Recorder {
+		received: Received,
+	}
+
+	impl shell_IInitializeWithStreamImpl for Recorder {
+		fn initialize(&self, stream: &IStream, mode: co::STGM) -> HrResult<()> {
+			let mut buf = [0u8; 16];
+			let read = stream.Read(&mut buf)?;
+			*self.received.borrow_mut() = Some((buf[..read as usize].to_vec(), mode));
+			Ok(())
+		}
+	}
+
+	#[test]
+	fn query_interface_and_initialize() {
+		let received = Received::default();
+		let obj = IInitializeWithStream::from_impl(
+			Recorder { received: received.clone() });
+
+		let unk = obj.QueryInterface::<IUnknown>().unwrap();
+		let handler = unk.QueryInterface::<IInitializeWithStream>().unwrap();
+		assert_eq!(
+			unk.QueryInterface::<IStream>().err(),
+			Some(co::HRESULT::E_NOINTERFACE),
+		);
+
+		let stream = SHCreateMemStream(&[1, 2, 3]).unwrap();
+		handler.Initialize(&stream, co::STGM::READ).unwrap();
+		assert_eq!(
+			*received.borrow(),
+			Some((vec![1, 2, 3], co::STGM::READ)),
+		);
+	}
+}
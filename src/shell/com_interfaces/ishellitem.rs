@@ -55,6 +55,46 @@ impl shell_IShellItem for IShellItem {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait shell_IShellItem: ole_IUnknown {
+	/// Retrieves the most common display names of the item, by calling
+	/// [`IShellItem::GetDisplayName`](crate::prelude::shell_IShellItem::GetDisplayName)
+	/// once for each form.
+	///
+	/// Items outside the file system, which don't support
+	/// [`SIGDN::FILESYSPATH`](crate::co::SIGDN::FILESYSPATH), will have `None`
+	/// as the file system path. This is detected by the
+	/// [`E_INVALIDARG`](crate::co::HRESULT::E_INVALIDARG) or
+	/// [`E_NOTIMPL`](crate::co::HRESULT::E_NOTIMPL) errors; any other error is
+	/// returned.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let shi = w::SHCreateItemFromParsingName::<w::IShellItem>(
+	///     "C:\\Temp\\foo.txt",
+	///     None::<&w::IBindCtx>,
+	/// )?;
+	///
+	/// let names = shi.display_names()?;
+	/// println!("{} - {}", names.normal,
+	///     names.file_system_path.unwrap_or_default());
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn display_names(&self) -> HrResult<DisplayNames> {
+		let file_system_path = match self.GetDisplayName(co::SIGDN::FILESYSPATH) {
+			Ok(path) => Some(path),
+			Err(co::HRESULT::E_INVALIDARG | co::HRESULT::E_NOTIMPL) => None, // virtual item
+			Err(e) => return Err(e),
+		};
+		Ok(DisplayNames {
+			file_system_path,
+			normal: self.GetDisplayName(co::SIGDN::NORMALDISPLAY)?,
+			parent_relative: self.GetDisplayName(co::SIGDN::PARENTRELATIVE)?,
+		})
+	}
+
 	/// [`IShellItem::BindToHandler`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellitem-bindtohandler)
 	/// method.
 	///
@@ -74,6 +114,27 @@ pub trait shell_IShellItem: ole_IUnknown {
 	/// )?;
 	/// # Ok::<_, co::HRESULT>(())
 	/// ```
+	///
+	/// Reading the first bytes of an item through an
+	/// [`IStream`](crate::IStream), which also works for items outside the file
+	/// system:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let sh_item: w::IShellItem; // initialized somewhere
+	/// # let sh_item = unsafe { w::IShellItem::null() };
+	///
+	/// let stream = sh_item.BindToHandler::<w::IStream>(
+	///     None::<&w::IBindCtx>,
+	///     &co::BHID::Stream,
+	/// )?;
+	///
+	/// let mut buf = [0u8; 16];
+	/// let num_read = stream.Read(&mut buf)?;
+	/// println!("{:?}", &buf[..num_read as usize]);
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
 	#[must_use]
 	fn BindToHandler<T>(&self,
 		bind_ctx: Option<&impl ole_IBindCtx>,
@@ -194,3 +255,29 @@ pub trait shell_IShellItem: ole_IUnknown {
 		/// ```
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::co;
+	use crate::decl::*;
+	use crate::prelude::*;
+
+	#[test]
+	fn display_names_of_file_system_and_virtual_items() {
+		let _com = CoInitializeEx(
+			co::COINIT::APARTMENTTHREADED | co::COINIT::DISABLE_OLE1DDE).unwrap();
+
+		let path = std::env::temp_dir();
+		let shi = SHCreateItemFromParsingName::<IShellItem>(
+			path.to_str().unwrap(), None::<&IBindCtx>).unwrap();
+		let names = shi.display_names().unwrap();
+		assert!(!names.file_system_path.unwrap().is_empty());
+		assert!(!names.normal.is_empty());
+
+		let recycle_bin = SHCreateItemFromParsingName::<IShellItem>(
+			"::{645FF040-5081-101B-9F08-00AA002F954E}", None::<&IBindCtx>).unwrap();
+		let names = recycle_bin.display_names().unwrap();
+		assert_eq!(names.file_system_path, None);
+		assert!(!names.normal.is_empty());
+	}
+}
@@ -1,10 +1,11 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
 use crate::decl::*;
+use crate::guard::*;
 use crate::kernel::ffi_types::*;
 use crate::ole::privs::*;
 use crate::prelude::*;
-use crate::shell::iterators::*;
+use crate::shell::{ffi, iterators::*};
 use crate::vt::*;
 
 /// [`IShellItemArray`](crate::IShellItemArray) virtual table.
@@ -89,6 +90,52 @@ pub trait shell_IShellItemArray: ole_IUnknown {
 		Ok(Box::new(IshellitemarrayIter::new(self)?))
 	}
 
+	/// Creates a new [`IShellItemArray`](crate::IShellItemArray) holding the
+	/// given items, by calling
+	/// [`SHGetIDListFromObject`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-shgetidlistfromobject)
+	/// on each item, then
+	/// [`SHCreateShellItemArrayFromIDLists`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-shcreateshellitemarrayfromidlists).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let item1 = w::SHCreateItemFromParsingName::<w::IShellItem>(
+	///     "C:\\Temp\\foo.txt", None::<&w::IBindCtx>)?;
+	/// let item2 = w::SHCreateItemFromParsingName::<w::IShellItem>(
+	///     "C:\\Temp\\bar.txt", None::<&w::IBindCtx>)?;
+	///
+	/// let ish_arr = w::IShellItemArray::from_items(&[&item1, &item2])?;
+	/// assert_eq!(ish_arr.GetCount()?, 2);
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn from_items(items: &[&IShellItem]) -> HrResult<IShellItemArray> {
+		let pidls = items.iter()
+			.map(|item| {
+				let mut pidl = std::ptr::null_mut();
+				ok_to_hrresult(
+					unsafe { ffi::SHGetIDListFromObject(item.ptr(), &mut pidl) },
+				).map(|_| unsafe { CoTaskMemFreeGuard::new(pidl, 0) })
+			})
+			.collect::<HrResult<Vec<_>>>()?;
+		let pidl_ptrs = pidls.iter()
+			.map(|pidl| pidl.as_ptr())
+			.collect::<Vec<_>>();
+
+		let mut queried = unsafe { IShellItemArray::null() };
+		ok_to_hrresult(
+			unsafe {
+				ffi::SHCreateShellItemArrayFromIDLists(
+					pidl_ptrs.len() as _,
+					pidl_ptrs.as_ptr(),
+					queried.as_mut(),
+				)
+			},
+		).map(|_| queried)
+	}
+
 	/// [`IShellItemArray::GetCount`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellitemarray-getcount)
 	/// method.
 	#[must_use]
@@ -120,3 +167,26 @@ pub trait shell_IShellItemArray: ole_IUnknown {
 		).map(|_| queried)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::co;
+	use crate::decl::*;
+	use crate::prelude::*;
+
+	#[test]
+	fn from_two_items() {
+		let _com = CoInitializeEx(
+			co::COINIT::APARTMENTTHREADED | co::COINIT::DISABLE_OLE1DDE).unwrap();
+
+		let path1 = std::env::temp_dir();
+		let path2 = std::env::current_dir().unwrap();
+		let item1 = SHCreateItemFromParsingName::<IShellItem>(
+			path1.to_str().unwrap(), None::<&IBindCtx>).unwrap();
+		let item2 = SHCreateItemFromParsingName::<IShellItem>(
+			path2.to_str().unwrap(), None::<&IBindCtx>).unwrap();
+
+		let ish_arr = IShellItemArray::from_items(&[&item1, &item2]).unwrap();
+		assert_eq!(ish_arr.GetCount().unwrap(), 2);
+	}
+}
@@ -0,0 +1,47 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::ffi_types::*;
+use crate::prelude::*;
+use crate::vt::*;
+
+/// [`IShellItemImageFactory`](crate::IShellItemImageFactory) virtual table.
+#[repr(C)]
+pub struct IShellItemImageFactoryVT {
+	pub IUnknownVT: IUnknownVT,
+	pub GetImage: fn(COMPTR, u64, u32, *mut HANDLE) -> HRES,
+}
+
+com_interface! { IShellItemImageFactory: "bcc18b79-ba16-442f-80c4-8a59c30c463b";
+	/// [`IShellItemImageFactory`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-ishellitemimagefactory)
+	/// COM interface over
+	/// [`IShellItemImageFactoryVT`](crate::vt::IShellItemImageFactoryVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let shi = w::SHCreateItemFromParsingName::<w::IShellItem>(
+	///     "C:\\Temp\\foo.jpg",
+	///     None::<&w::IBindCtx>,
+	/// )?;
+	/// let factory = shi.QueryInterface::<w::IShellItemImageFactory>()?;
+	/// # Ok::<_, winsafe::co::HRESULT>(())
+	/// ```
+}
+
+impl shell_IShellItemImageFactory for IShellItemImageFactory {}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`IShellItemImageFactory`](crate::IShellItemImageFactory).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_IShellItemImageFactory: ole_IUnknown {}
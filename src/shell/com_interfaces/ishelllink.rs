@@ -65,6 +65,36 @@ impl shell_IShellLink for IShellLink {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait shell_IShellLink: ole_IUnknown {
+	/// Sets or clears the "Run as administrator" option of the shortcut, by
+	/// toggling the [`SLDF::RUNAS_USER`](crate::co::SLDF::RUNAS_USER) flag
+	/// through [`IShellLinkDataList`](crate::IShellLinkDataList).
+	///
+	/// The change is written to disk only when the shortcut is saved through its
+	/// `IPersistFile` interface.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let link: w::IShellLink; // initialized somewhere
+	/// # let link = unsafe { w::IShellLink::null() };
+	///
+	/// link.set_run_as_admin(true)?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	fn set_run_as_admin(&self, admin: bool) -> HrResult<()> {
+		let data_list = self.QueryInterface::<IShellLinkDataList>()?;
+		let flags = data_list.GetFlags()?;
+		data_list.SetFlags(
+			if admin {
+				flags | co::SLDF::RUNAS_USER
+			} else {
+				flags & !co::SLDF::RUNAS_USER
+			},
+		)
+	}
+
 	/// [`IShellLink::GetArguments`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishelllinkw-getarguments)
 	/// method.
 	#[must_use]
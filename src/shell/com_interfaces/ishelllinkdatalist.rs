@@ -0,0 +1,137 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::ffi_types::*;
+use crate::ole::privs::*;
+use crate::prelude::*;
+use crate::vt::*;
+
+/// [`IShellLinkDataList`](crate::IShellLinkDataList) virtual table.
+#[repr(C)]
+pub struct IShellLinkDataListVT {
+	pub IUnknownVT: IUnknownVT,
+	pub AddDataBlock: fn(COMPTR, PVOID) -> HRES,
+	pub CopyDataBlock: fn(COMPTR, u32, *mut PVOID) -> HRES,
+	pub RemoveDataBlock: fn(COMPTR, u32) -> HRES,
+	pub GetFlags: fn(COMPTR, *mut u32) -> HRES,
+	pub SetFlags: fn(COMPTR, u32) -> HRES,
+}
+
+com_interface! { IShellLinkDataList: "45e2b4ae-b1c3-11d0-b92f-00a0c90312e1";
+	/// [`IShellLinkDataList`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-ishelllinkdatalist)
+	/// COM interface over
+	/// [`IShellLinkDataListVT`](crate::vt::IShellLinkDataListVT).
+	///
+	/// Automatically calls
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let link = w::CoCreateInstance::<w::IShellLink>(
+	///     &co::CLSID::ShellLink,
+	///     None,
+	///     co::CLSCTX::INPROC_SERVER,
+	/// )?;
+	/// let data_list = link.QueryInterface::<w::IShellLinkDataList>()?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+}
+
+impl shell_IShellLinkDataList for IShellLinkDataList {}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`IShellLinkDataList`](crate::IShellLinkDataList).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_IShellLinkDataList: ole_IUnknown {
+	/// [`IShellLinkDataList::AddDataBlock`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishelllinkdatalist-adddatablock)
+	/// method.
+	///
+	/// The `data_block` must start with a
+	/// [`DATABLOCK_HEADER`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/ns-shlobj_core-datablock_header),
+	/// whose `cbSize` matches the slice length, otherwise the method fails
+	/// with [`E_INVALIDARG`](crate::co::HRESULT::E_INVALIDARG).
+	fn AddDataBlock(&self, data_block: &[u8]) -> HrResult<()> {
+		const HEADER_LEN: usize = 8; // cbSize, dwSignature
+
+		if data_block.len() < HEADER_LEN
+			|| u32::from_ne_bytes(data_block[..4].try_into().unwrap()) as usize != data_block.len()
+		{
+			return Err(co::HRESULT::E_INVALIDARG);
+		}
+
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IShellLinkDataListVT>(self).AddDataBlock)(
+					self.ptr(),
+					data_block.as_ptr() as _,
+				)
+			},
+		)
+	}
+
+	/// [`IShellLinkDataList::CopyDataBlock`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishelllinkdatalist-copydatablock)
+	/// method.
+	///
+	/// The returned memory block starts with a
+	/// [`DATABLOCK_HEADER`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/ns-shlobj_core-datablock_header),
+	/// and it's automatically freed by the guard.
+	#[must_use]
+	fn CopyDataBlock(&self, signature: u32) -> HrResult<LocalFreeGuard> {
+		let mut pdata = std::ptr::null_mut();
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IShellLinkDataListVT>(self).CopyDataBlock)(
+					self.ptr(),
+					signature,
+					&mut pdata,
+				)
+			},
+		).map(|_| unsafe { LocalFreeGuard::new(HLOCAL::from_ptr(pdata)) })
+	}
+
+	/// [`IShellLinkDataList::GetFlags`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishelllinkdatalist-getflags)
+	/// method.
+	#[must_use]
+	fn GetFlags(&self) -> HrResult<co::SLDF> {
+		let mut flags = u32::default();
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IShellLinkDataListVT>(self).GetFlags)(self.ptr(), &mut flags)
+			},
+		).map(|_| unsafe { co::SLDF::from_raw(flags) })
+	}
+
+	/// [`IShellLinkDataList::RemoveDataBlock`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishelllinkdatalist-removedatablock)
+	/// method.
+	fn RemoveDataBlock(&self, signature: u32) -> HrResult<()> {
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IShellLinkDataListVT>(self).RemoveDataBlock)(
+					self.ptr(),
+					signature,
+				)
+			},
+		)
+	}
+
+	/// [`IShellLinkDataList::SetFlags`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishelllinkdatalist-setflags)
+	/// method.
+	fn SetFlags(&self, flags: co::SLDF) -> HrResult<()> {
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IShellLinkDataListVT>(self).SetFlags)(self.ptr(), flags.raw())
+			},
+		)
+	}
+}
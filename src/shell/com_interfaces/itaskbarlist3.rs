@@ -76,6 +76,8 @@ pub trait shell_ITaskbarList3: shell_ITaskbarList2 {
 
 	/// [`ITaskbarList3::SetOverlayIcon`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-itaskbarlist3-setoverlayicon)
 	/// method.
+	///
+	/// Passing `None` as `hicon` removes the current overlay.
 	fn SetOverlayIcon(&self,
 		hwnd: &HWND,
 		hicon: Option<&HICON>,
@@ -183,13 +185,16 @@ pub trait shell_ITaskbarList3: shell_ITaskbarList2 {
 
 	/// [`ITaskbarList3::SetThumbnailClip`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-itaskbarlist3-setthumbnailclip)
 	/// method.
+	///
+	/// Passing `None` restores the default thumbnail, which shows the whole
+	/// window.
 	fn SetThumbnailClip(&self, hwnd: &HWND, clip: Option<RECT>) -> HrResult<()> {
 		ok_to_hrresult(
 			unsafe {
 				(vt::<ITaskbarList3VT>(self).SetThumbnailClip)(
 					self.ptr(),
 					hwnd.ptr(),
-					&clip as *const _ as _,
+					clip.as_ref().map_or(std::ptr::null_mut(), |rc| rc as *const _ as _),
 				)
 			},
 		)
@@ -202,12 +207,79 @@ pub trait shell_ITaskbarList3: shell_ITaskbarList2 {
 		tip: Option<&str>,
 	) -> HrResult<()>
 	{
+		let wtip = tip.map(WString::from_str);
 		ok_to_hrresult(
 			unsafe {
 				(vt::<ITaskbarList3VT>(self).SetThumbnailTooltip)(
 					self.ptr(),
 					hwnd.ptr(),
-					tip.map_or(std::ptr::null_mut(), |s| WString::from_str(s).as_ptr()),
+					wtip.as_ref().map_or(std::ptr::null(), |ws| ws.as_ptr()),
+				)
+			},
+		)
+	}
+
+	/// [`ITaskbarList3::ThumbBarAddButtons`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-itaskbarlist3-thumbbaraddbuttons)
+	/// method.
+	///
+	/// Buttons can't be removed after being added, only hidden with
+	/// [`ThumbBarUpdateButtons`](crate::prelude::shell_ITaskbarList3::ThumbBarUpdateButtons).
+	/// When clicked, the window receives a
+	/// [`wm::Command`](crate::msg::wm::Command) message with the button ID.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let taskbar: w::ITaskbarList3; // initialized somewhere
+	/// # let taskbar = unsafe { w::ITaskbarList3::null() };
+	/// let hwnd: w::HWND;
+	/// # let hwnd = w::HWND::NULL;
+	/// let hicon: w::HICON;
+	/// # let hicon = w::HICON::NULL;
+	///
+	/// let mut btn = w::THUMBBUTTON::default();
+	/// btn.dwMask = co::THB::ICON | co::THB::TOOLTIP | co::THB::FLAGS;
+	/// btn.iId = 1001;
+	/// btn.hIcon = unsafe { hicon.raw_copy() };
+	/// btn.set_szTip("Play");
+	/// btn.dwFlags = co::THBF::ENABLED;
+	///
+	/// taskbar.ThumbBarAddButtons(&hwnd, &[btn])?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	fn ThumbBarAddButtons(&self,
+		hwnd: &HWND,
+		buttons: &[THUMBBUTTON],
+	) -> HrResult<()>
+	{
+		ok_to_hrresult(
+			unsafe {
+				(vt::<ITaskbarList3VT>(self).ThumbBarAddButtons)(
+					self.ptr(),
+					hwnd.ptr(),
+					buttons.len() as _,
+					buttons.as_ptr() as _,
+				)
+			},
+		)
+	}
+
+	/// [`ITaskbarList3::ThumbBarUpdateButtons`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-itaskbarlist3-thumbbarupdatebuttons)
+	/// method.
+	fn ThumbBarUpdateButtons(&self,
+		hwnd: &HWND,
+		buttons: &[THUMBBUTTON],
+	) -> HrResult<()>
+	{
+		ok_to_hrresult(
+			unsafe {
+				(vt::<ITaskbarList3VT>(self).ThumbBarUpdateButtons)(
+					self.ptr(),
+					hwnd.ptr(),
+					buttons.len() as _,
+					buttons.as_ptr() as _,
 				)
 			},
 		)
@@ -8,11 +8,15 @@ extern_sys! { "shell32";
 	DragQueryPoint(HANDLE, PVOID) -> BOOL
 	SHAddToRecentDocs(u32, PCVOID)
 	SHCreateItemFromParsingName(PCSTR, PVOID, PCVOID, *mut COMPTR) -> HRES
+	SHCreateShellItemArrayFromIDLists(u32, *const PCVOID, *mut COMPTR) -> HRES
+	SHCreateShellItemArrayFromShellItem(COMPTR, PCVOID, *mut COMPTR) -> HRES
 	Shell_NotifyIconW(u32, PVOID) -> BOOL
 	ShellAboutW(HANDLE, PCSTR, PCSTR, HANDLE) -> i32
+	ShellExecuteExW(PVOID) -> BOOL
 	ShellExecuteW(HANDLE, PCSTR, PCSTR, PCSTR, PCSTR, i32) -> HANDLE
 	SHFileOperationW(PVOID) -> i32
 	SHGetFileInfoW(PCSTR, u32, PVOID, u32, u32) -> usize
+	SHGetIDListFromObject(COMPTR, *mut PVOID) -> HRES
 	SHGetKnownFolderPath(PCVOID, u32, HANDLE, *mut PSTR) -> HRES
 	SHGetStockIconInfo(u32, u32, PVOID) -> HRES
 }
@@ -151,6 +151,37 @@ pub unsafe fn SHAddToRecentDocs<T>(flags: co::SHARD, pv: &T) {
 
 /// [`Shell_NotifyIcon`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-shell_notifyiconw)
 /// function.
+///
+/// # Examples
+///
+/// Adding a tray icon, showing a balloon tip, then removing the icon:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// # let hwnd = w::HWND::NULL;
+///
+/// let hicon = w::HINSTANCE::NULL.LoadIcon(w::IdIdiStr::Idi(co::IDI::INFORMATION))?;
+///
+/// let mut nid = w::NOTIFYICONDATA::default();
+/// nid.hWnd = unsafe { hwnd.raw_copy() };
+/// nid.uID = 1;
+/// nid.uFlags = co::NIF::ICON | co::NIF::TIP | co::NIF::MESSAGE;
+/// nid.uCallbackMessage = unsafe { co::WM::from_raw(co::WM::APP.raw() + 1) };
+/// nid.hIcon = unsafe { hicon.raw_copy() };
+/// nid.set_szTip("My application");
+/// w::Shell_NotifyIcon(co::NIM::ADD, &mut nid)?;
+///
+/// nid.uFlags = co::NIF::INFO;
+/// nid.set_szInfoTitle("Hello");
+/// nid.set_szInfo("This is a balloon tip.");
+/// nid.dwInfoFlags = co::NIIF::INFO;
+/// w::Shell_NotifyIcon(co::NIM::MODIFY, &mut nid)?;
+///
+/// w::Shell_NotifyIcon(co::NIM::DELETE, &mut nid)?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
 pub fn Shell_NotifyIcon(
 	message: co::NIM,
 	data: &mut NOTIFYICONDATA,
@@ -221,6 +252,133 @@ pub fn SHCreateMemStream(src: &[u8]) -> HrResult<IStream> {
 	}
 }
 
+/// [`SHCreateShellItemArrayFromShellItem`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-shcreateshellitemarrayfromshellitem)
+/// function.
+///
+/// To create an array with several items, use
+/// [`IShellItemArray::from_items`](crate::prelude::shell_IShellItemArray::from_items).
+#[must_use]
+pub fn SHCreateShellItemArrayFromShellItem(
+	item: &impl shell_IShellItem,
+) -> HrResult<IShellItemArray>
+{
+	let mut queried = unsafe { IShellItemArray::null() };
+	ok_to_hrresult(
+		unsafe {
+			ffi::SHCreateShellItemArrayFromShellItem(
+				item.ptr(),
+				&IShellItemArray::IID as *const _ as _,
+				queried.as_mut(),
+			)
+		},
+	).map(|_| queried)
+}
+
+/// Simplified interface to [`ShellExecuteEx`](crate::ShellExecuteEx), for
+/// the cases where the handle of the launched process is not needed.
+///
+/// If `verb` is `None`, the default verb is used – usually `"open"`.
+///
+/// # Examples
+///
+/// Opening a text file with its associated program:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// w::ShellExecute(
+///     None,
+///     "C:\\Temp\\notes.txt",
+///     None,
+///     None,
+///     co::SW::SHOWNORMAL,
+/// )?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub fn ShellExecute(
+	verb: Option<&str>,
+	file: &str,
+	parameters: Option<&str>,
+	directory: Option<&str>,
+	show_cmd: co::SW,
+) -> SysResult<()>
+{
+	let mut wverb = WString::from_opt_str(verb);
+	let mut wfile = WString::from_str(file);
+	let mut wparams = WString::from_opt_str(parameters);
+	let mut wdir = WString::from_opt_str(directory);
+
+	let mut sei = SHELLEXECUTEINFO::default();
+	sei.fMask = co::SEE_MASK::NOASYNC;
+	sei.set_lpVerb(verb.map(|_| &mut wverb));
+	sei.set_lpFile(Some(&mut wfile));
+	sei.set_lpParameters(parameters.map(|_| &mut wparams));
+	sei.set_lpDirectory(directory.map(|_| &mut wdir));
+	sei.nShow = show_cmd;
+
+	ShellExecuteEx(&mut sei).map(|_| ())
+}
+
+/// [`ShellExecuteEx`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-shellexecuteexw)
+/// function.
+///
+/// If [`SEE_MASK::NOCLOSEPROCESS`](crate::co::SEE_MASK::NOCLOSEPROCESS) is set
+/// and a process was launched, returns its handle.
+///
+/// # Examples
+///
+/// Opening an URL in the default browser:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let mut verb = w::WString::from_str("open");
+/// let mut file = w::WString::from_str("https://github.com/rodrigocfd/winsafe");
+///
+/// let mut sei = w::SHELLEXECUTEINFO::default();
+/// sei.set_lpVerb(Some(&mut verb));
+/// sei.set_lpFile(Some(&mut file));
+/// sei.nShow = co::SW::SHOWNORMAL;
+///
+/// w::ShellExecuteEx(&mut sei)?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+///
+/// Running a program elevated, and waiting for it to finish:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let mut verb = w::WString::from_str("runas");
+/// let mut file = w::WString::from_str("C:\\Temp\\setup.exe");
+///
+/// let mut sei = w::SHELLEXECUTEINFO::default();
+/// sei.fMask = co::SEE_MASK::NOCLOSEPROCESS;
+/// sei.set_lpVerb(Some(&mut verb));
+/// sei.set_lpFile(Some(&mut file));
+/// sei.nShow = co::SW::SHOWNORMAL;
+///
+/// if let Some(hprocess) = w::ShellExecuteEx(&mut sei)? {
+///     hprocess.WaitForSingleObject(None)?;
+/// }
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub fn ShellExecuteEx(
+	exec_info: &mut SHELLEXECUTEINFO,
+) -> SysResult<Option<CloseHandleGuard<HPROCESS>>>
+{
+	bool_to_sysresult(
+		unsafe { ffi::ShellExecuteExW(exec_info as *mut _ as _) },
+	).map(|_| {
+		let hprocess = std::mem::replace(&mut exec_info.hProcess, HPROCESS::NULL);
+		if hprocess == HPROCESS::NULL {
+			None
+		} else {
+			Some(unsafe { CloseHandleGuard::new(hprocess) })
+		}
+	})
+}
+
 /// [`SHFileOperation`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-shfileoperationw)
 /// function.
 pub fn SHFileOperation(file_op: &mut SHFILEOPSTRUCT) -> SysResult<()> {
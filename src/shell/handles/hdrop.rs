@@ -1,5 +1,6 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
+use crate::co;
 use crate::decl::*;
 use crate::prelude::*;
 use crate::shell::{ffi, iterators::*};
@@ -20,6 +21,72 @@ impl shell_Hdrop for HDROP {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait shell_Hdrop: Handle {
+	/// Returns the paths of all dropped files, by calling
+	/// [`DragQueryFile`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-dragqueryfilew)
+	/// for each one of them.
+	///
+	/// Unlike
+	/// [`HDROP::DragQueryFile`](crate::prelude::shell_Hdrop::DragQueryFile),
+	/// this method does not call
+	/// [`DragFinish`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-dragfinish),
+	/// so it can be used with a `CF_HDROP` handle owned by someone else, like
+	/// the clipboard or an [`IDataObject`](crate::IDataObject). When handling
+	/// [`WM_DROPFILES`](crate::msg::wm::DropFiles), prefer
+	/// [`HDROP::DragQueryFile`](crate::prelude::shell_Hdrop::DragQueryFile),
+	/// which releases the handle.
+	///
+	/// Since `DragQueryFile` doesn't always set the last error, a failure with
+	/// no error code is reported as
+	/// [`ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hdrop: w::HDROP; // initialized somewhere
+	/// # let hdrop = w::HDROP::NULL;
+	///
+	/// for file_path in hdrop.files()? {
+	///     println!("File: {}", file_path);
+	/// }
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn files(&self) -> SysResult<Vec<String>> {
+		let count = unsafe {
+			ffi::DragQueryFileW(self.ptr(), 0xffff_ffff, std::ptr::null_mut(), 0)
+		};
+
+		let query_err = || match GetLastError() {
+			co::ERROR::SUCCESS => co::ERROR::INVALID_DATA, // last error not always set
+			err => err,
+		};
+
+		(0..count)
+			.map(|index| {
+				let len = match unsafe {
+					ffi::DragQueryFileW(self.ptr(), index, std::ptr::null_mut(), 0)
+				} {
+					0 => return Err(query_err()), // a path is never empty
+					len => len,
+				};
+				let mut buf = WString::new_alloc_buf(len as usize + 1); // room for terminating null
+				match unsafe {
+					ffi::DragQueryFileW(
+						self.ptr(),
+						index,
+						buf.as_mut_ptr(),
+						buf.buf_len() as _,
+					)
+				} {
+					0 => Err(query_err()),
+					_ => Ok(buf.to_string()),
+				}
+			})
+			.collect()
+	}
+
 	/// [`DragQueryFile`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-dragqueryfilew)
 	/// function.
 	///
@@ -82,3 +149,32 @@ pub trait shell_Hdrop: Handle {
 		(pt, client_area != 0)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::co;
+	use crate::decl::*;
+	use crate::prelude::*;
+
+	#[test]
+	fn files_of_constructed_hdrop() {
+		let paths = ["C:\\Temp\\foo.txt", "C:\\Temp\\bar.txt"];
+
+		let mut data = Vec::<u8>::default();
+		data.extend_from_slice(&20u32.to_ne_bytes()); // DROPFILES::pFiles
+		data.extend_from_slice(&[0; 12]); // pt and fNC
+		data.extend_from_slice(&1i32.to_ne_bytes()); // fWide
+		for path in paths.iter() {
+			for ch in path.encode_utf16().chain([0]) {
+				data.extend_from_slice(&ch.to_ne_bytes());
+			}
+		}
+		data.extend_from_slice(&[0, 0]); // terminating null of the list
+
+		let hglobal = HGLOBAL::GlobalAlloc(Some(co::GMEM::MOVEABLE), data.len()).unwrap();
+		hglobal.GlobalLock().unwrap().as_mut_slice()[..data.len()].copy_from_slice(&data);
+
+		let hdrop = unsafe { HDROP::from_ptr(hglobal.ptr()) }; // memory owned by hglobal
+		assert_eq!(hdrop.files().unwrap(), paths);
+	}
+}
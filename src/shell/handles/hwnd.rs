@@ -51,6 +51,9 @@ pub trait shell_Hwnd: ole_Hwnd {
 
 	/// [`ShellExecute`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-shellexecutew)
 	/// function.
+	///
+	/// If you don't need an owner window, consider using the simpler
+	/// [`ShellExecute`](crate::ShellExecute) free function.
 	fn ShellExecute(&self,
 		operation: &str,
 		file: &str,
@@ -64,8 +67,8 @@ pub trait shell_Hwnd: ole_Hwnd {
 				self.ptr(),
 				WString::from_str(operation).as_ptr(),
 				WString::from_str(file).as_ptr(),
-				parameters.map_or(std::ptr::null(), |lp| WString::from_str(lp).as_ptr()),
-				directory.map_or(std::ptr::null(), |lp| WString::from_str(lp).as_ptr()),
+				WString::from_opt_str(parameters).as_ptr(),
+				WString::from_opt_str(directory).as_ptr(),
 				show_cmd.raw(),
 			)
 		};
@@ -74,35 +74,6 @@ impl<'a, H> HdropIter<'a, H>
 
 //------------------------------------------------------------------------------
 
-pub(in crate::shell) struct IenumshellitemsIter<'a, I>
-	where I: shell_IEnumShellItems,
-{
-	enum_shi: &'a I,
-}
-
-impl<'a, I> Iterator for IenumshellitemsIter<'a, I>
-	where I: shell_IEnumShellItems,
-{
-	type Item = HrResult<IShellItem>;
-
-	fn next(&mut self) -> Option<Self::Item> {
-		match self.enum_shi.Next() {
-			Err(err) => Some(Err(err)),
-			Ok(maybe_item) => maybe_item.map(|item| Ok(item)),
-		}
-	}
-}
-
-impl<'a, I> IenumshellitemsIter<'a, I>
-	where I: shell_IEnumShellItems,
-{
-	pub(in crate::shell) fn new(enum_shi: &'a I) -> Self {
-		Self { enum_shi }
-	}
-}
-
-//------------------------------------------------------------------------------
-
 pub(in crate::shell) struct IshellitemarrayIter<'a, I>
 	where I: shell_IShellItemArray,
 {
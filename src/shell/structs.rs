@@ -5,6 +5,7 @@ use std::marker::PhantomData;
 use crate::co;
 use crate::decl::*;
 use crate::kernel::{ffi_types::*, privs::*};
+use crate::prelude::*;
 
 /// [`COMDLG_FILTERSPEC`](https://learn.microsoft.com/en-us/windows/win32/api/shtypes/ns-shtypes-comdlg_filterspec)
 /// struct.
@@ -53,6 +54,59 @@ impl NOTIFYICONDATA {
 	pub_fn_string_arr_get_set!(szInfoTitle, set_szInfoTitle);
 }
 
+/// [`SHELLEXECUTEINFO`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/ns-shellapi-shellexecuteinfow)
+/// struct.
+///
+/// The process handle, returned when
+/// [`SEE_MASK::NOCLOSEPROCESS`](crate::co::SEE_MASK::NOCLOSEPROCESS) is set, is
+/// retrieved through the return value of
+/// [`ShellExecuteEx`](crate::ShellExecuteEx).
+#[repr(C)]
+pub struct SHELLEXECUTEINFO<'a, 'b, 'c, 'd, 'e> {
+	cbSize: u32,
+	pub fMask: co::SEE_MASK,
+	pub hwnd: HWND,
+	lpVerb: *mut u16,
+	lpFile: *mut u16,
+	lpParameters: *mut u16,
+	lpDirectory: *mut u16,
+	pub nShow: co::SW,
+	pub hInstApp: HINSTANCE,
+	lpIDList: *mut std::ffi::c_void,
+	lpClass: *mut u16,
+	pub hkeyClass: HKEY,
+	pub dwHotKey: u32,
+	hIconOrMonitor: HANDLE, // union with hMonitor
+	pub(in crate::shell) hProcess: HPROCESS,
+
+	_lpVerb: PhantomData<&'a mut u16>,
+	_lpFile: PhantomData<&'b mut u16>,
+	_lpParameters: PhantomData<&'c mut u16>,
+	_lpDirectory: PhantomData<&'d mut u16>,
+	_lpClass: PhantomData<&'e mut u16>,
+}
+
+impl_default_with_size!(SHELLEXECUTEINFO, cbSize, 'a, 'b, 'c, 'd, 'e);
+
+impl<'a, 'b, 'c, 'd, 'e> SHELLEXECUTEINFO<'a, 'b, 'c, 'd, 'e> {
+	pub_fn_string_ptr_get_set!('a, lpVerb, set_lpVerb);
+	pub_fn_string_ptr_get_set!('b, lpFile, set_lpFile);
+	pub_fn_string_ptr_get_set!('c, lpParameters, set_lpParameters);
+	pub_fn_string_ptr_get_set!('d, lpDirectory, set_lpDirectory);
+	pub_fn_string_ptr_get_set!('e, lpClass, set_lpClass);
+
+	/// Returns the `hMonitor` field.
+	#[must_use]
+	pub fn hMonitor(&self) -> HMONITOR {
+		unsafe { HMONITOR::from_ptr(self.hIconOrMonitor) }
+	}
+
+	/// Sets the `hMonitor` field.
+	pub fn set_hMonitor(&mut self, hmon: &HMONITOR) {
+		self.hIconOrMonitor = hmon.ptr();
+	}
+}
+
 /// [`SHFILEINFO`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/ns-shellapi-shfileinfow)
 /// struct.
 #[repr(C)]
@@ -143,3 +197,21 @@ impl_default_with_size!(SHSTOCKICONINFO, cbSize);
 impl SHSTOCKICONINFO {
 	pub_fn_string_arr_get_set!(szPath, get_szPath);
 }
+
+/// [`THUMBBUTTON`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/ns-shobjidl_core-thumbbutton)
+/// struct.
+#[repr(C)]
+pub struct THUMBBUTTON {
+	pub dwMask: co::THB,
+	pub iId: u32,
+	pub iBitmap: u32,
+	pub hIcon: HICON,
+	szTip: [u16; 260],
+	pub dwFlags: co::THBF,
+}
+
+impl_default!(THUMBBUTTON);
+
+impl THUMBBUTTON {
+	pub_fn_string_arr_get_set!(szTip, set_szTip);
+}
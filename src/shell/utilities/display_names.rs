@@ -0,0 +1,15 @@
+/// Display names of an [`IShellItem`](crate::IShellItem), returned by
+/// [`IShellItem::display_names`](crate::prelude::shell_IShellItem::display_names).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DisplayNames {
+	/// The file system path, retrieved with
+	/// [`SIGDN::FILESYSPATH`](crate::co::SIGDN::FILESYSPATH). It's `None` for
+	/// virtual items, like the Recycle Bin.
+	pub file_system_path: Option<String>,
+	/// The name as displayed to the user, retrieved with
+	/// [`SIGDN::NORMALDISPLAY`](crate::co::SIGDN::NORMALDISPLAY).
+	pub normal: String,
+	/// The name relative to the parent folder, retrieved with
+	/// [`SIGDN::PARENTRELATIVE`](crate::co::SIGDN::PARENTRELATIVE).
+	pub parent_relative: String,
+}
@@ -0,0 +1,3 @@
+mod display_names;
+
+pub use display_names::DisplayNames;
@@ -12,6 +12,17 @@ pub type CCHOOKPROC =
 		lParam: isize,
 	) -> usize;
 
+/// Type alias to
+/// [`CFHOOKPROC`](https://learn.microsoft.com/en-us/windows/win32/api/commdlg/nc-commdlg-lpcfhookproc)
+/// callback function.
+pub type CFHOOKPROC =
+	extern "system" fn(
+		hWnd: HWND,
+		uMsg: u32,
+		wParam: usize,
+		lParam: isize,
+	) -> usize;
+
 /// Type alias to
 /// [`DLGPROC`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nc-winuser-dlgproc)
 /// callback function.
@@ -44,6 +55,17 @@ pub type HOOKPROC =
 		lParam: isize,
 	) -> isize;
 
+/// Type alias to
+/// [`OFNHOOKPROC`](https://learn.microsoft.com/en-us/windows/win32/api/commdlg/nc-commdlg-lpofnhookproc)
+/// callback function.
+pub type OFNHOOKPROC =
+	extern "system" fn(
+		hWnd: HWND,
+		uMsg: u32,
+		wParam: usize,
+		lParam: isize,
+	) -> usize;
+
 /// Type alias to
 /// [`TIMERPROC`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nc-winuser-timerproc)
 /// callback function.
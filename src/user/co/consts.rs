@@ -79,6 +79,22 @@ const_ordinary! { APPCOMMAND: u16;
 	DWM_FLIP3D 54
 }
 
+const_bitflag! { AW: u32;
+	/// [`HWND::AnimateWindow`](crate::prelude::user_Hwnd::AnimateWindow)
+	/// `flags` (`u32`).
+	=>
+	=>
+	ACTIVATE 0x0002_0000
+	BLEND 0x0008_0000
+	CENTER 0x0000_0010
+	HIDE 0x0001_0000
+	HOR_POSITIVE 0x0000_0001
+	HOR_NEGATIVE 0x0000_0002
+	SLIDE 0x0004_0000
+	VER_POSITIVE 0x0000_0004
+	VER_NEGATIVE 0x0000_0008
+}
+
 const_wm! { BM;
 	/// Button control
 	/// [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-button-control-reference-messages)
@@ -1997,6 +2013,19 @@ const_bitflag! { MNS: u32;
 	CHECKORBMP 0x0400_0000
 }
 
+const_bitflag! { MOD: u16;
+	/// [`HWND::RegisterHotKey`](crate::prelude::user_Hwnd::RegisterHotKey)
+	/// `modifiers` and [`wm::HotKey`](crate::msg::wm::HotKey) `modifiers`
+	/// (`u16`).
+	=>
+	=>
+	ALT 0x0001
+	CONTROL 0x0002
+	SHIFT 0x0004
+	WIN 0x0008
+	NOREPEAT 0x4000
+}
+
 const_ordinary! { MONITOR: u32;
 	/// [`HMONITOR::MonitorFromPoint`](crate::prelude::user_Hmonitor::MonitorFromPoint),
 	/// [`HMONITOR::MonitorFromRect`](crate::prelude::user_Hmonitor::MonitorFromRect),
@@ -2173,6 +2202,93 @@ const_ordinary! { ODT_C: u32;
 	COMBOBOX ODT::COMBOBOX.0
 }
 
+const_bitflag! { OFN: u32;
+	/// [`OPENFILENAME`](crate::OPENFILENAME) `Flags` (`u32`).
+	=>
+	=>
+	/// Causes the Read Only check box to be selected initially when the dialog
+	/// box is created.
+	READONLY 0x0000_0001
+	/// Causes the Save As dialog box to generate a message box if the selected
+	/// file already exists.
+	OVERWRITEPROMPT 0x0000_0002
+	/// Hides the Read Only check box.
+	HIDEREADONLY 0x0000_0004
+	/// Restores the current directory to its original value if the user
+	/// changed the directory while searching for files.
+	NOCHANGEDIR 0x0000_0008
+	/// Causes the dialog box to display the Help button.
+	SHOWHELP 0x0000_0010
+	/// Enables the hook function specified in the `lpfnHook` member.
+	ENABLEHOOK 0x0000_0020
+	/// The `lpTemplateName` member is a pointer to the name of a dialog
+	/// template resource in the module identified by the `hInstance` member.
+	ENABLETEMPLATE 0x0000_0040
+	/// The `hInstance` member identifies a data block that contains a preloaded
+	/// dialog box template.
+	ENABLETEMPLATEHANDLE 0x0000_0080
+	/// The common dialog boxes allow invalid characters in the returned file
+	/// name.
+	NOVALIDATE 0x0000_0100
+	/// The File Name list box allows multiple selections.
+	ALLOWMULTISELECT 0x0000_0200
+	/// The user typed a file name extension that differs from the extension
+	/// specified by `lpstrDefExt`.
+	EXTENSIONDIFFERENT 0x0000_0400
+	/// The user can type only valid paths and file names.
+	PATHMUSTEXIST 0x0000_0800
+	/// The user can type only names of existing files in the File Name entry
+	/// field.
+	FILEMUSTEXIST 0x0000_1000
+	/// If the user specifies a file that does not exist, this flag causes the
+	/// dialog box to prompt the user for permission to create the file.
+	CREATEPROMPT 0x0000_2000
+	/// Specifies that if a call to the `OpenFile` function fails because of a
+	/// network sharing violation, the error is ignored and the dialog box
+	/// returns the selected file name.
+	SHAREAWARE 0x0000_4000
+	/// The returned file does not have the Read Only check box selected and is
+	/// not in a write-protected directory.
+	NOREADONLYRETURN 0x0000_8000
+	/// The file is not created before the dialog box is closed.
+	NOTESTFILECREATE 0x0001_0000
+	/// Hides and disables the Network button.
+	NONETWORKBUTTON 0x0002_0000
+	/// For old-style dialog boxes, this flag causes the dialog box to use short
+	/// file names (8.3 format).
+	NOLONGNAMES 0x0004_0000
+	/// Indicates that any customizations made to the Open or Save As dialog
+	/// box use the Explorer-style customization methods.
+	EXPLORER 0x0008_0000
+	/// Directs the dialog box to return the path and file name of the selected
+	/// shortcut (.LNK) file, instead of the file referenced by the shortcut.
+	NODEREFERENCELINKS 0x0010_0000
+	/// For old-style dialog boxes, this flag causes the dialog box to use long
+	/// file names.
+	LONGNAMES 0x0020_0000
+	/// Causes the dialog box to send `CDN_INCLUDEITEM` notification messages
+	/// to your `OFNHookProc` hook procedure when the user opens a folder.
+	ENABLEINCLUDENOTIFY 0x0040_0000
+	/// Enables the Explorer-style dialog box to be resized using either the
+	/// mouse or the keyboard.
+	ENABLESIZING 0x0080_0000
+	/// Prevents the system from adding a link to the selected file in the file
+	/// system directory that contains the user's most recently used documents.
+	DONTADDTORECENT 0x0200_0000
+	/// Forces the showing of system and hidden files, thus overriding the user
+	/// setting to show or not show hidden files.
+	FORCESHOWHIDDEN 0x1000_0000
+}
+
+const_bitflag! { OFN_EX: u32;
+	/// [`OPENFILENAME`](crate::OPENFILENAME) `FlagsEx` (`u32`).
+	=>
+	=>
+	NONE 0
+	/// If this flag is set, the places bar is not displayed.
+	NOPLACESBAR 0x0000_0001
+}
+
 const_ordinary! { OIC: u32;
 	/// [`HINSTANCE::LoadImageIcon`](crate::prelude::gdi_Hinstance::LoadImageIcon)
 	/// OEM icon identifier (`u32`).
@@ -3243,6 +3359,7 @@ const_ordinary! { WM: u32;
 	SYSCOLORCHANGE 0x0015
 	SHOWWINDOW 0x0018
 	WININICHANGE 0x001a
+	SETTINGCHANGE Self::WININICHANGE.0
 	DEVMODECHANGE 0x001b
 	ACTIVATEAPP 0x001c
 	FONTCHANGE 0x001d
@@ -3652,7 +3769,7 @@ const_bitflag! { WS_EX: u32;
 	/// been painted.
 	///
 	/// To achieve transparency without these restrictions use the
-	/// [`HWND::SetWindowRgn`](crate::prelude::user_Hwnd::SetWindowRgn)
+	/// [`HWND::SetWindowRgn`](crate::prelude::gdi_Hwnd::SetWindowRgn)
 	/// function.
 	TRANSPARENT 0x0000_0020
 	/// The window is a MDI child window.
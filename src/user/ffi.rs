@@ -3,6 +3,8 @@ use crate::kernel::ffi_types::*;
 extern_sys! { "comdlg32";
 	ChooseColorW(PVOID) -> BOOL
 	CommDlgExtendedError() -> u32
+	GetOpenFileNameW(PVOID) -> BOOL
+	GetSaveFileNameW(PVOID) -> BOOL
 }
 
 #[cfg(target_pointer_width = "32")]
@@ -22,6 +24,7 @@ extern_sys! { "user32";
 	AdjustWindowRectEx(PVOID, u32, BOOL, u32) -> BOOL
 	AdjustWindowRectExForDpi(PVOID, u32, BOOL, u32, u32) -> BOOL
 	AllowSetForegroundWindow(u32) -> BOOL
+	AnimateWindow(HANDLE, u32, u32) -> BOOL
 	AnyPopup() -> BOOL
 	AppendMenuW(HANDLE, u32, usize, PCSTR) -> BOOL
 	ArrangeIconicWindows(HANDLE) -> u32
@@ -34,6 +37,7 @@ extern_sys! { "user32";
 	CallNextHookEx(HANDLE, i32, usize, isize) -> isize
 	ChangeDisplaySettingsExW(PCSTR, PVOID, PVOID, u32, PVOID) -> i32
 	ChangeDisplaySettingsW(PVOID, u32) -> i32
+	CheckDlgButton(HANDLE, i32, u32) -> BOOL
 	CheckMenuItem(HANDLE, u32, u32) -> i32
 	CheckMenuRadioItem(HANDLE, u32, u32, u32, u32) -> BOOL
 	ChildWindowFromPoint(HANDLE, i32, i32) -> HANDLE
@@ -103,11 +107,14 @@ extern_sys! { "user32";
 	GetDialogBaseUnits() -> i32
 	GetDlgCtrlID(HANDLE) -> i32
 	GetDlgItem(HANDLE, i32) -> HANDLE
+	GetDlgItemInt(HANDLE, i32, *mut BOOL, BOOL) -> u32
+	GetDlgItemTextW(HANDLE, i32, PSTR, i32) -> u32
 	GetDoubleClickTime() -> u32
 	GetDpiForWindow(HANDLE) -> u32
 	GetFocus() -> HANDLE
 	GetForegroundWindow() -> HANDLE
 	GetGUIThreadInfo(u32, PVOID) -> BOOL
+	GetKeyState(i32) -> i16
 	GetLastActivePopup(HANDLE) -> HANDLE
 	GetMenu(HANDLE) -> HANDLE
 	GetMenuBarInfo(HANDLE, i32, i32, PVOID) -> BOOL
@@ -161,6 +168,7 @@ extern_sys! { "user32";
 	InvertRect(HANDLE, PCVOID) -> BOOL
 	IsChild(HANDLE, HANDLE) -> BOOL
 	IsDialogMessageW(HANDLE, PVOID) -> BOOL
+	IsDlgButtonChecked(HANDLE, i32) -> u32
 	IsGUIThread(BOOL) -> BOOL
 	IsIconic(HANDLE) -> BOOL
 	IsMenu(HANDLE) -> BOOL
@@ -201,6 +209,7 @@ extern_sys! { "user32";
 	RealGetWindowClassW(HANDLE, PSTR, i32) -> u32
 	RedrawWindow(HANDLE, PCVOID, HANDLE, u32) -> BOOL
 	RegisterClassExW(PCVOID) -> u16
+	RegisterHotKey(HANDLE, i32, u32, u32) -> BOOL
 	RegisterWindowMessageW(PCSTR) -> u32
 	ReleaseCapture() -> BOOL
 	ReleaseDC(HANDLE, HANDLE) -> i32
@@ -216,6 +225,8 @@ extern_sys! { "user32";
 	SetCaretPos(i32, i32) -> BOOL
 	SetClipboardData(u32, HANDLE) -> HANDLE
 	SetCursorPos(i32, i32) -> BOOL
+	SetDlgItemInt(HANDLE, i32, u32, BOOL) -> BOOL
+	SetDlgItemTextW(HANDLE, i32, PCSTR) -> BOOL
 	SetDoubleClickTime(u32) -> BOOL
 	SetFocus(HANDLE) -> HANDLE
 	SetForegroundWindow(HANDLE) -> BOOL
@@ -237,7 +248,6 @@ extern_sys! { "user32";
 	SetWindowDisplayAffinity(HANDLE, u32) -> BOOL
 	SetWindowPlacement(HANDLE, PCVOID) -> BOOL
 	SetWindowPos(HANDLE, HANDLE, i32, i32, i32, i32, u32) -> BOOL
-	SetWindowRgn(HANDLE, HANDLE, BOOL) -> i32
 	SetWindowsHookExW(i32, PFUNC, HANDLE, u32) -> HANDLE
 	SetWindowTextW(HANDLE, PCSTR) -> BOOL
 	ShowCaret(HANDLE) -> BOOL
@@ -258,9 +268,11 @@ extern_sys! { "user32";
 	UnhookWindowsHookEx(HANDLE) -> BOOL
 	UnionRect(PVOID, PCVOID, PCVOID) -> BOOL
 	UnregisterClassW(PCSTR, HANDLE) -> BOOL
+	UnregisterHotKey(HANDLE, i32) -> BOOL
 	UpdateWindow(HANDLE) -> BOOL
 	ValidateRect(HANDLE, PCVOID) -> BOOL
 	ValidateRgn(HANDLE, HANDLE) -> BOOL
+	WaitForInputIdle(HANDLE, u32) -> u32
 	WaitMessage() -> BOOL
 	WindowFromDC(HANDLE) -> HANDLE
 	WindowFromPhysicalPoint(i32, i32) -> HANDLE
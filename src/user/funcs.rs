@@ -51,7 +51,22 @@ pub fn AdjustWindowRectExForDpi(
 }
 
 /// [`AllowSetForegroundWindow`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-allowsetforegroundwindow)
-/// function
+/// function.
+///
+/// Enables the given process, or any process if `None`, to set the
+/// foreground window with
+/// [`HWND::SetForegroundWindow`](crate::prelude::user_Hwnd::SetForegroundWindow),
+/// bypassing the foreground lock timeout. The calling process must itself be
+/// able to set the foreground window, otherwise the call fails.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// w::AllowSetForegroundWindow(Some(w::GetCurrentProcessId()))?;
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
 pub fn AllowSetForegroundWindow(process_id: Option<u32>) -> SysResult<()> {
 	bool_to_sysresult(
 		unsafe { ffi::AllowSetForegroundWindow(process_id.unwrap_or(ASFW_ANY)) },
@@ -561,6 +576,24 @@ pub fn GetGUIThreadInfo(
 	)
 }
 
+/// [`GetKeyState`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getkeystate)
+/// function.
+///
+/// If the high-order bit is set, the key is down. If the low-order bit is set,
+/// the key is toggled, like [`VK::CAPITAL`](crate::co::VK::CAPITAL).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let caps_lock_on = w::GetKeyState(co::VK::CAPITAL) & 0x0001 != 0;
+/// ```
+#[must_use]
+pub fn GetKeyState(virt_key: co::VK) -> i16 {
+	unsafe { ffi::GetKeyState(virt_key.raw() as _) }
+}
+
 /// [`GetMessage`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getmessagew)
 /// function.
 pub fn GetMessage(
@@ -597,6 +630,49 @@ pub fn GetMessagePos() -> POINT {
 	POINT::from(unsafe { ffi::GetMessagePos() })
 }
 
+/// [`GetOpenFileName`](https://learn.microsoft.com/en-us/windows/win32/api/commdlg/nf-commdlg-getopenfilenamew)
+/// function.
+///
+/// Returns `false` if the user cancelled the dialog.
+///
+/// Prefer the [`IFileOpenDialog`](crate::IFileOpenDialog) COM interface, if
+/// available.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let parent_hwnd: w::HWND; // initialized somewhere
+/// # let parent_hwnd = w::HWND::NULL;
+///
+/// let mut ofn = w::OPENFILENAME::default();
+/// let mut filter = w::WString::from_str_vec(&[
+///     "Text files (*.txt)", "*.txt",
+///     "All files (*.*)", "*.*",
+/// ]);
+/// let mut file_buf = w::WString::new_alloc_buf(260); // MAX_PATH
+///
+/// ofn.hwndOwner = parent_hwnd;
+/// ofn.Flags = co::OFN::EXPLORER | co::OFN::FILEMUSTEXIST;
+/// ofn.set_lpstrFilter(Some(&mut filter));
+/// ofn.set_lpstrFile(Some(&mut file_buf));
+///
+/// if w::GetOpenFileName(&mut ofn)? {
+///     println!("Chosen: {}", ofn.lpstrFile().unwrap());
+/// }
+/// # Ok::<_, co::CDERR>(())
+/// ```
+pub fn GetOpenFileName(ofn: &mut OPENFILENAME) -> Result<bool, co::CDERR> {
+	match unsafe { ffi::GetOpenFileNameW(ofn as *mut _ as _) } {
+		0 => match CommDlgExtendedError() {
+			co::CDERR::NoValue => Ok(false),
+			err => Err(err),
+		},
+		_ => Ok(true),
+	}
+}
+
 /// [`GetQueueStatus`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getqueuestatus)
 /// function.
 #[must_use]
@@ -604,6 +680,23 @@ pub fn GetQueueStatus(flags: co::QS) -> u32 {
 	unsafe { ffi::GetQueueStatus(flags.raw()) }
 }
 
+/// [`GetSaveFileName`](https://learn.microsoft.com/en-us/windows/win32/api/commdlg/nf-commdlg-getsavefilenamew)
+/// function.
+///
+/// Returns `false` if the user cancelled the dialog.
+///
+/// Prefer the [`IFileSaveDialog`](crate::IFileSaveDialog) COM interface, if
+/// available.
+pub fn GetSaveFileName(ofn: &mut OPENFILENAME) -> Result<bool, co::CDERR> {
+	match unsafe { ffi::GetSaveFileNameW(ofn as *mut _ as _) } {
+		0 => match CommDlgExtendedError() {
+			co::CDERR::NoValue => Ok(false),
+			err => Err(err),
+		},
+		_ => Ok(true),
+	}
+}
+
 /// [`GetSysColor`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getsyscolor)
 /// function.
 #[must_use]
@@ -778,6 +871,26 @@ pub unsafe fn RegisterClassEx(wcx: &WNDCLASSEX) -> SysResult<ATOM> {
 
 /// [`RegisterWindowMessage`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerwindowmessagew)
 /// function.
+///
+/// Returns a message identifier in the range `0xc000` through `0xffff`. All
+/// processes which register the same string receive the same identifier.
+///
+/// # Examples
+///
+/// Broadcasting a private message to all top-level windows:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co, msg};
+///
+/// let msg_id = w::RegisterWindowMessage("MyApp.Refresh")?;
+///
+/// w::HWND::BROADCAST.SendMessageTimeout(
+///     msg::WndMsg::new(unsafe { co::WM::from_raw(msg_id) }, 0, 0),
+///     co::SMTO::ABORTIFHUNG,
+///     1000,
+/// )?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
 #[must_use]
 pub fn RegisterWindowMessage(s: &str) -> SysResult<u32> {
 	match unsafe { ffi::RegisterWindowMessageW(WString::from_str(s).as_ptr()) } {
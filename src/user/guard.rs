@@ -250,3 +250,13 @@ impl<'a, H> ReleaseDCGuard<'a, H>
 		std::mem::replace(&mut self.hdc, HDC::INVALID)
 	}
 }
+
+//------------------------------------------------------------------------------
+
+handle_guard! { UnhookWindowsHookExGuard: HHOOK;
+	ffi::UnhookWindowsHookEx;
+	/// RAII implementation for [`HHOOK`](crate::HHOOK) which automatically
+	/// calls
+	/// [`UnhookWindowsHookEx`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-unhookwindowshookex)
+	/// when the object goes out of scope.
+}
@@ -98,6 +98,30 @@ pub trait user_Hdc: Handle {
 	/// )?;
 	/// # Ok::<_, winsafe::co::ERROR>(())
 	/// ```
+	///
+	/// Collecting all the display monitors, along with their work areas, by
+	/// calling the method on a null `HDC`:
+	///
+	/// ```no_run
+	/// use std::cell::RefCell;
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let monitors = RefCell::new(Vec::<w::HMONITOR>::new());
+	/// w::HDC::NULL.EnumDisplayMonitors(
+	///     None,
+	///     |hmon: w::HMONITOR, _: w::HDC, _: &w::RECT| -> bool {
+	///         monitors.borrow_mut().push(hmon);
+	///         true
+	///     },
+	/// )?;
+	///
+	/// for hmon in monitors.into_inner() {
+	///     let mut mi = w::MONITORINFOEX::default();
+	///     hmon.GetMonitorInfo(&mut mi)?;
+	///     println!("{}: {}", mi.szDevice(), mi.rcWork);
+	/// }
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
 	fn EnumDisplayMonitors<F>(&self,
 		rc_clip: Option<RECT>,
 		func: F,
@@ -108,7 +132,7 @@ pub trait user_Hdc: Handle {
 			unsafe {
 				ffi::EnumDisplayMonitors(
 					self.ptr(),
-					rc_clip.map_or(std::ptr::null_mut(), |rc| &rc as *const _ as _),
+					rc_clip.as_ref().map_or(std::ptr::null_mut(), |rc| rc as *const _ as _),
 					enum_display_monitors_proc::<F> as _,
 					&func as *const _ as _,
 				)
@@ -36,6 +36,26 @@ pub trait user_Hdwp: Handle {
 	/// [`EndDeferWindowPosGuard`](crate::guard::EndDeferWindowPosGuard), which
 	/// automatically calls `EndDeferWindowPos` when the guard goes out of
 	/// scope.
+	///
+	/// # Examples
+	///
+	/// Moving two windows at once, without flickering:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let (hwnd1, hwnd2): (w::HWND, w::HWND); // initialized somewhere
+	/// # let (hwnd1, hwnd2) = (w::HWND::NULL, w::HWND::NULL);
+	///
+	/// {
+	///     let mut hdwp = w::HDWP::BeginDeferWindowPos(2)?;
+	///     hdwp.DeferWindowPos(&hwnd1, w::HwndPlace::None,
+	///         w::POINT::new(10, 10), w::SIZE::new(100, 20), co::SWP::NOZORDER)?;
+	///     hdwp.DeferWindowPos(&hwnd2, w::HwndPlace::None,
+	///         w::POINT::new(10, 40), w::SIZE::new(100, 20), co::SWP::NOZORDER)?;
+	/// } // EndDeferWindowPos() called here
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
 	#[must_use]
 	fn BeginDeferWindowPos(
 		num_windows: u32,
@@ -51,7 +71,13 @@ pub trait user_Hdwp: Handle {
 	/// function.
 	///
 	/// Originally this method returns the handle to the reallocated memory
-	/// object; here the original handle is automatically updated.
+	/// object, which must be used in the subsequent calls; here the original
+	/// handle is automatically updated, so you can keep calling this method on
+	/// the same guard.
+	///
+	/// If the function fails, the system has already destroyed the whole
+	/// positioning sequence, so the handle is invalidated and
+	/// `EndDeferWindowPos` won't be called.
 	fn DeferWindowPos(&mut self,
 		hwnd: &HWND,
 		hwnd_insert_after: HwndPlace,
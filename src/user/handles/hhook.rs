@@ -2,6 +2,7 @@
 
 use crate::co;
 use crate::decl::*;
+use crate::guard::*;
 use crate::kernel::privs::*;
 use crate::prelude::*;
 use crate::user::ffi;
@@ -35,28 +36,73 @@ pub trait user_Hhook: Handle {
 
 	/// [`SetWindowsHookEx`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setwindowshookexw)
 	/// function.
+	///
+	/// In the original C implementation, you must call
+	/// [`UnhookWindowsHookEx`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-unhookwindowshookex)
+	/// as a cleanup operation.
+	///
+	/// Here, the cleanup is performed automatically, because
+	/// `SetWindowsHookEx` returns an
+	/// [`UnhookWindowsHookExGuard`](crate::guard::UnhookWindowsHookExGuard),
+	/// which automatically calls `UnhookWindowsHookEx` when the guard goes out
+	/// of scope.
+	///
+	/// # Examples
+	///
+	/// Installing a thread-local hook which monitors the messages retrieved by
+	/// the current thread:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// extern "system" fn get_msg_proc(
+	///     code: i32, wparam: usize, lparam: isize) -> isize
+	/// {
+	///     let msg = unsafe { &*(lparam as *const w::MSG) };
+	///     println!("Message: {}", msg.message);
+	///     w::HHOOK::NULL.CallNextHookEx(
+	///         unsafe { co::WH::from_raw(code) }, wparam, lparam)
+	/// }
+	///
+	/// let _hhook = w::HHOOK::SetWindowsHookEx(
+	///     co::WH::GETMESSAGE,
+	///     get_msg_proc,
+	///     None,
+	///     Some(w::GetCurrentThreadId()),
+	/// )?;
+	///
+	/// // UnhookWindowsHookEx() called here
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
 	fn SetWindowsHookEx(
 		hook_id: co::WH,
 		proc: HOOKPROC,
 		module: Option<&HINSTANCE>,
 		thread_id: Option<u32>,
-	) -> SysResult<HHOOK>
+	) -> SysResult<UnhookWindowsHookExGuard>
 	{
-		ptr_to_sysresult_handle(
-			unsafe {
+		unsafe {
+			ptr_to_sysresult_handle(
 				ffi::SetWindowsHookExW(
 					hook_id.raw(),
 					proc as _,
 					module.map_or(std::ptr::null_mut(), |h| h.ptr()),
 					thread_id.unwrap_or_default(),
-				)
-			},
-		)
+				),
+			).map(|h| UnhookWindowsHookExGuard::new(h))
+		}
 	}
 
 	/// [`UnhookWindowsHookEx`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-unhookwindowshookex)
 	/// function.
 	///
+	/// **Note:** Prefer using the
+	/// [`UnhookWindowsHookExGuard`](crate::guard::UnhookWindowsHookExGuard)
+	/// returned by
+	/// [`HHOOK::SetWindowsHookEx`](crate::prelude::user_Hhook::SetWindowsHookEx),
+	/// which calls this function automatically.
+	///
 	/// After calling this method, the handle will be invalidated and further
 	/// operations will fail with
 	/// [`ERROR::INVALID_HANDLE`](crate::co::ERROR::INVALID_HANDLE) error code.
@@ -20,6 +20,12 @@ pub trait user_Hinstance: kernel_Hinstance {
 	/// [`CreateDialogParam`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createdialogparamw)
 	/// function.
 	///
+	/// Creates a modeless dialog; for a modal one, see
+	/// [`HINSTANCE::DialogBoxParam`](crate::prelude::user_Hinstance::DialogBoxParam).
+	///
+	/// If you want to handle the dialog messages with closures, prefer using
+	/// [`gui::WindowModeless::new_dlg`](crate::gui::WindowModeless::new_dlg).
+	///
 	/// # Safety
 	///
 	/// To create a dialog, you must provide a dialog procedure.
@@ -70,13 +76,48 @@ pub trait user_Hinstance: kernel_Hinstance {
 		}
 	}
 
-
 	/// [`DialogBoxParam`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-dialogboxparamw)
 	/// function.
 	///
+	/// Returns the value passed to
+	/// [`HWND::EndDialog`](crate::prelude::user_Hwnd::EndDialog).
+	///
+	/// If you want to handle the dialog messages with closures, prefer using
+	/// [`gui::WindowModal::new_dlg`](crate::gui::WindowModal::new_dlg).
+	///
 	/// # Safety
 	///
 	/// To create a dialog, you must provide a dialog procedure.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// const ID_DLG: u16 = 101;
+	///
+	/// extern "system" fn dlg_proc(
+	///     hdlg: w::HWND, msg: co::WM, wparam: usize, _: isize) -> isize
+	/// {
+	///     match msg {
+	///         co::WM::INITDIALOG => 1, // let the system set the focus
+	///         co::WM::COMMAND => {
+	///             let id = unsafe { co::DLGID::from_raw(wparam as u16) };
+	///             if id == co::DLGID::OK || id == co::DLGID::CANCEL {
+	///                 hdlg.EndDialog(id.raw() as _).unwrap();
+	///             }
+	///             1
+	///         },
+	///         _ => 0, // not handled
+	///     }
+	/// }
+	///
+	/// let hinst = w::HINSTANCE::GetModuleHandle(None)?;
+	/// let ret = unsafe {
+	///     hinst.DialogBoxParam(w::IdStr::Id(ID_DLG), None, dlg_proc, None)?
+	/// };
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
 	unsafe fn DialogBoxParam(&self,
 		resource_id: IdStr,
 		hwnd_parent: Option<&HWND>,
@@ -113,6 +154,20 @@ pub trait user_Hinstance: kernel_Hinstance {
 	///     .GetClassInfoEx("SOME_CLASS_NAME", &mut wcx)?;
 	/// # Ok::<_, winsafe::co::ERROR>(())
 	/// ```
+	///
+	/// Retrieving the window procedure of a system class, which is registered
+	/// with a null [`HINSTANCE`](crate::HINSTANCE):
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let mut wcx = w::WNDCLASSEX::default();
+	/// w::HINSTANCE::NULL.GetClassInfoEx("BUTTON", &mut wcx)?;
+	///
+	/// let button_proc = wcx.lpfnWndProc.unwrap();
+	/// println!("Style: {}", wcx.style);
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
 	fn GetClassInfoEx(&self,
 		class_name: &str,
 		wcx: &mut WNDCLASSEX,
@@ -198,6 +253,9 @@ pub trait user_Hinstance: kernel_Hinstance {
 
 	/// [`LoadString`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-loadstringw)
 	/// function.
+	///
+	/// The string is read directly from the resource table, so there is no
+	/// buffer size limit.
 	#[must_use]
 	fn LoadString(&self, id: u16) -> SysResult<String> {
 		let mut pdata: *const u16 = std::ptr::null_mut();
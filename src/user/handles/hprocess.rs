@@ -38,4 +38,49 @@ pub trait user_Hprocess: kernel_Hprocess {
 			),
 		)
 	}
+
+	/// [`WaitForInputIdle`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-waitforinputidle)
+	/// function.
+	///
+	/// Returns [`WAIT::OBJECT_0`](crate::co::WAIT::OBJECT_0) when the process
+	/// is ready, or [`WAIT::TIMEOUT`](crate::co::WAIT::TIMEOUT) if the time
+	/// interval elapsed. If the process has no message queue, like a console
+	/// process, the function returns immediately.
+	///
+	/// # Examples
+	///
+	/// Launching Notepad and waiting until it's ready to receive input:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let mut si = w::STARTUPINFO::default();
+	///
+	/// let pi = w::HPROCESS::CreateProcess(
+	///     None,
+	///     Some("notepad.exe"),
+	///     None,
+	///     None,
+	///     false,
+	///     co::CREATE::NoValue,
+	///     None,
+	///     None,
+	///     &mut si,
+	/// )?;
+	///
+	/// if pi.hProcess.WaitForInputIdle(Some(5000))? == co::WAIT::TIMEOUT {
+	///     println!("Notepad is not ready yet.");
+	/// }
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	fn WaitForInputIdle(&self, milliseconds: Option<u32>) -> SysResult<co::WAIT> {
+		match unsafe {
+			co::WAIT::from_raw(
+				ffi::WaitForInputIdle(self.ptr(), milliseconds.unwrap_or(INFINITE)),
+			)
+		} {
+			co::WAIT::FAILED => Err(GetLastError()),
+			wait => Ok(wait),
+		}
+	}
 }
@@ -26,8 +26,9 @@ impl user_Hwnd for HWND {}
 /// ```
 pub trait user_Hwnd: Handle {
 	/// Represents all top-level windows in
-	/// [`HWND::PostMessage`](crate::prelude::user_Hwnd::PostMessage) and
-	/// [`HWND::SendMessage`](crate::prelude::user_Hwnd::SendMessage).
+	/// [`HWND::PostMessage`](crate::prelude::user_Hwnd::PostMessage),
+	/// [`HWND::SendMessage`](crate::prelude::user_Hwnd::SendMessage) and
+	/// [`HWND::SendMessageTimeout`](crate::prelude::user_Hwnd::SendMessageTimeout).
 	const BROADCAST: HWND = HWND(0xffff as _);
 
 	/// Represents the desktop window in
@@ -43,6 +44,70 @@ pub trait user_Hwnd: Handle {
 		}
 	}
 
+	/// Tells whether the handle refers to an existing top-level window, by
+	/// checking if
+	/// [`HWND::GetAncestor`](crate::prelude::user_Hwnd::GetAncestor) with
+	/// [`GA::ROOT`](crate::co::GA::ROOT) returns the window itself.
+	#[must_use]
+	fn is_top_level(&self) -> bool {
+		self.IsWindow()
+			&& self.GetAncestor(co::GA::ROOT)
+				.is_some_and(|root| root.ptr() == self.ptr())
+	}
+
+	/// Changes the parent of the window with
+	/// [`HWND::SetParent`](crate::prelude::user_Hwnd::SetParent), also
+	/// reconciling the window styles: [`WS::POPUP`](crate::co::WS::POPUP) is
+	/// removed and [`WS::CHILD`](crate::co::WS::CHILD) is added. The frame is
+	/// then recalculated with
+	/// [`HWND::SetWindowPos`](crate::prelude::user_Hwnd::SetWindowPos).
+	///
+	/// Returns the previous parent, if any. If
+	/// [`HWND::SetParent`](crate::prelude::user_Hwnd::SetParent) fails, the
+	/// original styles are restored.
+	fn reparent_as_child(&self, new_parent: &HWND) -> SysResult<Option<HWND>> {
+		let style = self.GetWindowLongPtr(co::GWLP::STYLE);
+		self.SetWindowLongPtr(
+			co::GWLP::STYLE,
+			((unsafe { co::WS::from_raw(style as _) } & !co::WS::POPUP)
+				| co::WS::CHILD).raw() as _,
+		);
+		let prev = self.SetParent(new_parent)
+			.inspect_err(|_| {
+				self.SetWindowLongPtr(co::GWLP::STYLE, style); // restore original style
+			})?;
+		self.SetWindowPos(
+			HwndPlace::None,
+			POINT::default(),
+			SIZE::default(),
+			co::SWP::NOMOVE | co::SWP::NOSIZE | co::SWP::NOZORDER
+				| co::SWP::FRAMECHANGED,
+		)?;
+		Ok(prev)
+	}
+
+	/// [`AnimateWindow`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-animatewindow)
+	/// function.
+	///
+	/// # Examples
+	///
+	/// Fading in a hidden window during 200 milliseconds:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// hwnd.AnimateWindow(200, co::AW::BLEND | co::AW::ACTIVATE)?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	fn AnimateWindow(&self, time_ms: u32, flags: co::AW) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe { ffi::AnimateWindow(self.ptr(), time_ms, flags.raw()) },
+		)
+	}
+
 	/// [`ArrangeIconicWindows`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-arrangeiconicwindows)
 	/// function.
 	fn ArrangeIconicWindows(&self) -> SysResult<u32> {
@@ -115,6 +180,14 @@ pub trait user_Hwnd: Handle {
 		bool_to_sysresult(unsafe { ffi::BringWindowToTop(self.ptr()) })
 	}
 
+	/// [`CheckDlgButton`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-checkdlgbutton)
+	/// function.
+	fn CheckDlgButton(&self, ctrl_id: u16, check: co::BST) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe { ffi::CheckDlgButton(self.ptr(), ctrl_id as _, check.raw()) },
+		)
+	}
+
 	/// [`ChildWindowFromPoint`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-childwindowfrompoint)
 	/// function.
 	#[must_use]
@@ -513,6 +586,69 @@ pub trait user_Hwnd: Handle {
 		)
 	}
 
+	/// [`GetDlgItemInt`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getdlgitemint)
+	/// function.
+	///
+	/// If `signed` is `true`, the returned value should be cast to `i32`.
+	///
+	/// Returns `None` if the control text could not be translated into an
+	/// integer.
+	#[must_use]
+	fn GetDlgItemInt(&self, ctrl_id: u16, signed: bool) -> Option<u32> {
+		let mut translated: BOOL = 0;
+		let val = unsafe {
+			ffi::GetDlgItemInt(
+				self.ptr(),
+				ctrl_id as _,
+				&mut translated,
+				signed as _,
+			)
+		};
+		if translated == 0 { None } else { Some(val) }
+	}
+
+	/// [`GetDlgItemText`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getdlgitemtextw)
+	/// function.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hdlg: w::HWND; // initialized somewhere
+	/// # let hdlg = w::HWND::NULL;
+	///
+	/// const ID_TXT_NAME: u16 = 1001;
+	///
+	/// hdlg.SetDlgItemText(ID_TXT_NAME, "John")?;
+	/// let name = hdlg.GetDlgItemText(ID_TXT_NAME)?;
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn GetDlgItemText(&self, ctrl_id: u16) -> SysResult<String> {
+		let len = self.GetDlgItem(ctrl_id)?.GetWindowTextLength()?;
+		if len == 0 {
+			return Ok(String::default()); // control has no text
+		}
+
+		let mut buf = WString::new_alloc_buf(len as usize + 1); // plus terminating null
+		SetLastError(co::ERROR::SUCCESS);
+		match unsafe {
+			ffi::GetDlgItemTextW(
+				self.ptr(),
+				ctrl_id as _,
+				buf.as_mut_ptr(),
+				len + 1,
+			)
+		} {
+			0 => match GetLastError() {
+				co::ERROR::SUCCESS => Ok(String::default()), // no chars copied for some reason
+				err => Err(err),
+			},
+			_ => Ok(buf.to_string()),
+		}
+	}
+
 	/// [`GetDpiForWindow`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getdpiforwindow)
 	/// function.
 	#[must_use]
@@ -952,6 +1088,15 @@ pub trait user_Hwnd: Handle {
 		unsafe { ffi::IsDialogMessageW(self.ptr(), msg as *mut _ as _) != 0 }
 	}
 
+	/// [`IsDlgButtonChecked`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-isdlgbuttonchecked)
+	/// function.
+	#[must_use]
+	fn IsDlgButtonChecked(&self, ctrl_id: u16) -> co::BST {
+		unsafe {
+			co::BST::from_raw(ffi::IsDlgButtonChecked(self.ptr(), ctrl_id as _))
+		}
+	}
+
 	/// [`IsIconic`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-isiconic)
 	/// function.
 	#[must_use]
@@ -961,6 +1106,24 @@ pub trait user_Hwnd: Handle {
 
 	/// [`IsWindow`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-iswindow)
 	/// function.
+	///
+	/// Useful to check whether a window is still alive, since it may have been
+	/// destroyed after its handle was retrieved.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// if hwnd.IsWindow() {
+	///     println!("Top-level: {}", hwnd.is_top_level());
+	/// } else {
+	///     println!("Window is gone.");
+	/// }
+	/// ```
 	#[must_use]
 	fn IsWindow(&self) -> bool {
 		unsafe { ffi::IsWindow(self.ptr()) != 0 }
@@ -1053,7 +1216,10 @@ pub trait user_Hwnd: Handle {
 	/// function.
 	///
 	/// This method can convert either a series of [`POINT`](crate::POINT)
-	/// structs or a single [`RECT`](crate::RECT).
+	/// structs or a single [`RECT`](crate::RECT). To convert to or from screen
+	/// coordinates, use [`HWND::DESKTOP`](crate::prelude::user_Hwnd::DESKTOP).
+	///
+	/// Returns the horizontal and vertical offsets added to each point.
 	///
 	/// # Examples
 	///
@@ -1094,7 +1260,10 @@ pub trait user_Hwnd: Handle {
 				forced_pts.len() as _,
 			)
 		} {
-			0 => Err(GetLastError()),
+			0 => match GetLastError() {
+				co::ERROR::SUCCESS => Ok((0, 0)), // actual zero offsets
+				err => Err(err),
+			},
 			n => Ok((LOWORD(n as _) as _, HIWORD(n as _) as _)),
 		}
 	}
@@ -1292,6 +1461,46 @@ pub trait user_Hwnd: Handle {
 		)
 	}
 
+	/// [`RegisterHotKey`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerhotkey)
+	/// function.
+	///
+	/// The window will receive [`wm::HotKey`](crate::msg::wm::HotKey) messages
+	/// when the hotkey is pressed. Paired with
+	/// [`HWND::UnregisterHotKey`](crate::prelude::user_Hwnd::UnregisterHotKey).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// hwnd.RegisterHotKey(1, co::MOD::CONTROL | co::MOD::ALT, co::VK::CHAR_K)?;
+	///
+	/// // ...
+	///
+	/// hwnd.UnregisterHotKey(1)?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	fn RegisterHotKey(&self,
+		id: i32,
+		modifiers: co::MOD,
+		vk_code: co::VK,
+	) -> SysResult<()>
+	{
+		bool_to_sysresult(
+			unsafe {
+				ffi::RegisterHotKey(
+					self.ptr(),
+					id,
+					modifiers.raw() as _,
+					vk_code.raw() as _,
+				)
+			},
+		)
+	}
+
 	/// [`ScreenToClient`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-screentoclient)
 	/// function.
 	///
@@ -1406,6 +1615,31 @@ pub trait user_Hwnd: Handle {
 
 	/// [`SendMessageTimeout`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-sendmessagetimeoutw)
 	/// function.
+	///
+	/// If the window doesn't respond within the given interval, fails with
+	/// [`ERROR::TIMEOUT`](crate::co::ERROR::TIMEOUT).
+	///
+	/// # Examples
+	///
+	/// Checking whether a window is responsive, without hanging if it's not:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, msg};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// match hwnd.SendMessageTimeout(
+	///     msg::wm::Null {},
+	///     co::SMTO::ABORTIFHUNG | co::SMTO::BLOCK,
+	///     500,
+	/// ) {
+	///     Ok(_) => println!("Window is responsive."),
+	///     Err(co::ERROR::TIMEOUT) => println!("Window is hung."),
+	///     Err(e) => return Err(e),
+	/// }
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
 	fn SendMessageTimeout<M>(&self,
 		msg: M,
 		flags: co::SMTO,
@@ -1440,6 +1674,32 @@ pub trait user_Hwnd: Handle {
 
 	/// [`SetCapture`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setcapture)
 	/// function.
+	///
+	/// In the original C implementation, you must call
+	/// [`ReleaseCapture`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-releasecapture)
+	/// when the mouse capture is no longer needed.
+	///
+	/// Here, the cleanup is performed automatically, because `SetCapture`
+	/// returns a [`ReleaseCaptureGuard`](crate::guard::ReleaseCaptureGuard),
+	/// which automatically calls `ReleaseCapture` when the guard goes out of
+	/// scope. You must, however, keep the guard alive, otherwise the capture
+	/// will be released right away.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// let _capture = hwnd.SetCapture(); // keep guard alive
+	///
+	/// // handle mouse dragging...
+	///
+	/// // ReleaseCapture() called automatically
+	/// ```
+	#[must_use]
 	fn SetCapture(&self) -> ReleaseCaptureGuard<'_, Self> {
 		unsafe {
 			ReleaseCaptureGuard::new(
@@ -1451,6 +1711,37 @@ pub trait user_Hwnd: Handle {
 		}
 	}
 
+	/// [`SetDlgItemInt`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setdlgitemint)
+	/// function.
+	///
+	/// If `signed` is `true`, `value` is interpreted as `i32`.
+	fn SetDlgItemInt(&self,
+		ctrl_id: u16,
+		value: u32,
+		signed: bool,
+	) -> SysResult<()>
+	{
+		bool_to_sysresult(
+			unsafe {
+				ffi::SetDlgItemInt(self.ptr(), ctrl_id as _, value, signed as _)
+			},
+		)
+	}
+
+	/// [`SetDlgItemText`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setdlgitemtextw)
+	/// function.
+	fn SetDlgItemText(&self, ctrl_id: u16, text: &str) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe {
+				ffi::SetDlgItemTextW(
+					self.ptr(),
+					ctrl_id as _,
+					WString::from_str(text).as_ptr(),
+				)
+			},
+		)
+	}
+
 	/// [`SetFocus`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setfocus)
 	/// function.
 	fn SetFocus(&self) -> Option<HWND> {
@@ -1465,6 +1756,30 @@ pub trait user_Hwnd: Handle {
 
 	/// [`SetLayeredWindowAttributes`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setlayeredwindowattributes)
 	/// function.
+	///
+	/// The window must have the
+	/// [`WS_EX::LAYERED`](crate::co::WS_EX::LAYERED) extended style, otherwise
+	/// the call fails. The `transparency_color_key` is used only if
+	/// [`LWA::COLORKEY`](crate::co::LWA::COLORKEY) is set, and `alpha` only if
+	/// [`LWA::ALPHA`](crate::co::LWA::ALPHA) is set.
+	///
+	/// # Examples
+	///
+	/// Setting 50% opacity:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// hwnd.SetLayeredWindowAttributes(
+	///     w::COLORREF::new(0, 0, 0),
+	///     128,
+	///     co::LWA::ALPHA,
+	/// )?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
 	fn SetLayeredWindowAttributes(&self,
 		transparency_color_key: COLORREF,
 		alpha: u8,
@@ -1491,7 +1806,15 @@ pub trait user_Hwnd: Handle {
 
 	/// [`SetParent`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setparent)
 	/// function.
+	///
+	/// Returns the previous parent, if any.
+	///
+	/// Note that this function doesn't change the window styles: usually you
+	/// must toggle [`WS::CHILD`](crate::co::WS::CHILD) and
+	/// [`WS::POPUP`](crate::co::WS::POPUP) yourself. Alternatively, use
+	/// [`HWND::reparent_as_child`](crate::prelude::user_Hwnd::reparent_as_child).
 	fn SetParent(&self, hwnd_new_parent: &HWND) -> SysResult<Option<HWND>> {
+		SetLastError(co::ERROR::SUCCESS);
 		match ptr_to_option_handle(
 			unsafe { ffi::SetParent(self.ptr(), hwnd_new_parent.ptr()) },
 		) {
@@ -1609,6 +1932,25 @@ pub trait user_Hwnd: Handle {
 
 	/// [`SetWindowDisplayAffinity`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setwindowdisplayaffinity)
 	/// function.
+	///
+	/// Note that
+	/// [`co::WDA::EXCLUDEFROMCAPTURE`](crate::co::WDA::EXCLUDEFROMCAPTURE) is
+	/// supported only on Windows 10 version 2004 (build 19041) and later; on
+	/// older systems the call will fail.
+	///
+	/// # Examples
+	///
+	/// Excluding the window from screen capture:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// hwnd.SetWindowDisplayAffinity(co::WDA::EXCLUDEFROMCAPTURE)?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
 	fn SetWindowDisplayAffinity(&self, affinity: co::WDA) -> SysResult<()> {
 		bool_to_sysresult(
 			unsafe { ffi::SetWindowDisplayAffinity(self.ptr(), affinity.raw()) },
@@ -1672,14 +2014,6 @@ pub trait user_Hwnd: Handle {
 		)
 	}
 
-	/// [`SetWindowRgn`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setwindowrgn)
-	/// function.
-	fn SetWindowRgn(&self, hrgn: &HRGN, redraw: bool) -> SysResult<()> {
-		bool_to_sysresult(
-			unsafe { ffi::SetWindowRgn(self.ptr(), hrgn.ptr(), redraw as _) },
-		)
-	}
-
 	/// [`SetWindowText`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setwindowtextw)
 	/// function.
 	fn SetWindowText(&self, text: &str) -> SysResult<()> {
@@ -1759,6 +2093,12 @@ pub trait user_Hwnd: Handle {
 		)
 	}
 
+	/// [`UnregisterHotKey`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-unregisterhotkey)
+	/// function.
+	fn UnregisterHotKey(&self, id: i32) -> SysResult<()> {
+		bool_to_sysresult(unsafe { ffi::UnregisterHotKey(self.ptr(), id) })
+	}
+
 	/// [`UpdateWindow`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-updatewindow)
 	/// function.
 	fn UpdateWindow(&self) -> SysResult<()> {
@@ -767,6 +767,42 @@ unsafe impl<'a> MsgSendRecv for Help<'a> {
 	}
 }
 
+/// [`WM_HOTKEY`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-hotkey)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct HotKey {
+	pub id: i32,
+	pub modifiers: co::MOD,
+	pub vk_code: co::VK,
+}
+
+unsafe impl MsgSend for HotKey {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::HOTKEY,
+			wparam: self.id as _,
+			lparam: MAKEDWORD(self.modifiers.raw(), self.vk_code.raw()) as _,
+		}
+	}
+}
+
+unsafe impl MsgSendRecv for HotKey {
+	fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			id: p.wparam as _,
+			modifiers: unsafe { co::MOD::from_raw(LOWORD(p.lparam as _)) },
+			vk_code: unsafe { co::VK::from_raw(HIWORD(p.lparam as _)) },
+		}
+	}
+}
+
 /// [`WM_HSCROLL`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-hscroll)
 /// message parameters.
 ///
@@ -1513,6 +1549,64 @@ unsafe impl MsgSendRecv for SetText {
 	}
 }
 
+/// [`WM_SETTINGCHANGE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-settingchange)
+/// message parameters.
+///
+/// Return type: `()`.
+///
+/// # Examples
+///
+/// Notifying all top-level windows that the environment variables have
+/// changed, without hanging on unresponsive windows:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co, msg};
+///
+/// let section = w::WString::from_str("Environment");
+///
+/// w::HWND::BROADCAST.SendMessageTimeout(
+///     msg::wm::SettingChange {
+///         action: None,
+///         section: section.as_ptr(),
+///     },
+///     co::SMTO::ABORTIFHUNG,
+///     1000,
+/// )?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub struct SettingChange {
+	pub action: Option<co::SPI>,
+	pub section: *const u16, // can't be WString because this message can be received
+}
+
+unsafe impl MsgSend for SettingChange {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::SETTINGCHANGE,
+			wparam: self.action.map_or(0, |a| a.raw() as _),
+			lparam: self.section as _,
+		}
+	}
+}
+
+unsafe impl MsgSendRecv for SettingChange {
+	fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			action: match p.wparam {
+				0 => None,
+				action => Some(unsafe { co::SPI::from_raw(action as _) }),
+			},
+			section: p.lparam as _,
+		}
+	}
+}
+
 /// [`WM_SHOWWINDOW`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-showwindow)
 /// message parameters.
 ///
@@ -4,7 +4,7 @@ use std::marker::PhantomData;
 
 use crate::co;
 use crate::decl::*;
-use crate::kernel::ffi_types::*;
+use crate::kernel::{ffi_types::*, privs::*};
 use crate::prelude::*;
 use crate::user::privs::*;
 
@@ -35,25 +35,6 @@ pub struct ALTTABINFO {
 
 impl_default_with_size!(ALTTABINFO, cbSize);
 
-/// [`ATOM`](https://learn.microsoft.com/en-us/windows/win32/winprog/windows-data-types#atom)
-/// returned by [`RegisterClassEx`](crate::RegisterClassEx).
-#[repr(transparent)]
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
-pub struct ATOM(u16);
-
-impl_intunderlying!(ATOM, u16);
-
-impl std::fmt::Display for ATOM {
-	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		std::fmt::Display::fmt(&self.0, f)
-	}
-}
-impl std::fmt::Debug for ATOM {
-	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		write!(f, "ATOM [{:#06x} {}]", self.0, self.0)
-	}
-}
-
 /// [`CHOOSECOLOR`](https://learn.microsoft.com/en-us/windows/win32/api/commdlg/ns-commdlg-choosecolorw-r1)
 /// struct.
 #[repr(C)]
@@ -766,6 +747,79 @@ impl<'a> NCCALCSIZE_PARAMS<'a> {
 	pub_fn_ptr_get_set!('a, lppos, set_lppos, WINDOWPOS);
 }
 
+/// [`OPENFILENAME`](https://learn.microsoft.com/en-us/windows/win32/api/commdlg/ns-commdlg-openfilenamew)
+/// struct.
+#[repr(C)]
+pub struct OPENFILENAME<'a> {
+	lStructSize: u32,
+	pub hwndOwner: HWND,
+	pub hInstance: HINSTANCE,
+	lpstrFilter: *mut u16,
+	lpstrCustomFilter: *mut u16,
+	nMaxCustFilter: u32,
+	pub nFilterIndex: u32,
+	lpstrFile: *mut u16,
+	nMaxFile: u32,
+	lpstrFileTitle: *mut u16,
+	nMaxFileTitle: u32,
+	lpstrInitialDir: *mut u16,
+	lpstrTitle: *mut u16,
+	pub Flags: co::OFN,
+	pub nFileOffset: u16,
+	pub nFileExtension: u16,
+	lpstrDefExt: *mut u16,
+	pub lCustData: isize,
+	pub lpfnHook: Option<OFNHOOKPROC>,
+	lpTemplateName: *mut u16, // u16 resource ID
+	pvReserved: *mut std::ffi::c_void,
+	dwReserved: u32,
+	pub FlagsEx: co::OFN_EX,
+
+	_lpstr: PhantomData<&'a mut u16>,
+}
+
+impl_default_with_size!(OPENFILENAME, lStructSize, 'a);
+
+impl<'a> OPENFILENAME<'a> {
+	/// Returns the `lpstrFilter` field, with the description and pattern
+	/// strings of each filter in sequence.
+	#[must_use]
+	pub fn lpstrFilter(&self) -> Option<Vec<String>> {
+		unsafe { self.lpstrFilter.as_mut() }
+			.map(|psz| parse_multi_z_str(psz))
+	}
+
+	/// Sets the `lpstrFilter` field.
+	///
+	/// The buffer must contain pairs of description and pattern strings, and
+	/// it must end with two terminating nulls, which is what
+	/// [`WString::from_str_vec`](crate::WString::from_str_vec) produces.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let mut ofn = w::OPENFILENAME::default();
+	/// let mut filter = w::WString::from_str_vec(&[
+	///     "Text files (*.txt)", "*.txt",
+	///     "All files (*.*)", "*.*",
+	/// ]);
+	/// ofn.set_lpstrFilter(Some(&mut filter));
+	/// ```
+	pub fn set_lpstrFilter(&mut self, buf: Option<&'a mut WString>) {
+		self.lpstrFilter = buf.map_or(std::ptr::null_mut(), |buf| unsafe { buf.as_mut_ptr() });
+	}
+
+	pub_fn_string_buf_get_set!('a, lpstrCustomFilter, set_lpstrCustomFilter, nMaxCustFilter);
+	pub_fn_string_buf_get_set!('a, lpstrFile, set_lpstrFile, nMaxFile);
+	pub_fn_string_buf_get_set!('a, lpstrFileTitle, set_lpstrFileTitle, nMaxFileTitle);
+	pub_fn_string_ptr_get_set!('a, lpstrInitialDir, set_lpstrInitialDir);
+	pub_fn_string_ptr_get_set!('a, lpstrTitle, set_lpstrTitle);
+	pub_fn_string_ptr_get_set!('a, lpstrDefExt, set_lpstrDefExt);
+	pub_fn_resource_id_get_set!(lpTemplateName, set_lpTemplateName);
+}
+
 /// [`PAINTSTRUCT`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-paintstruct)
 /// struct.
 #[repr(C)]